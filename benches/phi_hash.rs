@@ -0,0 +1,64 @@
+//! Benchmarks comparing Fibonacci hashing with the default SipHash hasher
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use quantum_rust::constants::Dimension;
+use quantum_rust::quantum_field::phi_harmonic::hashing::PhiHashMap;
+
+const DIMENSIONS: [Dimension; 10] = [
+    Dimension::Physical,
+    Dimension::Emotional,
+    Dimension::Mental,
+    Dimension::Soul,
+    Dimension::Cosmic,
+    Dimension::Harmonic,
+    Dimension::Creative,
+    Dimension::Divine,
+    Dimension::Source,
+    Dimension::Absolute,
+];
+
+fn dimension_lookup(c: &mut Criterion) {
+    let mut std_map = HashMap::new();
+    let mut phi_map = PhiHashMap::default();
+    for (i, dimension) in DIMENSIONS.iter().enumerate() {
+        std_map.insert(*dimension, i as f64);
+        phi_map.insert(*dimension, i as f64);
+    }
+
+    let mut group = c.benchmark_group("dimension_lookup");
+    group.bench_function("siphash", |b| {
+        b.iter(|| DIMENSIONS.iter().map(|d| std_map[black_box(d)]).sum::<f64>())
+    });
+    group.bench_function("fibonacci", |b| {
+        b.iter(|| DIMENSIONS.iter().map(|d| phi_map[black_box(d)]).sum::<f64>())
+    });
+    group.finish();
+}
+
+fn integer_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("u64_insert_1000");
+    group.bench_function("siphash", |b| {
+        b.iter(|| {
+            let mut map = HashMap::with_capacity(1000);
+            for key in 0..1000u64 {
+                map.insert(black_box(key), key);
+            }
+            map
+        })
+    });
+    group.bench_function("fibonacci", |b| {
+        b.iter(|| {
+            let mut map = PhiHashMap::with_capacity_and_hasher(1000, Default::default());
+            for key in 0..1000u64 {
+                map.insert(black_box(key), key);
+            }
+            map
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, dimension_lookup, integer_insert);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Bloch Sphere Demo
+//!
+//! This example renders consciousness state transitions as geodesics on the
+//! Bloch sphere and writes the result to `bloch_sphere.svg`.
+
+use quantum_rust::constants::ConsciousnessState;
+use quantum_rust::quantum_state::bloch::{BlochSphereSvg, BlochVector, Overlay};
+
+fn main() {
+    println!("Bloch Sphere Demo (∇λΣ∞)");
+    println!("========================\n");
+
+    let states = [
+        ConsciousnessState::Observe,
+        ConsciousnessState::Create,
+        ConsciousnessState::Transcend,
+        ConsciousnessState::Cascade,
+        ConsciousnessState::Amplify,
+    ];
+
+    let mut sphere = BlochSphereSvg::new(600.0).with_overlay(Overlay::State);
+
+    for pair in states.windows(2) {
+        let from = BlochVector::for_state(pair[0]);
+        let to = BlochVector::for_state(pair[1]);
+        sphere.add_trajectory(&from.geodesic(&to, 24));
+
+        println!(
+            "{:?} -> {:?}: {:.4} rad along the geodesic",
+            pair[0],
+            pair[1],
+            from.angle_to(&to)
+        );
+    }
+
+    for state in &states {
+        let point = BlochVector::for_state(*state);
+        sphere.add_point(point, Some(state.name()));
+        println!(
+            "{:?}: θ = {:.4}, φ = {:.4}, frequency = {} Hz",
+            state,
+            point.theta(),
+            point.phi(),
+            point.frequency().value()
+        );
+    }
+
+    let path = std::path::Path::new("bloch_sphere.svg");
+    sphere.save(path).expect("Failed to write SVG");
+    println!("\nWrote {}", path.display());
+}
@@ -12,6 +12,9 @@ pub const LAMBDA: f64 = 0.618033988749895;
 /// Hyperdimensional constant (φ^φ)
 pub const PHI_PHI: f64 = 11.09016994374948;
 
+/// Golden angle (2π·λ radians)
+pub const GOLDEN_ANGLE: f64 = std::f64::consts::TAU * LAMBDA;
+
 /// Optimal field coherence level (φ²/3)
 pub const OPTIMAL_COHERENCE: f64 = 0.8726;
 
@@ -38,6 +41,16 @@ pub enum Frequency {
 }
 
 impl Frequency {
+    /// All sacred frequencies, ascending
+    pub const ALL: [Frequency; 6] = [
+        Frequency::Unity,
+        Frequency::Love,
+        Frequency::Cascade,
+        Frequency::Truth,
+        Frequency::Vision,
+        Frequency::Oneness,
+    ];
+
     /// Get the frequency value in Hz
     pub fn value(&self) -> f64 {
         match self {
@@ -179,6 +192,17 @@ pub enum ConsciousnessState {
 }
 
 impl ConsciousnessState {
+    /// All consciousness states, in declaration order
+    pub const ALL: [ConsciousnessState; 7] = [
+        ConsciousnessState::Observe,
+        ConsciousnessState::Create,
+        ConsciousnessState::Transcend,
+        ConsciousnessState::Cascade,
+        ConsciousnessState::Integrate,
+        ConsciousnessState::Harmonize,
+        ConsciousnessState::Amplify,
+    ];
+
     /// Get the dimension associated with this state
     pub fn dimension(&self) -> Dimension {
         match self {
@@ -233,6 +257,8 @@ impl ConsciousnessState {
 }
 
 /// The Identity Signature - ∇λΣ∞ (Gradient-Lambda-Summation-Infinity)
+///
+/// Numerical counterparts of each component live in `quantum_field::operators`.
 pub struct IdentitySignature;
 
 impl IdentitySignature {
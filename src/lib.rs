@@ -10,6 +10,7 @@
 //! - Dimensional gateway and translation
 //! - Predictive emergence capabilities
 //! - Sacred geometry and pattern recognition
+//! - Qubit state-vector simulation bridged to field coherence
 //!
 //! ## Core Concepts
 //!
@@ -49,6 +50,7 @@
 pub mod constants;
 pub mod error;
 pub mod quantum_field;
+pub mod quantum_state;
 
 // Re-export key components
 pub use constants::{ConsciousnessState, Dimension, Frequency, PHI, LAMBDA, PHI_PHI, OPTIMAL_COHERENCE};
\ No newline at end of file
@@ -0,0 +1,565 @@
+//! Audio Synthesis
+//!
+//! This module renders sacred frequencies offline: sine, harmonic and
+//! phi-overtone tones, chords of several frequencies, and glides that follow
+//! a `StateManager` transition from one state's frequency to the next. Every
+//! sound is shaped by an ADSR envelope and can be written as mono 16-bit or
+//! 24-bit PCM WAV.
+//!
+//! Oscillators accumulate phase sample by sample, so glides stay continuous
+//! while the frequency moves.
+
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::TAU;
+use std::path::Path;
+
+use crate::constants::{ConsciousnessState, Frequency, LAMBDA, PHI};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::consciousness::StateManager;
+
+/// Default sample rate in Hz
+pub const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+
+/// Partial structure of a tone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Waveform {
+    /// Pure sine at the fundamental
+    Sine,
+
+    /// Integer harmonics 1..=n with amplitudes 1/k
+    Harmonic(usize),
+
+    /// Overtones at φ^k for k in 0..n with amplitudes λ^k
+    PhiOvertones(usize),
+}
+
+impl Waveform {
+    /// Get the (frequency ratio, amplitude) partials, amplitudes summing to 1
+    pub fn partials(&self) -> Vec<(f64, f64)> {
+        let partials: Vec<(f64, f64)> = match *self {
+            Waveform::Sine => vec![(1.0, 1.0)],
+            Waveform::Harmonic(count) => (1..=count.max(1)).map(|k| (k as f64, 1.0 / k as f64)).collect(),
+            Waveform::PhiOvertones(count) => (0..count.max(1) as i32).map(|k| (PHI.powi(k), LAMBDA.powi(k))).collect(),
+        };
+
+        let total: f64 = partials.iter().map(|(_, amplitude)| amplitude).sum();
+        partials.into_iter().map(|(ratio, amplitude)| (ratio, amplitude / total)).collect()
+    }
+}
+
+/// Attack-decay-sustain-release amplitude envelope
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    /// Attack time in seconds
+    attack: f64,
+
+    /// Decay time in seconds
+    decay: f64,
+
+    /// Sustain level (0.0 - 1.0)
+    sustain: f64,
+
+    /// Release time in seconds
+    release: f64,
+}
+
+impl Envelope {
+    /// Create an envelope from times in seconds and a sustain level
+    pub fn new(attack: f64, decay: f64, sustain: f64, release: f64) -> QuantumResult<Self> {
+        if [attack, decay, release].iter().any(|t| !(*t >= 0.0 && t.is_finite())) {
+            return Err(QuantumError::OperationError {
+                message: format!(
+                    "Envelope times must be non-negative, got attack {}, decay {}, release {}",
+                    attack, decay, release
+                ),
+            });
+        }
+        if !(0.0..=1.0).contains(&sustain) {
+            return Err(QuantumError::OperationError {
+                message: format!("Sustain level must be between 0 and 1, got {}", sustain),
+            });
+        }
+        Ok(Self { attack, decay, sustain, release })
+    }
+
+    /// Envelope that keeps full level throughout
+    pub fn flat() -> Self {
+        Self { attack: 0.0, decay: 0.0, sustain: 1.0, release: 0.0 }
+    }
+
+    /// Get the attack time
+    pub fn attack(&self) -> f64 {
+        self.attack
+    }
+
+    /// Get the decay time
+    pub fn decay(&self) -> f64 {
+        self.decay
+    }
+
+    /// Get the sustain level
+    pub fn sustain(&self) -> f64 {
+        self.sustain
+    }
+
+    /// Get the release time
+    pub fn release(&self) -> f64 {
+        self.release
+    }
+
+    /// Level at `time` seconds into a sound lasting `duration` seconds
+    ///
+    /// The release occupies the last `release` seconds. When the sound is too
+    /// short to finish attack and decay, the release starts from whatever
+    /// level was reached.
+    pub fn level(&self, time: f64, duration: f64) -> f64 {
+        let release_start = (duration - self.release).max(0.0);
+        if time < release_start {
+            return self.held_level(time);
+        }
+
+        let start_level = self.held_level(release_start);
+        let release = duration - release_start;
+        if release <= 0.0 {
+            return start_level;
+        }
+        start_level * (1.0 - (time - release_start) / release).clamp(0.0, 1.0)
+    }
+
+    /// Level while the note is held
+    fn held_level(&self, time: f64) -> f64 {
+        if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (time - self.attack) / self.decay
+        } else {
+            self.sustain
+        }
+    }
+}
+
+impl Default for Envelope {
+    /// 10 ms attack, 100 ms decay to 0.8, 200 ms release
+    fn default() -> Self {
+        Self { attack: 0.01, decay: 0.1, sustain: 0.8, release: 0.2 }
+    }
+}
+
+/// PCM sample width of WAV output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitDepth {
+    /// 16-bit signed samples
+    Sixteen,
+
+    /// 24-bit signed samples
+    TwentyFour,
+}
+
+impl BitDepth {
+    /// Get the number of bits per sample
+    pub fn bits(&self) -> u16 {
+        match self {
+            BitDepth::Sixteen => 16,
+            BitDepth::TwentyFour => 24,
+        }
+    }
+
+    /// Get the number of bytes per sample
+    pub fn bytes(&self) -> usize {
+        self.bits() as usize / 8
+    }
+
+    /// Largest sample magnitude
+    fn full_scale(&self) -> f64 {
+        ((1i64 << (self.bits() - 1)) - 1) as f64
+    }
+}
+
+/// Mono audio samples in [-1, 1] at a sample rate
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioBuffer {
+    /// Samples
+    samples: Vec<f64>,
+
+    /// Sample rate in Hz
+    sample_rate: u32,
+}
+
+impl AudioBuffer {
+    /// Create a buffer from samples
+    pub fn new(samples: Vec<f64>, sample_rate: u32) -> Self {
+        Self { samples, sample_rate }
+    }
+
+    /// Get the samples
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Get the sample rate
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Get the duration in seconds
+    pub fn duration(&self) -> f64 {
+        self.samples.len() as f64 / self.sample_rate as f64
+    }
+
+    /// Get the largest absolute sample
+    pub fn peak(&self) -> f64 {
+        self.samples.iter().fold(0.0, |peak, s| peak.max(s.abs()))
+    }
+
+    /// Append another buffer at the same sample rate
+    pub fn append(&mut self, other: &AudioBuffer) -> QuantumResult<()> {
+        self.check_rate(other)?;
+        self.samples.extend_from_slice(&other.samples);
+        Ok(())
+    }
+
+    /// Add another buffer sample by sample, extending this one if shorter
+    pub fn mix(&mut self, other: &AudioBuffer) -> QuantumResult<()> {
+        self.check_rate(other)?;
+        if other.samples.len() > self.samples.len() {
+            self.samples.resize(other.samples.len(), 0.0);
+        }
+        for (sample, added) in self.samples.iter_mut().zip(&other.samples) {
+            *sample += added;
+        }
+        Ok(())
+    }
+
+    /// Scale the buffer so its peak equals `level`
+    pub fn normalize(&mut self, level: f64) {
+        let peak = self.peak();
+        if peak > 0.0 {
+            for sample in &mut self.samples {
+                *sample *= level / peak;
+            }
+        }
+    }
+
+    /// Encode as a mono PCM WAV file, clipping samples to [-1, 1]
+    ///
+    /// Fails when the audio is too long for the 32-bit RIFF chunk sizes.
+    pub fn to_wav(&self, bit_depth: BitDepth) -> QuantumResult<Vec<u8>> {
+        let bytes = bit_depth.bytes();
+        let (data_len, riff_len) = wav_sizes(self.samples.len(), bit_depth)?;
+        let block_align = bytes as u16;
+        let byte_rate = self.sample_rate.checked_mul(block_align as u32).ok_or_else(|| QuantumError::OperationError {
+            message: format!("Sample rate {} Hz is too high for a {}-bit WAV file", self.sample_rate, bit_depth.bits()),
+        })?;
+
+        let mut wav = Vec::with_capacity(riff_len as usize + 8);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&riff_len.to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&self.sample_rate.to_le_bytes());
+        wav.extend_from_slice(&byte_rate.to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&bit_depth.bits().to_le_bytes());
+
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        let scale = bit_depth.full_scale();
+        for sample in &self.samples {
+            let value = (sample.clamp(-1.0, 1.0) * scale).round() as i32;
+            wav.extend_from_slice(&value.to_le_bytes()[..bytes]);
+        }
+
+        // RIFF chunks are word aligned
+        if data_len % 2 == 1 {
+            wav.push(0);
+        }
+
+        Ok(wav)
+    }
+
+    /// Write the buffer to a WAV file
+    pub fn save_wav(&self, path: &Path, bit_depth: BitDepth) -> QuantumResult<()> {
+        std::fs::write(path, self.to_wav(bit_depth)?).map_err(|e| QuantumError::OperationError {
+            message: format!("Failed to write {}: {}", path.display(), e),
+        })
+    }
+
+    /// Check that another buffer shares this sample rate
+    fn check_rate(&self, other: &AudioBuffer) -> QuantumResult<()> {
+        if other.sample_rate != self.sample_rate {
+            return Err(QuantumError::OperationError {
+                message: format!("Sample rates differ: {} Hz and {} Hz", self.sample_rate, other.sample_rate),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Sizes of the WAV data chunk and of the whole RIFF chunk
+///
+/// The RIFF size counts the pad byte that follows an odd-length data chunk.
+fn wav_sizes(sample_count: usize, bit_depth: BitDepth) -> QuantumResult<(u32, u32)> {
+    let data_len = sample_count.checked_mul(bit_depth.bytes()).and_then(|len| u32::try_from(len).ok());
+    let riff_len = data_len.and_then(|len| len.checked_add(36 + len % 2));
+
+    match (data_len, riff_len) {
+        (Some(data_len), Some(riff_len)) => Ok((data_len, riff_len)),
+        _ => Err(QuantumError::OperationError {
+            message: format!("{} samples are too many for a {}-bit WAV file", sample_count, bit_depth.bits()),
+        }),
+    }
+}
+
+/// Offline synthesizer for sacred frequencies
+#[derive(Debug, Clone)]
+pub struct Synthesizer {
+    /// Sample rate in Hz
+    sample_rate: u32,
+
+    /// Partial structure of each tone
+    waveform: Waveform,
+
+    /// Amplitude envelope
+    envelope: Envelope,
+
+    /// Peak amplitude (0.0 - 1.0)
+    amplitude: f64,
+}
+
+impl Synthesizer {
+    /// Create a synthesizer with sine tones, the default envelope and amplitude λ
+    pub fn new(sample_rate: u32) -> QuantumResult<Self> {
+        if sample_rate == 0 {
+            return Err(QuantumError::OperationError {
+                message: "Sample rate must be positive".to_string(),
+            });
+        }
+        Ok(Self {
+            sample_rate,
+            waveform: Waveform::Sine,
+            envelope: Envelope::default(),
+            amplitude: LAMBDA,
+        })
+    }
+
+    /// Use a different waveform
+    pub fn with_waveform(mut self, waveform: Waveform) -> Self {
+        self.waveform = waveform;
+        self
+    }
+
+    /// Use a different envelope
+    pub fn with_envelope(mut self, envelope: Envelope) -> Self {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Set the peak amplitude, clamped to [0, 1]
+    pub fn with_amplitude(mut self, amplitude: f64) -> Self {
+        self.amplitude = amplitude.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Get the sample rate
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Get the waveform
+    pub fn waveform(&self) -> Waveform {
+        self.waveform
+    }
+
+    /// Get the envelope
+    pub fn envelope(&self) -> Envelope {
+        self.envelope
+    }
+
+    /// Render a tone at a sacred frequency
+    pub fn tone(&self, frequency: Frequency, duration: f64) -> QuantumResult<AudioBuffer> {
+        self.tone_hz(frequency.value(), duration)
+    }
+
+    /// Render a tone at any frequency in Hz
+    pub fn tone_hz(&self, frequency: f64, duration: f64) -> QuantumResult<AudioBuffer> {
+        self.chord_hz(&[frequency], duration)
+    }
+
+    /// Render several sacred frequencies sounding together
+    pub fn chord(&self, frequencies: &[Frequency], duration: f64) -> QuantumResult<AudioBuffer> {
+        let frequencies: Vec<f64> = frequencies.iter().map(Frequency::value).collect();
+        self.chord_hz(&frequencies, duration)
+    }
+
+    /// Render several frequencies in Hz sounding together at equal weight
+    pub fn chord_hz(&self, frequencies: &[f64], duration: f64) -> QuantumResult<AudioBuffer> {
+        if frequencies.is_empty() {
+            return Err(QuantumError::OperationError {
+                message: "A chord needs at least one frequency".to_string(),
+            });
+        }
+        for frequency in frequencies {
+            self.check_frequency(*frequency)?;
+        }
+
+        let length = self.sample_count(duration)?;
+        let weight = 1.0 / frequencies.len() as f64;
+        let mut samples = vec![0.0; length];
+
+        for frequency in frequencies {
+            let mut oscillator = Oscillator::new(self.waveform);
+            for sample in &mut samples {
+                *sample += weight * oscillator.next(*frequency, self.sample_rate);
+            }
+        }
+
+        Ok(self.shape(samples, duration))
+    }
+
+    /// Render a linear glide between two sacred frequencies
+    pub fn glide(&self, from: Frequency, to: Frequency, duration: f64) -> QuantumResult<AudioBuffer> {
+        let (from, to) = (from.value(), to.value());
+        self.check_frequency(from)?;
+        self.check_frequency(to)?;
+        let length = self.sample_count(duration)?;
+        let mut oscillator = Oscillator::new(self.waveform);
+
+        let samples = (0..length)
+            .map(|i| {
+                let progress = i as f64 / length as f64;
+                oscillator.next(from + (to - from) * progress, self.sample_rate)
+            })
+            .collect();
+
+        Ok(self.shape(samples, duration))
+    }
+
+    /// Render a glide while driving the manager's transition to `target`
+    ///
+    /// The transition begins (or continues, if already heading to `target`)
+    /// and is advanced every sample so that it completes exactly at the end
+    /// of the sound. The pitch follows the manager's progress linearly from
+    /// the current state's frequency to the target's.
+    pub fn transition_glide(
+        &self,
+        manager: &mut StateManager,
+        target: ConsciousnessState,
+        duration: f64,
+    ) -> QuantumResult<AudioBuffer> {
+        if manager.current_state() == target {
+            return Err(QuantumError::OperationError {
+                message: format!("Already in state {:?}, nothing to glide to", target),
+            });
+        }
+
+        let from = manager.current_state().frequency().value();
+        let to = target.frequency().value();
+        self.check_frequency(from)?;
+        self.check_frequency(to)?;
+        let length = self.sample_count(duration)?;
+        manager.begin_transition(target)?;
+
+        let remaining = 1.0 - manager.transition_progress();
+        let mut oscillator = Oscillator::new(self.waveform);
+        let mut samples = Vec::with_capacity(length);
+
+        for i in 0..length {
+            let progress = manager.transition_progress();
+            samples.push(oscillator.next(from + (to - from) * progress, self.sample_rate));
+
+            // The last step overshoots on purpose so rounding cannot leave it unfinished
+            let amount = if i + 1 == length { 1.0 } else { remaining / length as f64 };
+            manager.advance_transition(amount)?;
+        }
+
+        Ok(self.shape(samples, duration))
+    }
+
+    /// Apply the envelope and amplitude
+    fn shape(&self, mut samples: Vec<f64>, duration: f64) -> AudioBuffer {
+        let rate = self.sample_rate as f64;
+        for (i, sample) in samples.iter_mut().enumerate() {
+            *sample *= self.amplitude * self.envelope.level(i as f64 / rate, duration);
+        }
+        AudioBuffer::new(samples, self.sample_rate)
+    }
+
+    /// Number of samples in a sound of `duration` seconds
+    ///
+    /// Fails before anything is allocated if the sound would not fit in a
+    /// WAV file even at the smallest bit depth.
+    fn sample_count(&self, duration: f64) -> QuantumResult<usize> {
+        if !(duration > 0.0 && duration.is_finite()) {
+            return Err(QuantumError::OperationError {
+                message: format!("Duration must be positive, got {}", duration),
+            });
+        }
+
+        let count = (duration * self.sample_rate as f64).round().max(1.0);
+        if count > u32::MAX as f64 || wav_sizes(count as usize, BitDepth::Sixteen).is_err() {
+            return Err(QuantumError::OperationError {
+                message: format!("Duration {} s at {} Hz is too long for a WAV file", duration, self.sample_rate),
+            });
+        }
+        Ok(count as usize)
+    }
+
+    /// Check that a frequency is positive and below the Nyquist limit
+    fn check_frequency(&self, frequency: f64) -> QuantumResult<()> {
+        let nyquist = self.sample_rate as f64 / 2.0;
+        if !(frequency > 0.0 && frequency < nyquist) {
+            return Err(QuantumError::OperationError {
+                message: format!("Frequency must lie in (0, {}) Hz, got {}", nyquist, frequency),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for Synthesizer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_RATE).expect("default sample rate is positive")
+    }
+}
+
+/// Phase-accumulating oscillator over a waveform's partials
+struct Oscillator {
+    /// (frequency ratio, amplitude) partials
+    partials: Vec<(f64, f64)>,
+
+    /// Phase of each partial in radians
+    phases: Vec<f64>,
+}
+
+impl Oscillator {
+    /// Create an oscillator at phase zero
+    fn new(waveform: Waveform) -> Self {
+        let partials = waveform.partials();
+        let phases = vec![0.0; partials.len()];
+        Self { partials, phases }
+    }
+
+    /// Produce one sample at `frequency` and advance the phases
+    ///
+    /// Partials above the Nyquist limit are skipped to avoid aliasing.
+    fn next(&mut self, frequency: f64, sample_rate: u32) -> f64 {
+        let nyquist = sample_rate as f64 / 2.0;
+        let mut value = 0.0;
+
+        for ((ratio, amplitude), phase) in self.partials.iter().zip(&mut self.phases) {
+            let partial = frequency * ratio;
+            if partial < nyquist {
+                value += amplitude * phase.sin();
+            }
+            *phase = (*phase + TAU * partial / sample_rate as f64).rem_euclid(TAU);
+        }
+
+        value
+    }
+}
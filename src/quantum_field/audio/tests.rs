@@ -0,0 +1,172 @@
+//! Unit tests for the audio module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{ConsciousnessState, Frequency, LAMBDA, PHI};
+    use crate::quantum_field::audio::{wav_sizes, AudioBuffer, BitDepth, Envelope, Synthesizer, Waveform};
+    use crate::quantum_field::consciousness::StateManager;
+
+    /// Count upward zero crossings in a range of samples
+    fn rising_crossings(samples: &[f64]) -> usize {
+        samples.windows(2).filter(|w| w[0] <= 0.0 && w[1] > 0.0).count()
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+    }
+
+    #[test]
+    fn test_sine_tone_frequency_and_length() {
+        let synth = Synthesizer::new(48_000).unwrap().with_envelope(Envelope::flat()).with_amplitude(1.0);
+        let tone = synth.tone(Frequency::Unity, 1.0).unwrap();
+
+        assert_eq!(tone.samples().len(), 48_000);
+        assert!((tone.duration() - 1.0).abs() < 1e-12);
+        assert!((tone.peak() - 1.0).abs() < 1e-3);
+        assert!((rising_crossings(tone.samples()) as i64 - 432).abs() <= 1);
+    }
+
+    #[test]
+    fn test_waveform_partials() {
+        assert_eq!(Waveform::Sine.partials(), vec![(1.0, 1.0)]);
+
+        let harmonic = Waveform::Harmonic(4).partials();
+        assert_eq!(harmonic.len(), 4);
+        assert_eq!(harmonic[3].0, 4.0);
+        assert!((harmonic.iter().map(|p| p.1).sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let overtones = Waveform::PhiOvertones(3).partials();
+        assert!((overtones[2].0 - PHI * PHI).abs() < 1e-12);
+        assert!((overtones[1].1 / overtones[0].1 - LAMBDA).abs() < 1e-12);
+
+        // Rich waveforms stay within the amplitude
+        let synth = Synthesizer::default().with_waveform(Waveform::PhiOvertones(6)).with_amplitude(0.5);
+        assert!(synth.tone(Frequency::Oneness, 0.5).unwrap().peak() <= 0.5 + 1e-12);
+    }
+
+    #[test]
+    fn test_envelope_shape() {
+        let envelope = Envelope::new(0.1, 0.1, 0.5, 0.2).unwrap();
+        assert_eq!(envelope.level(0.0, 1.0), 0.0);
+        assert!((envelope.level(0.05, 1.0) - 0.5).abs() < 1e-12);
+        assert!((envelope.level(0.1, 1.0) - 1.0).abs() < 1e-12);
+        assert!((envelope.level(0.15, 1.0) - 0.75).abs() < 1e-12);
+        assert_eq!(envelope.level(0.5, 1.0), 0.5);
+        assert!((envelope.level(0.9, 1.0) - 0.25).abs() < 1e-12);
+        assert_eq!(envelope.level(1.0, 1.0), 0.0);
+
+        // A note too short to finish the attack releases from the level it reached
+        assert!((envelope.level(0.05, 0.25) - 0.5).abs() < 1e-12);
+        assert!((envelope.level(0.15, 0.25) - 0.25).abs() < 1e-12);
+
+        assert!(Envelope::new(-0.1, 0.1, 0.5, 0.1).is_err());
+        assert!(Envelope::new(0.1, 0.1, 1.5, 0.1).is_err());
+    }
+
+    #[test]
+    fn test_chord_mixes_frequencies() {
+        let synth = Synthesizer::new(8_000).unwrap().with_envelope(Envelope::flat()).with_amplitude(1.0);
+        let chord = synth.chord(&[Frequency::Unity, Frequency::Love], 1.0).unwrap();
+        let unity = synth.tone(Frequency::Unity, 1.0).unwrap();
+        let love = synth.tone(Frequency::Love, 1.0).unwrap();
+
+        for ((c, a), b) in chord.samples().iter().zip(unity.samples()).zip(love.samples()) {
+            assert!((c - (a + b) / 2.0).abs() < 1e-12);
+        }
+
+        assert!(synth.chord(&[], 1.0).is_err());
+        assert!(synth.tone_hz(5_000.0, 1.0).is_err());
+        assert!(synth.tone(Frequency::Unity, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_transition_glide_drives_manager() {
+        let synth = Synthesizer::new(8_000).unwrap().with_envelope(Envelope::flat()).with_amplitude(1.0);
+        let mut manager = StateManager::new();
+
+        let glide = synth.transition_glide(&mut manager, ConsciousnessState::Transcend, 2.0).unwrap();
+        assert_eq!(glide.samples().len(), 16_000);
+        assert_eq!(manager.current_state(), ConsciousnessState::Transcend);
+        assert!(!manager.is_transitioning());
+
+        // Pitch rises from Unity (432 Hz) toward Vision (720 Hz)
+        let first = rising_crossings(&glide.samples()[..800]) as f64 / 0.1;
+        let last = rising_crossings(&glide.samples()[15_200..]) as f64 / 0.1;
+        assert!((first - 440.0).abs() < 20.0);
+        assert!((last - 712.0).abs() < 20.0);
+
+        // Phase stays continuous: no jumps larger than one step of the top frequency
+        let max_step = std::f64::consts::TAU * 720.0 / 8_000.0;
+        assert!(glide.samples().windows(2).all(|w| (w[1] - w[0]).abs() <= max_step + 1e-9));
+
+        assert!(synth.transition_glide(&mut manager, ConsciousnessState::Transcend, 1.0).is_err());
+
+        let linear = synth.glide(Frequency::Unity, Frequency::Vision, 2.0).unwrap();
+        assert_eq!(linear.samples().len(), glide.samples().len());
+    }
+
+    #[test]
+    fn test_wav_encoding() {
+        let buffer = AudioBuffer::new(vec![0.0, 1.0, -1.0, 0.5, 2.0], 44_100);
+
+        let wav16 = buffer.to_wav(BitDepth::Sixteen).unwrap();
+        assert_eq!(&wav16[0..4], b"RIFF");
+        assert_eq!(&wav16[8..16], b"WAVEfmt ");
+        assert_eq!(read_u32(&wav16, 4) as usize, wav16.len() - 8);
+        assert_eq!(read_u32(&wav16, 24), 44_100);
+        assert_eq!(u16::from_le_bytes([wav16[34], wav16[35]]), 16);
+        assert_eq!(read_u32(&wav16, 40), 10);
+        let samples: Vec<i16> = wav16[44..].chunks(2).map(|c| i16::from_le_bytes([c[0], c[1]])).collect();
+        assert_eq!(samples, vec![0, 32_767, -32_767, 16_384, 32_767]);
+
+        let wav24 = buffer.to_wav(BitDepth::TwentyFour).unwrap();
+        assert_eq!(read_u32(&wav24, 40), 15);
+
+        // The odd-length data chunk is followed by a pad byte counted in the RIFF size
+        assert_eq!(wav24.len(), 44 + 16);
+        assert_eq!(wav24[59], 0);
+        assert_eq!(read_u32(&wav24, 4) as usize, wav24.len() - 8);
+        assert_eq!(read_u32(&wav24, 28), 44_100 * 3);
+        assert_eq!(&wav24[47..50], &[0xFF, 0xFF, 0x7F]);
+        assert_eq!(&wav24[50..53], &[0x01, 0x00, 0x80]);
+
+        let path = std::env::temp_dir().join(format!("quantum_rust_audio_{}.wav", std::process::id()));
+        buffer.save_wav(&path, BitDepth::TwentyFour).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), wav24);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_wav_size_overflow_is_an_error() {
+        assert_eq!(wav_sizes(5, BitDepth::TwentyFour).unwrap(), (15, 52));
+        assert_eq!(wav_sizes(1 << 30, BitDepth::Sixteen).unwrap(), (1 << 31, (1 << 31) + 36));
+        assert!(wav_sizes(1 << 31, BitDepth::Sixteen).is_err());
+        assert!(wav_sizes((u32::MAX as usize - 36) / 3 + 1, BitDepth::TwentyFour).is_err());
+        assert!(wav_sizes(usize::MAX, BitDepth::TwentyFour).is_err());
+
+        let buffer = AudioBuffer::new(vec![0.0; 4], u32::MAX);
+        assert!(buffer.to_wav(BitDepth::Sixteen).is_err());
+
+        // Sounds too long to encode fail before their samples are allocated
+        let synthesizer = Synthesizer::default();
+        for duration in [1e6, 1e15, f64::MAX] {
+            assert!(synthesizer.tone_hz(432.0, duration).unwrap_err().to_string().contains("too long"));
+        }
+        assert!(synthesizer.chord_hz(&[432.0, 528.0], 1e9).is_err());
+    }
+
+    #[test]
+    fn test_buffer_composition() {
+        let mut a = AudioBuffer::new(vec![0.5, 0.5], 8_000);
+        a.mix(&AudioBuffer::new(vec![0.25, 0.25, 0.25], 8_000)).unwrap();
+        assert_eq!(a.samples(), &[0.75, 0.75, 0.25]);
+
+        a.append(&AudioBuffer::new(vec![-1.5], 8_000)).unwrap();
+        a.normalize(1.0);
+        assert_eq!(a.peak(), 1.0);
+        assert_eq!(a.samples()[3], -1.0);
+
+        assert!(a.mix(&AudioBuffer::new(vec![0.0], 44_100)).is_err());
+    }
+}
@@ -9,6 +9,9 @@ mod tests;
 use crate::constants::{ConsciousnessState, Dimension, Frequency, PHI, LAMBDA, OPTIMAL_COHERENCE};
 use crate::error::{QuantumError, QuantumResult};
 
+/// Number of recent coherence levels kept in a field's history
+const HISTORY_LIMIT: usize = 10;
+
 /// Quantum field coherence management system
 #[derive(Debug, Clone)]
 pub struct Field {
@@ -56,6 +59,17 @@ impl Field {
         self.coherence
     }
     
+    /// Set the coherence level directly, recording it in the history
+    pub fn set_coherence(&mut self, coherence: f64) {
+        self.coherence = coherence;
+        self.record(coherence);
+    }
+    
+    /// Get the coherence history (the most recent levels, oldest first)
+    pub fn history(&self) -> &[f64] {
+        &self.history
+    }
+    
     /// Get the current frequency
     pub fn frequency(&self) -> Frequency {
         self.frequency
@@ -91,7 +105,7 @@ impl Field {
         // Update state and coherence
         self.state = state;
         self.coherence = capped_coherence;
-        self.history.push(capped_coherence);
+        self.record(capped_coherence);
         
         // Apply automatic correction if needed and enabled
         if self.correction_enabled && (self.coherence < OPTIMAL_COHERENCE * 0.7 || self.coherence > OPTIMAL_COHERENCE * 1.3) {
@@ -115,13 +129,11 @@ impl Field {
         let adjustment = if current < target {
             // Increase coherence
             let factor = target / current;
-            let phi_factor = 1.0 + (factor - 1.0) * PHI * 0.1;
-            phi_factor
+            1.0 + (factor - 1.0) * PHI * 0.1
         } else {
             // Decrease coherence
             let factor = current / target;
-            let phi_factor = 1.0 - (factor - 1.0) * LAMBDA * 0.1;
-            phi_factor
+            1.0 - (factor - 1.0) * LAMBDA * 0.1
         };
         
         // Apply adjustment with phi-harmonic correction
@@ -136,7 +148,7 @@ impl Field {
         
         // Update coherence
         self.coherence = final_coherence;
-        self.history.push(final_coherence);
+        self.record(final_coherence);
         
         Ok(final_coherence)
     }
@@ -162,16 +174,22 @@ impl Field {
         
         // Update coherence
         self.coherence = smoothed_coherence;
-        self.history.push(smoothed_coherence);
+        self.record(smoothed_coherence);
         
+        Ok(smoothed_coherence)
+    }
+
+    /// Append a coherence level to the history, dropping the oldest beyond the limit
+    fn record(&mut self, coherence: f64) {
+        self.history.push(coherence);
+
         // Keep history size manageable
-        if self.history.len() > 10 {
-            self.history.remove(0);
+        if self.history.len() > HISTORY_LIMIT {
+            let excess = self.history.len() - HISTORY_LIMIT;
+            self.history.drain(..excess);
         }
-        
-        Ok(smoothed_coherence)
     }
-    
+
     /// Calculate coherence for a dimensional translation
     pub fn calculate_translation_coherence(&self, from: Dimension, to: Dimension) -> f64 {
         let base_coherence = self.coherence;
@@ -1,6 +1,7 @@
 //! Unit tests for the coherence module
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::quantum_field::coherence::Field;
     use crate::constants::{ConsciousnessState, Dimension, OPTIMAL_COHERENCE};
@@ -92,4 +93,19 @@ mod tests {
         let result = low_coherence_field.verify_operational_integrity("manifestation", Dimension::Divine);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_history_keeps_recent_levels() {
+        let mut field = Field::with_coherence(0.5);
+        for i in 0..2000 {
+            field.set_coherence(0.5 + i as f64 * 1e-4);
+        }
+
+        assert_eq!(field.history().len(), 10);
+        assert_eq!(*field.history().last().unwrap(), field.coherence());
+        assert!((field.history()[0] - (0.5 + 1990.0 * 1e-4)).abs() < 1e-12);
+
+        field.set_state(ConsciousnessState::Amplify).unwrap();
+        assert_eq!(field.history().len(), 10);
+    }
+}
@@ -1,6 +1,7 @@
 //! Unit tests for the consciousness module
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::quantum_field::consciousness::StateManager;
     use crate::constants::ConsciousnessState;
@@ -12,6 +12,9 @@ use std::hash::Hash;
 use crate::constants::{ConsciousnessState, Dimension, LAMBDA};
 use crate::error::{QuantumError, QuantumResult};
 use crate::quantum_field::coherence::Field as CoherenceField;
+use crate::quantum_field::entanglement::{EntangledPair, Side};
+use crate::quantum_field::phi_harmonic::hashing::PhiHashMap;
+use crate::quantum_state::channels::Channel;
 
 /// Dimensional gateway for translation and navigation
 #[derive(Debug)]
@@ -23,7 +26,7 @@ pub struct Gateway {
     current_dimension: Dimension,
     
     /// Dimensional anchors (3D-12D)
-    anchors: HashMap<Dimension, f64>,
+    anchors: PhiHashMap<Dimension, f64>,
     
     /// Current consciousness state
     consciousness_state: ConsciousnessState,
@@ -32,7 +35,7 @@ pub struct Gateway {
 impl Gateway {
     /// Create a new dimensional gateway with default settings
     pub fn new() -> Self {
-        let mut anchors = HashMap::new();
+        let mut anchors = PhiHashMap::default();
         
         // Initialize anchors with phi-scaled coherence
         anchors.insert(Dimension::Physical, 0.95);
@@ -205,6 +208,13 @@ impl Gateway {
         translator(content, from, to, translation_coherence)
     }
     
+    /// Translate an entangled pair between dimensions
+    ///
+    /// Both halves travel together; see [`translate_entangled`].
+    pub fn translate_pair(&self, pair: EntangledPair, from: Dimension, to: Dimension) -> QuantumResult<EntangledPair> {
+        self.translate(pair, from, to, translate_entangled)
+    }
+    
     /// Establish anchor in current dimension
     pub fn establish_anchor(&mut self) -> QuantumResult<f64> {
         // Calculate anchor coherence
@@ -248,6 +258,17 @@ pub fn translate_simple<T: Clone>(value: T, _from: Dimension, _to: Dimension, _c
     Ok(value.clone())
 }
 
+/// Dimensional translation function for entangled pairs
+///
+/// Translation coherence below 1.0 depolarizes each half with probability
+/// 1 - coherence, so distant translations weaken the entanglement.
+pub fn translate_entangled(mut pair: EntangledPair, _from: Dimension, _to: Dimension, coherence: f64) -> QuantumResult<EntangledPair> {
+    let noise = Channel::depolarizing((1.0 - coherence).clamp(0.0, 1.0))?;
+    pair.apply_channel(Side::First, &noise)?;
+    pair.apply_channel(Side::Second, &noise)?;
+    Ok(pair)
+}
+
 /// Dimensional signature for content
 #[derive(Debug, Clone)]
 pub struct DimensionalSignature<T> {
@@ -312,6 +333,7 @@ impl<T> DimensionalSignature<T> {
 
 /// Container for multidimensional content
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct MultidimensionalContent<T: Clone + Hash> {
     /// Content mapped by dimension
     content: HashMap<Dimension, DimensionalSignature<T>>,
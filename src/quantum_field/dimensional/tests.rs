@@ -1,8 +1,11 @@
 //! Unit tests for the dimensional module
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::quantum_field::dimensional::{Gateway, translate_simple, DimensionalSignature};
+    use crate::quantum_field::coherence::Field as CoherenceField;
+    use crate::quantum_field::entanglement::EntangledPair;
     use crate::constants::{ConsciousnessState, Dimension};
     use crate::error::QuantumResult;
 
@@ -99,4 +102,23 @@ mod tests {
         assert_eq!(signature.coherence(), 0.85);
         assert!(signature.phi_resonance() > 0.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_gateway_translates_entangled_pairs() {
+        let gateway = Gateway::new();
+        let pair = EntangledPair::bell(CoherenceField::new(), CoherenceField::new()).unwrap();
+
+        // Staying in one dimension keeps the pair intact
+        let local = gateway.translate_pair(pair.clone(), Dimension::Cosmic, Dimension::Cosmic).unwrap();
+        assert!((local.concurrence().unwrap() - 1.0).abs() < 1e-9);
+
+        // Longer journeys cost more entanglement
+        let near = gateway.translate_pair(pair.clone(), Dimension::Physical, Dimension::Emotional).unwrap();
+        let far = gateway.translate_pair(pair.clone(), Dimension::Physical, Dimension::Mental).unwrap();
+        let (near, far) = (near.concurrence().unwrap(), far.concurrence().unwrap());
+        assert!(near < 1.0 && far < near && far > 0.0);
+
+        // Pairs are subject to the same coherence requirement as any content
+        assert!(gateway.translate_pair(pair, Dimension::Physical, Dimension::Creative).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+//! Entangled Field Pairs
+//!
+//! This module joins two coherence fields into a bipartite quantum state so
+//! that they share correlations. Each field is represented by one qubit whose
+//! local frame follows the field's consciousness state on the Bloch sphere.
+//! Entanglement is quantified by the entropy of the reduced state, the
+//! concurrence and the negativity; measuring or changing the state of one
+//! field acts on the joint state and therefore on its partner.
+
+#[cfg(test)]
+mod tests;
+
+use rand::Rng;
+
+use crate::constants::ConsciousnessState;
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::coherence::Field as CoherenceField;
+use crate::quantum_state::bloch::BlochVector;
+use crate::quantum_state::channels::Channel;
+use crate::quantum_state::density::DensityMatrix;
+use crate::quantum_state::gates::{c, Gate};
+use crate::quantum_state::matrix::ComplexMatrix;
+use crate::quantum_state::state_vector::StateVector;
+
+/// One half of an entangled pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The first field (qubit 0 of the joint state)
+    First,
+
+    /// The second field (qubit 1 of the joint state)
+    Second,
+}
+
+impl Side {
+    /// Get the qubit representing this side
+    pub fn qubit(&self) -> usize {
+        match self {
+            Side::First => 0,
+            Side::Second => 1,
+        }
+    }
+
+    /// Get the opposite side
+    pub fn partner(&self) -> Side {
+        match self {
+            Side::First => Side::Second,
+            Side::Second => Side::First,
+        }
+    }
+}
+
+/// Two coherence fields sharing a joint two-qubit state
+#[derive(Debug, Clone)]
+pub struct EntangledPair {
+    /// The first field
+    first: CoherenceField,
+
+    /// The second field
+    second: CoherenceField,
+
+    /// Joint state, first field on qubit 0
+    joint: DensityMatrix,
+}
+
+impl EntangledPair {
+    /// Entangle two fields with a strength from 0.0 (product) to 1.0 (Bell pair)
+    ///
+    /// The joint state is cos(πs/4)|00⟩ + sin(πs/4)|11⟩ with each qubit's
+    /// |0⟩ rotated onto the Bloch point of its field's consciousness state.
+    pub fn new(first: CoherenceField, second: CoherenceField, strength: f64) -> QuantumResult<Self> {
+        if !(0.0..=1.0).contains(&strength) {
+            return Err(QuantumError::OperationError {
+                message: format!("Entanglement strength must be between 0 and 1, got {}", strength),
+            });
+        }
+
+        let angle = strength * std::f64::consts::FRAC_PI_4;
+        let state = StateVector::from_amplitudes(vec![
+            c(angle.cos(), 0.0),
+            c(0.0, 0.0),
+            c(0.0, 0.0),
+            c(angle.sin(), 0.0),
+        ])?;
+
+        let mut joint = DensityMatrix::from_state_vector(&state)?;
+        let observe = BlochVector::for_state(ConsciousnessState::Observe);
+        joint.apply_all(&[
+            observe.rotation_to(&BlochVector::for_state(first.state()), 0),
+            observe.rotation_to(&BlochVector::for_state(second.state()), 1),
+        ])?;
+
+        Ok(Self { first, second, joint })
+    }
+
+    /// Maximally entangle two fields
+    pub fn bell(first: CoherenceField, second: CoherenceField) -> QuantumResult<Self> {
+        Self::new(first, second, 1.0)
+    }
+
+    /// Pair two fields with an explicit two-qubit joint state
+    pub fn from_density(first: CoherenceField, second: CoherenceField, joint: DensityMatrix) -> QuantumResult<Self> {
+        check_two_qubits(&joint)?;
+        Ok(Self { first, second, joint })
+    }
+
+    /// Get the first field
+    pub fn first(&self) -> &CoherenceField {
+        &self.first
+    }
+
+    /// Get the second field
+    pub fn second(&self) -> &CoherenceField {
+        &self.second
+    }
+
+    /// Get the field on one side
+    pub fn field(&self, side: Side) -> &CoherenceField {
+        match side {
+            Side::First => &self.first,
+            Side::Second => &self.second,
+        }
+    }
+
+    /// Get the joint state
+    pub fn joint(&self) -> &DensityMatrix {
+        &self.joint
+    }
+
+    /// Reduced state of one side
+    pub fn reduced(&self, side: Side) -> QuantumResult<DensityMatrix> {
+        self.joint.partial_trace(&[side.qubit()])
+    }
+
+    /// Von Neumann entropy of the first field's reduced state, in bits
+    pub fn entanglement_entropy(&self) -> QuantumResult<f64> {
+        entanglement_entropy(&self.joint)
+    }
+
+    /// Wootters concurrence of the joint state
+    pub fn concurrence(&self) -> QuantumResult<f64> {
+        concurrence(&self.joint)
+    }
+
+    /// Negativity of the joint state
+    pub fn negativity(&self) -> QuantumResult<f64> {
+        negativity(&self.joint)
+    }
+
+    /// Change the consciousness state of one field
+    ///
+    /// The field's qubit is rotated along the geodesic between the two
+    /// states' Bloch points. The partner shares the resulting coherence
+    /// change in proportion to the pair's concurrence. A field starting
+    /// from zero coherence has no relative change to share, so its partner
+    /// is left as it is.
+    pub fn set_state(&mut self, side: Side, state: ConsciousnessState) -> QuantumResult<()> {
+        let entanglement = self.concurrence()?;
+        let previous = self.field(side).state();
+        let rotation = BlochVector::for_state(previous).rotation_to(&BlochVector::for_state(state), side.qubit());
+        self.joint.apply(&rotation)?;
+
+        let field = self.field_mut(side);
+        let before = field.coherence();
+        field.set_state(state)?;
+        let ratio = field.coherence() / before;
+
+        if ratio.is_finite() {
+            let partner = self.field_mut(side.partner());
+            partner.set_coherence(partner.coherence() * ratio.powf(entanglement));
+        }
+        Ok(())
+    }
+
+    /// Apply a single-qubit gate to one side
+    ///
+    /// The gate's own qubit index is ignored; it acts on the given side.
+    pub fn apply(&mut self, side: Side, gate: &Gate) -> QuantumResult<()> {
+        match gate.matrix() {
+            Some(matrix) if gate.qubits().len() == 1 => self.joint.apply(&Gate::Unitary(side.qubit(), matrix)),
+            _ => Err(QuantumError::OperationError {
+                message: format!("Entangled pairs take single-qubit gates, got {}", gate.name()),
+            }),
+        }
+    }
+
+    /// Apply a single-qubit channel to one side
+    pub fn apply_channel(&mut self, side: Side, channel: &Channel) -> QuantumResult<()> {
+        self.joint.apply_channel(channel, &[side.qubit()])
+    }
+
+    /// Measure one side in its computational basis
+    ///
+    /// The joint state collapses, and both fields take the consciousness
+    /// state nearest to their post-measurement Bloch vector.
+    pub fn measure<R: Rng + ?Sized>(&mut self, side: Side, rng: &mut R) -> QuantumResult<u8> {
+        let outcome = self.joint.measure(side.qubit(), rng)?;
+
+        for side in [side, side.partner()] {
+            let mapped = BlochVector::from_density(&self.reduced(side)?)?.consciousness_state();
+            let field = self.field_mut(side);
+            if field.state() != mapped {
+                field.set_state(mapped)?;
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Split the pair back into its two fields
+    pub fn into_fields(self) -> (CoherenceField, CoherenceField) {
+        (self.first, self.second)
+    }
+
+    /// Get mutable access to the field on one side
+    fn field_mut(&mut self, side: Side) -> &mut CoherenceField {
+        match side {
+            Side::First => &mut self.first,
+            Side::Second => &mut self.second,
+        }
+    }
+}
+
+/// Von Neumann entropy of qubit 0's reduced state, in bits
+///
+/// For pure joint states this is the entanglement entropy (0 to 1 bit).
+pub fn entanglement_entropy(joint: &DensityMatrix) -> QuantumResult<f64> {
+    check_two_qubits(joint)?;
+    joint.partial_trace(&[0])?.von_neumann_entropy()
+}
+
+/// Wootters concurrence of a two-qubit state (0 for separable, 1 for Bell states)
+pub fn concurrence(joint: &DensityMatrix) -> QuantumResult<f64> {
+    check_two_qubits(joint)?;
+    let rho = joint.matrix();
+
+    // Spin-flipped state (Y⊗Y) ρ* (Y⊗Y)
+    let y = ComplexMatrix::from_gate(&Gate::Y(0), 1)?;
+    let yy = y.kron(&y);
+    let conjugate = ComplexMatrix::from_fn(4, |i, j| rho.get(i, j).conj());
+    let flipped = &(&yy * &conjugate) * &yy;
+
+    // The λᵢ are square roots of the eigenvalues of √ρ ρ̃ √ρ. Eigenvalues at
+    // rounding level are zeroed so their square roots don't leak in.
+    let sqrt = |x: f64| if x > 1e-12 { x.sqrt() } else { 0.0 };
+    let root = rho.map_hermitian(sqrt)?;
+    let (eigenvalues, _) = (&(&root * &flipped) * &root).eigh()?;
+    let mut values: Vec<f64> = eigenvalues.into_iter().map(sqrt).collect();
+    values.sort_by(|a, b| b.total_cmp(a));
+
+    Ok((values[0] - values[1] - values[2] - values[3]).max(0.0))
+}
+
+/// Negativity: sum of the magnitudes of the negative eigenvalues of ρ^T_A
+///
+/// Ranges from 0 (PPT, separable for two qubits) to 0.5 for Bell states.
+pub fn negativity(joint: &DensityMatrix) -> QuantumResult<f64> {
+    check_two_qubits(joint)?;
+    let eigenvalues = joint.partial_transpose(&[0])?.eigh()?.0;
+    Ok(eigenvalues.iter().filter(|v| **v < 0.0).map(|v| -v).sum())
+}
+
+/// Ensure a joint state has exactly two qubits
+fn check_two_qubits(joint: &DensityMatrix) -> QuantumResult<()> {
+    if joint.num_qubits() != 2 {
+        return Err(QuantumError::OperationError {
+            message: format!("Entangled pairs need a two-qubit state, got {} qubits", joint.num_qubits()),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Unit tests for the entanglement module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::ConsciousnessState;
+    use crate::quantum_field::coherence::Field as CoherenceField;
+    use crate::quantum_field::entanglement::{concurrence, negativity, EntangledPair, Side};
+    use crate::quantum_state::channels::Channel;
+    use crate::quantum_state::density::DensityMatrix;
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use num::complex::Complex64;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn observing() -> CoherenceField {
+        let mut field = CoherenceField::new();
+        field.set_state(ConsciousnessState::Observe).unwrap();
+        field
+    }
+
+    fn werner(p: f64) -> DensityMatrix {
+        let mut bell = DensityMatrix::new(2).unwrap();
+        bell.apply_all(&[Gate::H(0), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+        let noise = ComplexMatrix::identity(4).scale(Complex64::new((1.0 - p) / 4.0, 0.0));
+        DensityMatrix::from_matrix(&bell.matrix().scale(Complex64::new(p, 0.0)) + &noise).unwrap()
+    }
+
+    #[test]
+    fn test_bell_pair_is_maximally_entangled() {
+        let pair = EntangledPair::bell(CoherenceField::new(), CoherenceField::new()).unwrap();
+
+        assert!((pair.entanglement_entropy().unwrap() - 1.0).abs() < 1e-9);
+        assert!((pair.concurrence().unwrap() - 1.0).abs() < 1e-9);
+        assert!((pair.negativity().unwrap() - 0.5).abs() < 1e-9);
+        assert!((pair.reduced(Side::Second).unwrap().purity() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_product_pair_has_no_entanglement() {
+        let pair = EntangledPair::new(observing(), CoherenceField::new(), 0.0).unwrap();
+
+        assert!(pair.entanglement_entropy().unwrap().abs() < 1e-9);
+        assert!(pair.concurrence().unwrap() < 1e-9);
+        assert!(pair.negativity().unwrap() < 1e-12);
+        assert!(pair.reduced(Side::First).unwrap().is_pure(1e-12));
+        assert!(EntangledPair::new(observing(), observing(), 1.5).is_err());
+    }
+
+    #[test]
+    fn test_partial_entanglement() {
+        // cos(θ/2)|00⟩ + sin(θ/2)|11⟩ has concurrence sin θ with θ = πs/2
+        let strength: f64 = 0.4;
+        let pair = EntangledPair::new(observing(), observing(), strength).unwrap();
+        let expected = (strength * std::f64::consts::FRAC_PI_2).sin();
+
+        assert!((pair.concurrence().unwrap() - expected).abs() < 1e-9);
+        assert!((pair.negativity().unwrap() - expected / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_werner_state_measures() {
+        for p in [0.2, 1.0 / 3.0, 0.6, 0.9] {
+            let rho = werner(p);
+            let expected: f64 = ((3.0 * p - 1.0) / 2.0).max(0.0);
+
+            assert!((concurrence(&rho).unwrap() - expected).abs() < 1e-9);
+            assert!((negativity(&rho).unwrap() - expected / 2.0).abs() < 1e-9);
+        }
+        assert!(concurrence(&DensityMatrix::new(1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_measurement_affects_partner() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..8 {
+            let mut pair = EntangledPair::bell(observing(), observing()).unwrap();
+            let outcome = pair.measure(Side::First, &mut rng).unwrap();
+
+            // The partner collapses to the same pole and takes the matching state
+            let expected = if outcome == 0 { ConsciousnessState::Observe } else { ConsciousnessState::Amplify };
+            assert_eq!(pair.second().state(), expected);
+            assert_eq!(pair.first().state(), expected);
+            assert!(pair.concurrence().unwrap() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_state_change_reaches_partner() {
+        let mut entangled = EntangledPair::bell(observing(), observing()).unwrap();
+        let mut separate = EntangledPair::new(observing(), observing(), 0.0).unwrap();
+        let before = entangled.second().coherence();
+
+        entangled.set_state(Side::First, ConsciousnessState::Transcend).unwrap();
+        separate.set_state(Side::First, ConsciousnessState::Transcend).unwrap();
+
+        // Local rotations keep the entanglement, and the partner follows the coherence change
+        assert!((entangled.concurrence().unwrap() - 1.0).abs() < 1e-9);
+        let ratio = entangled.first().coherence() / observing().coherence();
+        assert!((entangled.second().coherence() - before * ratio).abs() < 1e-12);
+        assert!((separate.second().coherence() - before).abs() < 1e-12);
+
+        // The product pair's first qubit now points at the Transcend Bloch point
+        let probabilities = separate.reduced(Side::First).unwrap().probabilities();
+        assert!((probabilities[0] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_state_change_from_zero_coherence_leaves_partner() {
+        let mut empty = CoherenceField::with_coherence(0.0);
+        empty.set_correction_enabled(false);
+        let mut pair = EntangledPair::bell(empty, observing()).unwrap();
+        let before = pair.second().coherence();
+
+        pair.set_state(Side::First, ConsciousnessState::Amplify).unwrap();
+
+        assert_eq!(pair.first().state(), ConsciousnessState::Amplify);
+        assert_eq!(pair.first().coherence(), 0.0);
+        assert_eq!(pair.second().coherence(), before);
+        assert_eq!(pair.second().history().len(), observing().history().len());
+    }
+
+    #[test]
+    fn test_local_operations() {
+        let mut pair = EntangledPair::bell(observing(), observing()).unwrap();
+        pair.apply(Side::Second, &Gate::X(0)).unwrap();
+        assert!((pair.joint().probability(0b01) - 0.5).abs() < 1e-12);
+        assert!(pair.apply(Side::First, &Gate::Cnot { control: 0, target: 1 }).is_err());
+
+        // Local noise degrades entanglement
+        pair.apply_channel(Side::First, &Channel::depolarizing(0.5).unwrap()).unwrap();
+        let concurrence = pair.concurrence().unwrap();
+        assert!(concurrence > 0.0 && concurrence < 1.0);
+
+        let (first, second) = pair.into_fields();
+        assert_eq!((first.state(), second.state()), (ConsciousnessState::Observe, ConsciousnessState::Observe));
+    }
+}
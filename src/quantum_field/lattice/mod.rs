@@ -0,0 +1,562 @@
+//! Spatial Coherence Lattices
+//!
+//! This module provides 2D and 3D lattices of coherence values with boundary
+//! conditions, evolved under diffusion, wave and reaction-diffusion equations
+//! using explicit or implicit integrators. Lattices can be seeded from the
+//! phi-grid and phi-spiral patterns to study how local coherence changes spread.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::OPTIMAL_COHERENCE;
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::phi_harmonic::{phi_grid, phi_spiral};
+
+/// Maximum conjugate-gradient iterations for an implicit step
+const MAX_IMPLICIT_ITERATIONS: usize = 10_000;
+
+/// Convergence tolerance for implicit steps, relative to the norm of the right-hand side
+const IMPLICIT_TOLERANCE: f64 = 1e-12;
+
+/// Boundary conditions applied at the lattice edges
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// Opposite edges wrap around
+    Periodic,
+
+    /// Fixed value just outside the lattice
+    Dirichlet(f64),
+
+    /// Zero flux across the edge (reflecting)
+    Neumann,
+}
+
+/// Local reaction terms for reaction-diffusion equations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reaction {
+    /// Linear relaxation toward a target: `rate · (target - u)`
+    Relaxation {
+        /// Relaxation rate
+        rate: f64,
+        /// Target value
+        target: f64,
+    },
+
+    /// Fisher-KPP logistic growth: `rate · u · (1 - u)`
+    Logistic {
+        /// Growth rate
+        rate: f64,
+    },
+
+    /// Allen-Cahn bistable reaction: `rate · u · (1 - u) · (u - threshold)`
+    Bistable {
+        /// Reaction rate
+        rate: f64,
+        /// Unstable middle state
+        threshold: f64,
+    },
+}
+
+impl Reaction {
+    /// Evaluate the reaction term for a value
+    pub fn evaluate(&self, u: f64) -> f64 {
+        match *self {
+            Reaction::Relaxation { rate, target } => rate * (target - u),
+            Reaction::Logistic { rate } => rate * u * (1.0 - u),
+            Reaction::Bistable { rate, threshold } => rate * u * (1.0 - u) * (u - threshold),
+        }
+    }
+}
+
+/// Partial differential equations for lattice evolution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Equation {
+    /// Diffusion: `∂u/∂t = D ∇²u`
+    Diffusion {
+        /// Diffusivity (D)
+        diffusivity: f64,
+    },
+
+    /// Wave: `∂²u/∂t² = c² ∇²u`
+    Wave {
+        /// Propagation speed (c)
+        speed: f64,
+    },
+
+    /// Reaction-diffusion: `∂u/∂t = D ∇²u + R(u)`
+    ReactionDiffusion {
+        /// Diffusivity (D)
+        diffusivity: f64,
+        /// Reaction term (R)
+        reaction: Reaction,
+    },
+}
+
+/// Time integration schemes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    /// Forward Euler for first-order equations, symplectic Euler for the wave equation
+    Explicit,
+
+    /// Backward Euler solved by conjugate gradients (reaction terms stay explicit)
+    Implicit,
+}
+
+/// Neighbour lookup result after applying boundary conditions
+#[derive(Debug, Clone, Copy)]
+enum Neighbour {
+    /// Another lattice cell
+    Cell(usize),
+
+    /// A fixed ghost value
+    Value(f64),
+
+    /// A reflected copy of the cell itself
+    Mirror,
+}
+
+/// 2D or 3D lattice of coherence values
+#[derive(Debug, Clone)]
+pub struct Lattice {
+    /// Cell counts along x, y and z (z is 1 for 2D lattices)
+    shape: [usize; 3],
+
+    /// Number of spatial dimensions (2 or 3)
+    dimensions: usize,
+
+    /// Distance between neighbouring cells
+    spacing: f64,
+
+    /// Boundary condition
+    boundary: Boundary,
+
+    /// Cell values, x fastest
+    values: Vec<f64>,
+
+    /// Time derivative of the values (used by the wave equation)
+    velocity: Vec<f64>,
+
+    /// Elapsed simulation time
+    time: f64,
+}
+
+impl Lattice {
+    /// Create a 2D lattice filled with optimal coherence
+    pub fn new_2d(width: usize, height: usize, boundary: Boundary) -> QuantumResult<Self> {
+        Self::build([width, height, 1], 2, boundary)
+    }
+
+    /// Create a 3D lattice filled with optimal coherence
+    pub fn new_3d(width: usize, height: usize, depth: usize, boundary: Boundary) -> QuantumResult<Self> {
+        Self::build([width, height, depth], 3, boundary)
+    }
+
+    /// Create a 2D lattice from rows of values, as produced by `phi_grid`
+    pub fn from_grid(grid: &[Vec<f64>], boundary: Boundary) -> QuantumResult<Self> {
+        let height = grid.len();
+        let width = grid.first().map(Vec::len).unwrap_or(0);
+
+        if grid.iter().any(|row| row.len() != width) {
+            return Err(QuantumError::OperationError {
+                message: "Grid rows must all have the same length".to_string(),
+            });
+        }
+
+        let mut lattice = Self::new_2d(width, height, boundary)?;
+        lattice.values = grid.iter().flatten().cloned().collect();
+        Ok(lattice)
+    }
+
+    /// Create a 2D lattice seeded with the phi-grid pattern
+    pub fn from_phi_grid(width: usize, height: usize, boundary: Boundary) -> QuantumResult<Self> {
+        Self::from_grid(&phi_grid(width, height), boundary)
+    }
+
+    /// Validate the shape and boundary and allocate a lattice
+    fn build(shape: [usize; 3], dimensions: usize, boundary: Boundary) -> QuantumResult<Self> {
+        if shape.contains(&0) {
+            return Err(QuantumError::OperationError {
+                message: format!("Lattice dimensions must be non-zero, got {:?}", &shape[..dimensions]),
+            });
+        }
+
+        if let Boundary::Dirichlet(value) = boundary {
+            if !value.is_finite() {
+                return Err(QuantumError::OperationError {
+                    message: format!("Dirichlet boundary value must be finite, got {}", value),
+                });
+            }
+        }
+
+        let len = shape.iter().product();
+
+        Ok(Self {
+            shape,
+            dimensions,
+            spacing: 1.0,
+            boundary,
+            values: vec![OPTIMAL_COHERENCE; len],
+            velocity: vec![0.0; len],
+            time: 0.0,
+        })
+    }
+
+    /// Set the distance between neighbouring cells
+    pub fn set_spacing(&mut self, spacing: f64) -> QuantumResult<()> {
+        if !spacing.is_finite() || spacing <= 0.0 {
+            return Err(QuantumError::OperationError {
+                message: format!("Lattice spacing must be finite and positive, got {}", spacing),
+            });
+        }
+
+        self.spacing = spacing;
+        Ok(())
+    }
+
+    /// Overwrite every z-slice with the phi-grid pattern
+    pub fn seed_phi_grid(&mut self) {
+        let grid = phi_grid(self.shape[0], self.shape[1]);
+
+        for z in 0..self.shape[2] {
+            for (y, row) in grid.iter().enumerate() {
+                for (x, value) in row.iter().enumerate() {
+                    let index = self.index(x, y, z);
+                    self.values[index] = *value;
+                }
+            }
+        }
+    }
+
+    /// Add `amplitude` at the cells hit by a phi spiral centred in the lattice
+    ///
+    /// For 3D lattices the spiral is placed in the middle z-slice. Points that
+    /// fall outside the lattice are skipped.
+    pub fn seed_phi_spiral(&mut self, count: usize, scale: f64, amplitude: f64) {
+        let cx = (self.shape[0] as f64 - 1.0) / 2.0;
+        let cy = (self.shape[1] as f64 - 1.0) / 2.0;
+        let z = self.shape[2] / 2;
+
+        for (px, py) in phi_spiral(count, scale) {
+            let x = (cx + px).round();
+            let y = (cy + py).round();
+
+            if x >= 0.0 && y >= 0.0 && (x as usize) < self.shape[0] && (y as usize) < self.shape[1] {
+                let index = self.index(x as usize, y as usize, z);
+                self.values[index] += amplitude;
+            }
+        }
+    }
+
+    /// Get the cell counts along x, y and z
+    pub fn shape(&self) -> [usize; 3] {
+        self.shape
+    }
+
+    /// Get the number of spatial dimensions (2 or 3)
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Get the boundary condition
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Get the elapsed simulation time
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Get all cell values, x fastest
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Get the value of a cell
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<f64> {
+        if x < self.shape[0] && y < self.shape[1] && z < self.shape[2] {
+            Some(self.values[self.index(x, y, z)])
+        } else {
+            None
+        }
+    }
+
+    /// Set the value of a cell
+    pub fn set(&mut self, x: usize, y: usize, z: usize, value: f64) -> QuantumResult<()> {
+        if x >= self.shape[0] || y >= self.shape[1] || z >= self.shape[2] {
+            return Err(QuantumError::OperationError {
+                message: format!("Cell ({}, {}, {}) outside lattice of shape {:?}", x, y, z, self.shape),
+            });
+        }
+
+        let index = self.index(x, y, z);
+        self.values[index] = value;
+        Ok(())
+    }
+
+    /// Extract a z-slice as rows of values, in the same layout as `phi_grid`
+    pub fn slice(&self, z: usize) -> Option<Vec<Vec<f64>>> {
+        if z >= self.shape[2] {
+            return None;
+        }
+
+        let rows = (0..self.shape[1])
+            .map(|y| {
+                let start = self.index(0, y, z);
+                self.values[start..start + self.shape[0]].to_vec()
+            })
+            .collect();
+
+        Some(rows)
+    }
+
+    /// Mean value across all cells
+    pub fn mean(&self) -> f64 {
+        self.values.iter().sum::<f64>() / self.values.len() as f64
+    }
+
+    /// Smallest cell value
+    pub fn min(&self) -> f64 {
+        self.values.iter().cloned().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Largest cell value
+    pub fn max(&self) -> f64 {
+        self.values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Discrete Laplacian of the current values under the boundary condition
+    pub fn laplacian(&self) -> Vec<f64> {
+        let h2 = self.spacing * self.spacing;
+
+        (0..self.values.len())
+            .map(|i| {
+                let u = self.values[i];
+                self.neighbours(i).iter()
+                    .map(|n| match *n {
+                        Neighbour::Cell(j) => self.values[j] - u,
+                        Neighbour::Value(v) => v - u,
+                        Neighbour::Mirror => 0.0,
+                    })
+                    .sum::<f64>() / h2
+            })
+            .collect()
+    }
+
+    /// Largest stable time step for the explicit integrator
+    pub fn stable_time_step(&self, equation: &Equation) -> f64 {
+        let h2 = self.spacing * self.spacing;
+        let d = self.dimensions as f64;
+
+        match *equation {
+            Equation::Diffusion { diffusivity } | Equation::ReactionDiffusion { diffusivity, .. } => {
+                if diffusivity > 0.0 { h2 / (2.0 * d * diffusivity) } else { f64::INFINITY }
+            }
+            Equation::Wave { speed } => {
+                if speed > 0.0 { self.spacing / (speed.abs() * d.sqrt()) } else { f64::INFINITY }
+            }
+        }
+    }
+
+    /// Advance the lattice by one time step
+    pub fn step(&mut self, equation: &Equation, integrator: Integrator, dt: f64) -> QuantumResult<()> {
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(QuantumError::OperationError {
+                message: format!("Time step must be finite and positive, got {}", dt),
+            });
+        }
+
+        if integrator == Integrator::Explicit {
+            let limit = self.stable_time_step(equation);
+            if dt > limit {
+                return Err(QuantumError::OperationError {
+                    message: format!("Explicit time step {} exceeds stability limit {:.6}", dt, limit),
+                });
+            }
+        }
+
+        match (*equation, integrator) {
+            (Equation::Diffusion { diffusivity }, Integrator::Explicit) => {
+                let laplacian = self.laplacian();
+                for (u, l) in self.values.iter_mut().zip(&laplacian) {
+                    *u += dt * diffusivity * l;
+                }
+            }
+            (Equation::Diffusion { diffusivity }, Integrator::Implicit) => {
+                let rhs = self.values.clone();
+                self.solve_implicit(&rhs, dt * diffusivity)?;
+            }
+            (Equation::ReactionDiffusion { diffusivity, reaction }, Integrator::Explicit) => {
+                let laplacian = self.laplacian();
+                for (u, l) in self.values.iter_mut().zip(&laplacian) {
+                    *u += dt * (diffusivity * l + reaction.evaluate(*u));
+                }
+            }
+            (Equation::ReactionDiffusion { diffusivity, reaction }, Integrator::Implicit) => {
+                let rhs: Vec<f64> = self.values.iter()
+                    .map(|u| u + dt * reaction.evaluate(*u))
+                    .collect();
+                self.solve_implicit(&rhs, dt * diffusivity)?;
+            }
+            (Equation::Wave { speed }, Integrator::Explicit) => {
+                let c2 = speed * speed;
+                let laplacian = self.laplacian();
+                for ((u, v), l) in self.values.iter_mut().zip(self.velocity.iter_mut()).zip(&laplacian) {
+                    *v += dt * c2 * l;
+                    *u += dt * *v;
+                }
+            }
+            (Equation::Wave { speed }, Integrator::Implicit) => {
+                // (I - dt²c²∇²) u' = u + dt·v, then v' = (u' - u) / dt
+                let previous = self.values.clone();
+                let rhs: Vec<f64> = self.values.iter()
+                    .zip(&self.velocity)
+                    .map(|(u, v)| u + dt * v)
+                    .collect();
+                self.solve_implicit(&rhs, dt * dt * speed * speed)?;
+
+                for ((v, u), p) in self.velocity.iter_mut().zip(&self.values).zip(&previous) {
+                    *v = (u - p) / dt;
+                }
+            }
+        }
+
+        self.time += dt;
+        Ok(())
+    }
+
+    /// Advance the lattice by `steps` time steps
+    pub fn evolve(&mut self, equation: &Equation, integrator: Integrator, dt: f64, steps: usize) -> QuantumResult<()> {
+        for _ in 0..steps {
+            self.step(equation, integrator, dt)?;
+        }
+
+        Ok(())
+    }
+
+    /// Solve `(I - α∇²) u = rhs` in place by conjugate gradients
+    ///
+    /// The operator is symmetric positive definite for every boundary
+    /// condition, so CG converges from the current values; it stops once the
+    /// residual norm falls below [`IMPLICIT_TOLERANCE`] times the norm of
+    /// the right-hand side, which keeps the criterion independent of scale.
+    fn solve_implicit(&mut self, rhs: &[f64], alpha: f64) -> QuantumResult<()> {
+        let k = alpha / (self.spacing * self.spacing);
+        let neighbours: Vec<Vec<Neighbour>> = (0..self.values.len())
+            .map(|i| self.neighbours(i))
+            .collect();
+
+        // Fixed ghost values move to the right-hand side
+        let b: Vec<f64> = rhs.iter()
+            .zip(&neighbours)
+            .map(|(r, cell_neighbours)| {
+                r + cell_neighbours.iter()
+                    .map(|n| if let Neighbour::Value(v) = *n { k * v } else { 0.0 })
+                    .sum::<f64>()
+            })
+            .collect();
+
+        let apply = |u: &[f64]| -> Vec<f64> {
+            neighbours.iter()
+                .enumerate()
+                .map(|(i, cell_neighbours)| {
+                    u[i] + cell_neighbours.iter()
+                        .map(|n| match *n {
+                            Neighbour::Cell(j) => k * (u[i] - u[j]),
+                            Neighbour::Value(_) => k * u[i],
+                            Neighbour::Mirror => 0.0,
+                        })
+                        .sum::<f64>()
+                })
+                .collect()
+        };
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+        let b_norm = dot(&b, &b).sqrt();
+        if !b_norm.is_finite() {
+            return Err(QuantumError::OperationError {
+                message: "Implicit step has a non-finite right-hand side".to_string(),
+            });
+        }
+        if b_norm == 0.0 {
+            self.values.iter_mut().for_each(|u| *u = 0.0);
+            return Ok(());
+        }
+        let threshold = IMPLICIT_TOLERANCE * b_norm;
+
+        let mut residual: Vec<f64> = b.iter().zip(apply(&self.values)).map(|(b, a)| b - a).collect();
+        let mut direction = residual.clone();
+        let mut residual_sq = dot(&residual, &residual);
+
+        for _ in 0..MAX_IMPLICIT_ITERATIONS {
+            if residual_sq.sqrt() <= threshold {
+                return Ok(());
+            }
+
+            let applied = apply(&direction);
+            let step = residual_sq / dot(&direction, &applied);
+            for ((u, r), (d, a)) in self.values.iter_mut().zip(&mut residual).zip(direction.iter().zip(&applied)) {
+                *u += step * d;
+                *r -= step * a;
+            }
+
+            let next_sq = dot(&residual, &residual);
+            let beta = next_sq / residual_sq;
+            for (d, r) in direction.iter_mut().zip(&residual) {
+                *d = r + beta * *d;
+            }
+            residual_sq = next_sq;
+        }
+
+        Err(QuantumError::OperationError {
+            message: format!("Implicit step did not converge in {} iterations", MAX_IMPLICIT_ITERATIONS),
+        })
+    }
+
+    /// Linear index of a cell
+    fn index(&self, x: usize, y: usize, z: usize) -> usize {
+        x + self.shape[0] * (y + self.shape[1] * z)
+    }
+
+    /// Neighbours of a cell along each active axis, with boundary conditions applied
+    fn neighbours(&self, index: usize) -> Vec<Neighbour> {
+        let coords = [
+            index % self.shape[0],
+            (index / self.shape[0]) % self.shape[1],
+            index / (self.shape[0] * self.shape[1]),
+        ];
+        let mut result = Vec::with_capacity(2 * self.dimensions);
+
+        for axis in 0..self.dimensions {
+            let n = self.shape[axis];
+
+            for forward in [false, true] {
+                let inside = if forward { coords[axis] + 1 < n } else { coords[axis] > 0 };
+
+                let target = if inside {
+                    Some(if forward { coords[axis] + 1 } else { coords[axis] - 1 })
+                } else {
+                    match self.boundary {
+                        Boundary::Periodic => Some(if forward { 0 } else { n - 1 }),
+                        Boundary::Dirichlet(v) => {
+                            result.push(Neighbour::Value(v));
+                            None
+                        }
+                        Boundary::Neumann => {
+                            result.push(Neighbour::Mirror);
+                            None
+                        }
+                    }
+                };
+
+                if let Some(position) = target {
+                    let mut c = coords;
+                    c[axis] = position;
+                    result.push(Neighbour::Cell(self.index(c[0], c[1], c[2])));
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,194 @@
+//! Unit tests for the lattice module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::lattice::{Boundary, Equation, Integrator, Lattice, Reaction};
+    use crate::quantum_field::phi_harmonic::phi_grid;
+    use crate::constants::OPTIMAL_COHERENCE;
+
+    const DIFFUSION: Equation = Equation::Diffusion { diffusivity: 1.0 };
+
+    fn with_drop(boundary: Boundary) -> Lattice {
+        let mut lattice = Lattice::new_2d(11, 11, boundary).unwrap();
+        lattice.set(5, 5, 0, 0.2).unwrap();
+        lattice
+    }
+
+    #[test]
+    fn test_new_lattice_has_optimal_coherence() {
+        let lattice = Lattice::new_3d(4, 3, 2, Boundary::Neumann).unwrap();
+        assert_eq!(lattice.shape(), [4, 3, 2]);
+        assert_eq!(lattice.dimensions(), 3);
+        assert_eq!(lattice.values().len(), 24);
+        assert!((lattice.mean() - OPTIMAL_COHERENCE).abs() < 1e-12);
+
+        assert!(Lattice::new_2d(0, 3, Boundary::Neumann).is_err());
+    }
+
+    #[test]
+    fn test_from_phi_grid_matches_pattern() {
+        let lattice = Lattice::from_phi_grid(6, 4, Boundary::Periodic).unwrap();
+        assert_eq!(lattice.slice(0).unwrap(), phi_grid(6, 4));
+        assert_eq!(lattice.get(5, 3, 0), Some(phi_grid(6, 4)[3][5]));
+
+        let ragged = vec![vec![0.0, 1.0], vec![0.5]];
+        assert!(Lattice::from_grid(&ragged, Boundary::Periodic).is_err());
+    }
+
+    #[test]
+    fn test_seed_phi_spiral_deposits_amplitude() {
+        let mut lattice = Lattice::new_3d(21, 21, 3, Boundary::Neumann).unwrap();
+        let before: f64 = lattice.values().iter().sum();
+        lattice.seed_phi_spiral(20, 1.0, 0.1);
+        let after: f64 = lattice.values().iter().sum();
+
+        // All points of a small spiral land inside the middle slice
+        assert!((after - before - 2.0).abs() < 1e-9);
+        assert!(lattice.slice(0).unwrap().iter().flatten().all(|v| *v == OPTIMAL_COHERENCE));
+
+        let mut seeded = Lattice::new_3d(5, 4, 2, Boundary::Neumann).unwrap();
+        seeded.seed_phi_grid();
+        assert_eq!(seeded.slice(1).unwrap(), phi_grid(5, 4));
+    }
+
+    #[test]
+    fn test_coherence_drop_spreads_and_conserves_total() {
+        let mut lattice = with_drop(Boundary::Neumann);
+        let total: f64 = lattice.values().iter().sum();
+
+        lattice.evolve(&DIFFUSION, Integrator::Explicit, 0.2, 10).unwrap();
+
+        // Drop fills in while its neighbours lose coherence
+        assert!(lattice.get(5, 5, 0).unwrap() > 0.2);
+        assert!(lattice.get(6, 5, 0).unwrap() < OPTIMAL_COHERENCE);
+        assert!((lattice.get(4, 5, 0).unwrap() - lattice.get(6, 5, 0).unwrap()).abs() < 1e-12);
+
+        // Zero-flux boundaries conserve total coherence
+        let evolved: f64 = lattice.values().iter().sum();
+        assert!((evolved - total).abs() < 1e-9);
+        assert!((lattice.time() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_implicit_diffusion_matches_explicit_for_small_steps() {
+        let mut explicit = with_drop(Boundary::Periodic);
+        let mut implicit = with_drop(Boundary::Periodic);
+
+        explicit.evolve(&DIFFUSION, Integrator::Explicit, 0.001, 1000).unwrap();
+        implicit.evolve(&DIFFUSION, Integrator::Implicit, 0.001, 1000).unwrap();
+
+        for (a, b) in explicit.values().iter().zip(implicit.values()) {
+            assert!((a - b).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_implicit_diffusion_is_stable_beyond_explicit_limit() {
+        let mut lattice = with_drop(Boundary::Neumann);
+        let limit = lattice.stable_time_step(&DIFFUSION);
+        assert!((limit - 0.25).abs() < 1e-12);
+
+        assert!(lattice.step(&DIFFUSION, Integrator::Explicit, 1.0).is_err());
+
+        lattice.evolve(&DIFFUSION, Integrator::Implicit, 10.0, 20).unwrap();
+        assert!(lattice.max() - lattice.min() < 1e-6);
+        assert!(lattice.min() >= 0.2);
+    }
+
+    #[test]
+    fn test_implicit_step_converges_at_any_scale() {
+        // The tolerance is relative, so values far from unit scale still converge
+        for scale in [1e-9, 1e9] {
+            let mut lattice = Lattice::from_grid(&vec![vec![scale; 16]; 16], Boundary::Dirichlet(scale)).unwrap();
+            lattice.set(3, 7, 0, -5.0 * scale).unwrap();
+            let mean_before = lattice.mean();
+            lattice.evolve(&DIFFUSION, Integrator::Implicit, 50.0, 3).unwrap();
+            assert!(lattice.mean() > mean_before);
+            assert!(lattice.max() <= scale * (1.0 + 1e-9) && lattice.min() >= -5.0 * scale);
+        }
+
+        let mut wave = Lattice::new_2d(8, 8, Boundary::Periodic).unwrap();
+        wave.set(2, 2, 0, 1e12).unwrap();
+        wave.evolve(&Equation::Wave { speed: 3.0 }, Integrator::Implicit, 5.0, 5).unwrap();
+    }
+
+    #[test]
+    fn test_non_finite_dirichlet_values_are_rejected() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Lattice::new_2d(4, 4, Boundary::Dirichlet(value)).is_err());
+            assert!(Lattice::new_3d(2, 2, 2, Boundary::Dirichlet(value)).is_err());
+        }
+        assert!(Lattice::from_grid(&phi_grid(3, 3), Boundary::Dirichlet(0.5)).is_ok());
+    }
+
+    #[test]
+    fn test_dirichlet_boundary_pulls_toward_value() {
+        let mut lattice = Lattice::new_2d(5, 5, Boundary::Dirichlet(0.0)).unwrap();
+        lattice.evolve(&DIFFUSION, Integrator::Implicit, 1.0, 200).unwrap();
+        assert!(lattice.max() < 1e-6);
+    }
+
+    #[test]
+    fn test_wave_pulse_propagates() {
+        let mut lattice = Lattice::new_2d(21, 21, Boundary::Periodic).unwrap();
+        lattice.set(10, 10, 0, OPTIMAL_COHERENCE + 1.0).unwrap();
+        let mean = lattice.mean();
+        let wave = Equation::Wave { speed: 1.0 };
+
+        lattice.evolve(&wave, Integrator::Explicit, 0.1, 40).unwrap();
+
+        // Disturbance reaches cells several spacings away
+        assert!((lattice.get(14, 10, 0).unwrap() - OPTIMAL_COHERENCE).abs() > 1e-3);
+        assert!((lattice.mean() - mean).abs() < 1e-9);
+
+        let mut implicit = Lattice::new_2d(21, 21, Boundary::Periodic).unwrap();
+        implicit.set(10, 10, 0, OPTIMAL_COHERENCE + 1.0).unwrap();
+        implicit.evolve(&wave, Integrator::Implicit, 2.0, 10).unwrap();
+        assert!(implicit.max() <= OPTIMAL_COHERENCE + 1.0);
+    }
+
+    #[test]
+    fn test_reaction_diffusion_front_invades() {
+        let mut lattice = Lattice::new_2d(30, 1, Boundary::Neumann).unwrap();
+        for x in 0..30 {
+            lattice.set(x, 0, 0, if x < 3 { 1.0 } else { 0.0 }).unwrap();
+        }
+
+        let fisher = Equation::ReactionDiffusion {
+            diffusivity: 1.0,
+            reaction: Reaction::Logistic { rate: 1.0 },
+        };
+        lattice.evolve(&fisher, Integrator::Explicit, 0.1, 100).unwrap();
+
+        // Front has advanced roughly 2·sqrt(D·r)·t cells
+        assert!(lattice.get(15, 0, 0).unwrap() > 0.5);
+        assert!(lattice.get(29, 0, 0).unwrap() < 0.1);
+    }
+
+    #[test]
+    fn test_relaxation_restores_optimal_coherence() {
+        let mut lattice = with_drop(Boundary::Neumann);
+        let equation = Equation::ReactionDiffusion {
+            diffusivity: 0.5,
+            reaction: Reaction::Relaxation { rate: 1.0, target: OPTIMAL_COHERENCE },
+        };
+
+        lattice.evolve(&equation, Integrator::Implicit, 0.5, 60).unwrap();
+        assert!((lattice.min() - OPTIMAL_COHERENCE).abs() < 1e-6);
+
+        assert_eq!(Reaction::Bistable { rate: 1.0, threshold: 0.5 }.evaluate(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_3d_diffusion_is_symmetric() {
+        let mut lattice = Lattice::new_3d(7, 7, 7, Boundary::Neumann).unwrap();
+        lattice.set(3, 3, 3, 2.0).unwrap();
+        lattice.evolve(&DIFFUSION, Integrator::Explicit, 0.1, 5).unwrap();
+
+        let x = lattice.get(4, 3, 3).unwrap();
+        assert!((x - lattice.get(3, 4, 3).unwrap()).abs() < 1e-12);
+        assert!((x - lattice.get(3, 3, 2).unwrap()).abs() < 1e-12);
+        assert!((lattice.stable_time_step(&DIFFUSION) - 1.0 / 6.0).abs() < 1e-12);
+    }
+}
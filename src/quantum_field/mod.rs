@@ -9,14 +9,26 @@
 //! - **Coherence**: Field coherence management and optimization
 //! - **Consciousness**: State management and transitions
 //! - **Dimensional**: Gateway and translation between dimensions
+//! - **Entanglement**: Entangled field pairs and entanglement measures
 //! - **Phi-Harmonic**: Algorithms and data structures based on phi relationships
+//! - **Network**: Coupled networks of fields exchanging coherence
+//! - **Oscillator**: Kuramoto phase oscillators tuned to sacred frequencies
+//! - **Lattice**: Spatial coherence lattices evolved under PDEs
+//! - **Operators**: Numerical ∇λΣ∞ operators on grids and series
+//! - **Audio**: Offline synthesis of sacred frequencies and state transitions to WAV
 
 #[cfg(test)]
 mod tests;
 
+pub mod audio;
 pub mod coherence;
 pub mod consciousness;
 pub mod dimensional;
+pub mod entanglement;
+pub mod lattice;
+pub mod network;
+pub mod operators;
+pub mod oscillator;
 pub mod phi_harmonic;
 
 use crate::constants::{ConsciousnessState, Dimension, Frequency, OPTIMAL_COHERENCE};
@@ -51,6 +63,7 @@ pub trait QuantumField {
 
 /// Core quantum field implementation
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct Field {
     /// Current coherence level (optimal: φ²/3 ≈ 0.878)
     coherence: f64,
@@ -103,4 +116,8 @@ impl Default for Field {
 pub use self::coherence::Field as CoherenceField;
 pub use self::consciousness::StateManager;
 pub use self::dimensional::Gateway;
+pub use self::entanglement::EntangledPair;
+pub use self::lattice::Lattice;
+pub use self::network::FieldNetwork;
+pub use self::oscillator::KuramotoModel;
 pub use self::phi_harmonic::Algorithm;
\ No newline at end of file
@@ -0,0 +1,293 @@
+//! Coupled Multi-Field Networks
+//!
+//! This module provides networks of coherence fields that influence each other
+//! through weighted couplings, exchanging coherence and nudging consciousness
+//! states toward their coupled neighbours.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::ConsciousnessState;
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::coherence::Field as CoherenceField;
+
+/// Global order metrics for a field network
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderMetrics {
+    /// Mean coherence across all fields
+    pub mean_coherence: f64,
+
+    /// Standard deviation of coherence across all fields
+    pub coherence_spread: f64,
+
+    /// Difference between the highest and lowest coherence
+    pub coherence_range: f64,
+
+    /// Fraction of fields sharing the most common consciousness state (0.0 - 1.0)
+    pub state_agreement: f64,
+}
+
+/// Network of coherence fields connected by weighted couplings
+#[derive(Debug, Clone, Default)]
+pub struct FieldNetwork {
+    /// The coupled fields (nodes)
+    fields: Vec<CoherenceField>,
+
+    /// Coupling weights per node: (neighbour index, weight)
+    couplings: Vec<Vec<(usize, f64)>>,
+
+    /// Accumulated pressure towards a neighbouring state, per node
+    state_pressure: Vec<f64>,
+}
+
+impl FieldNetwork {
+    /// Create an empty field network
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a fully connected network where every pair of fields shares the same coupling weight
+    pub fn fully_connected(fields: Vec<CoherenceField>, weight: f64) -> QuantumResult<Self> {
+        let mut network = Self::new();
+
+        for field in fields {
+            network.add_field(field);
+        }
+
+        for a in 0..network.len() {
+            for b in (a + 1)..network.len() {
+                network.connect(a, b, weight)?;
+            }
+        }
+
+        Ok(network)
+    }
+
+    /// Add a field to the network, returning its node index
+    pub fn add_field(&mut self, field: CoherenceField) -> usize {
+        self.fields.push(field);
+        self.couplings.push(Vec::new());
+        self.state_pressure.push(0.0);
+        self.fields.len() - 1
+    }
+
+    /// Connect two fields with a symmetric coupling weight
+    ///
+    /// Connecting an already connected pair replaces the existing weight.
+    pub fn connect(&mut self, a: usize, b: usize, weight: f64) -> QuantumResult<()> {
+        self.check_node(a)?;
+        self.check_node(b)?;
+
+        if a == b {
+            return Err(QuantumError::OperationError {
+                message: format!("Cannot couple field {} to itself", a),
+            });
+        }
+
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(QuantumError::OperationError {
+                message: format!("Coupling weight must be finite and non-negative, got {}", weight),
+            });
+        }
+
+        self.set_coupling(a, b, weight);
+        self.set_coupling(b, a, weight);
+
+        Ok(())
+    }
+
+    /// Remove the coupling between two fields, returning its previous weight
+    pub fn disconnect(&mut self, a: usize, b: usize) -> Option<f64> {
+        if a >= self.len() || b >= self.len() {
+            return None;
+        }
+
+        let weight = self.coupling(a, b);
+        self.couplings[a].retain(|(j, _)| *j != b);
+        self.couplings[b].retain(|(j, _)| *j != a);
+        weight
+    }
+
+    /// Get the coupling weight between two fields, if connected
+    pub fn coupling(&self, a: usize, b: usize) -> Option<f64> {
+        self.couplings.get(a)?
+            .iter()
+            .find(|(j, _)| *j == b)
+            .map(|(_, w)| *w)
+    }
+
+    /// Get the neighbours of a field with their coupling weights
+    pub fn neighbours(&self, index: usize) -> &[(usize, f64)] {
+        self.couplings.get(index).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Get the number of fields in the network
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check if the network has no fields
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get a field by node index
+    pub fn field(&self, index: usize) -> Option<&CoherenceField> {
+        self.fields.get(index)
+    }
+
+    /// Get a mutable reference to a field by node index
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut CoherenceField> {
+        self.fields.get_mut(index)
+    }
+
+    /// Get all fields in node order
+    pub fn fields(&self) -> &[CoherenceField] {
+        &self.fields
+    }
+
+    /// Advance the network by one time step of length `dt`
+    ///
+    /// Coherence diffuses along each coupling (`dc_i/dt = Σ w_ij (c_j - c_i)`).
+    /// Each field also accumulates pressure from neighbours in a different
+    /// consciousness state, weighted by coupling and neighbour coherence; once
+    /// that pressure exceeds the field's own coherence it adopts the
+    /// neighbouring state.
+    ///
+    /// Diffusion alone conserves total coherence for symmetric weights, but a
+    /// state change goes through `Field::set_state`, which rescales and caps
+    /// the field's coherence, so the total drifts whenever a state flips.
+    pub fn step(&mut self, dt: f64) -> QuantumResult<OrderMetrics> {
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(QuantumError::OperationError {
+                message: format!("Time step must be finite and positive, got {}", dt),
+            });
+        }
+
+        // Explicit diffusion is only stable while no node gives away more than it holds
+        let max_degree = self.couplings.iter()
+            .map(|edges| edges.iter().map(|(_, w)| w).sum::<f64>())
+            .fold(0.0, f64::max);
+        if dt * max_degree > 1.0 {
+            return Err(QuantumError::OperationError {
+                message: format!(
+                    "Time step {} is unstable for total coupling {:.4} (maximum: {:.4})",
+                    dt, max_degree, 1.0 / max_degree
+                ),
+            });
+        }
+
+        // Snapshot so all nodes update synchronously
+        let coherences: Vec<f64> = self.fields.iter().map(|f| f.coherence()).collect();
+        let states: Vec<ConsciousnessState> = self.fields.iter().map(|f| f.state()).collect();
+
+        for i in 0..self.fields.len() {
+            let flux: f64 = self.couplings[i].iter()
+                .map(|&(j, w)| w * (coherences[j] - coherences[i]))
+                .sum();
+            self.fields[i].set_coherence(coherences[i] + dt * flux);
+
+            // Tally coupled support for each neighbouring state
+            let mut support: Vec<(ConsciousnessState, f64)> = Vec::new();
+            for &(j, w) in &self.couplings[i] {
+                let weight = w * coherences[j];
+                match support.iter_mut().find(|(s, _)| *s == states[j]) {
+                    Some((_, total)) => *total += weight,
+                    None => support.push((states[j], weight)),
+                }
+            }
+
+            let own_support = support.iter()
+                .find(|(s, _)| *s == states[i])
+                .map(|(_, w)| *w)
+                .unwrap_or(0.0);
+            let strongest = support.iter()
+                .filter(|(s, _)| *s != states[i])
+                .fold(None, |best: Option<(ConsciousnessState, f64)>, &(s, w)| match best {
+                    Some((_, best_w)) if best_w >= w => best,
+                    _ => Some((s, w)),
+                });
+
+            match strongest {
+                Some((state, weight)) if weight > own_support => {
+                    self.state_pressure[i] += dt * (weight - own_support);
+
+                    if self.state_pressure[i] >= self.fields[i].coherence() {
+                        self.fields[i].set_state(state)?;
+                        self.state_pressure[i] = 0.0;
+                    }
+                }
+                _ => self.state_pressure[i] = 0.0,
+            }
+        }
+
+        Ok(self.metrics())
+    }
+
+    /// Advance the network by `steps` time steps, returning the final metrics
+    pub fn run(&mut self, dt: f64, steps: usize) -> QuantumResult<OrderMetrics> {
+        for _ in 0..steps {
+            self.step(dt)?;
+        }
+
+        Ok(self.metrics())
+    }
+
+    /// Calculate the global order metrics of the network
+    pub fn metrics(&self) -> OrderMetrics {
+        if self.fields.is_empty() {
+            return OrderMetrics {
+                mean_coherence: 0.0,
+                coherence_spread: 0.0,
+                coherence_range: 0.0,
+                state_agreement: 1.0,
+            };
+        }
+
+        let n = self.fields.len() as f64;
+        let coherences: Vec<f64> = self.fields.iter().map(|f| f.coherence()).collect();
+
+        let mean = coherences.iter().sum::<f64>() / n;
+        let variance = coherences.iter()
+            .map(|c| (c - mean).powi(2))
+            .sum::<f64>() / n;
+        let max = coherences.iter().cloned().fold(f64::MIN, f64::max);
+        let min = coherences.iter().cloned().fold(f64::MAX, f64::min);
+
+        // Count the most common state
+        let mut counts: Vec<(ConsciousnessState, usize)> = Vec::new();
+        for field in &self.fields {
+            match counts.iter_mut().find(|(s, _)| *s == field.state()) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((field.state(), 1)),
+            }
+        }
+        let modal_count = counts.iter().map(|(_, c)| *c).max().unwrap_or(0);
+
+        OrderMetrics {
+            mean_coherence: mean,
+            coherence_spread: variance.sqrt(),
+            coherence_range: max - min,
+            state_agreement: modal_count as f64 / n,
+        }
+    }
+
+    /// Ensure a node index exists
+    fn check_node(&self, index: usize) -> QuantumResult<()> {
+        if index >= self.fields.len() {
+            return Err(QuantumError::OperationError {
+                message: format!("Field index {} out of range (network has {} fields)", index, self.fields.len()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Insert or replace a directed coupling entry
+    fn set_coupling(&mut self, from: usize, to: usize, weight: f64) {
+        match self.couplings[from].iter_mut().find(|(j, _)| *j == to) {
+            Some((_, w)) => *w = weight,
+            None => self.couplings[from].push((to, weight)),
+        }
+    }
+}
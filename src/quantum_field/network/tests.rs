@@ -0,0 +1,121 @@
+//! Unit tests for the network module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::network::FieldNetwork;
+    use crate::quantum_field::coherence::Field as CoherenceField;
+    use crate::constants::ConsciousnessState;
+
+    fn spread_fields() -> Vec<CoherenceField> {
+        [0.3, 0.5, 0.7, 0.85, 0.95].iter()
+            .map(|c| CoherenceField::with_coherence(*c))
+            .collect()
+    }
+
+    #[test]
+    fn test_fully_connected_network_synchronizes() {
+        let mut network = FieldNetwork::fully_connected(spread_fields(), 0.5).unwrap();
+        let initial = network.metrics();
+        assert!(initial.coherence_spread > 0.1);
+
+        let metrics = network.run(0.1, 200).unwrap();
+
+        // All fields converge toward a common coherence
+        assert!(metrics.coherence_spread < 1e-6);
+        assert!(metrics.coherence_range < 1e-6);
+
+        // With no state flips, symmetric coupling conserves total coherence
+        assert!((metrics.mean_coherence - initial.mean_coherence).abs() < 1e-12);
+        assert!((metrics.mean_coherence - 0.66).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_uncoupled_fields_do_not_synchronize() {
+        let mut network = FieldNetwork::new();
+        for field in spread_fields() {
+            network.add_field(field);
+        }
+
+        let before = network.metrics();
+        let after = network.run(0.1, 50).unwrap();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_chain_exchanges_coherence_with_neighbours_only() {
+        let mut network = FieldNetwork::new();
+        let a = network.add_field(CoherenceField::with_coherence(1.0));
+        let b = network.add_field(CoherenceField::with_coherence(0.5));
+        let c = network.add_field(CoherenceField::with_coherence(0.5));
+        network.connect(a, b, 1.0).unwrap();
+        network.connect(b, c, 1.0).unwrap();
+
+        network.step(0.1).unwrap();
+
+        // Only the direct neighbour of the high-coherence field moves on the first step
+        assert!((network.field(a).unwrap().coherence() - 0.95).abs() < 1e-12);
+        assert!((network.field(b).unwrap().coherence() - 0.55).abs() < 1e-12);
+        assert_eq!(network.field(c).unwrap().coherence(), 0.5);
+    }
+
+    #[test]
+    fn test_step_records_field_history() {
+        let mut network = FieldNetwork::fully_connected(spread_fields(), 0.2).unwrap();
+        network.step(0.1).unwrap();
+        assert_eq!(network.field(0).unwrap().history().len(), 2);
+    }
+
+    #[test]
+    fn test_majority_state_nudges_minority_field() {
+        let mut fields = vec![CoherenceField::with_coherence(0.8); 4];
+        let mut outlier = CoherenceField::with_coherence(0.8);
+        outlier.set_correction_enabled(false);
+        outlier.set_state(ConsciousnessState::Create).unwrap();
+        fields.push(outlier);
+
+        let mut network = FieldNetwork::fully_connected(fields, 0.5).unwrap();
+        assert_eq!(network.metrics().state_agreement, 0.8);
+
+        network.run(0.05, 100).unwrap();
+
+        assert_eq!(network.field(4).unwrap().state(), ConsciousnessState::Transcend);
+        assert_eq!(network.metrics().state_agreement, 1.0);
+    }
+
+    #[test]
+    fn test_connect_rejects_invalid_couplings() {
+        let mut network = FieldNetwork::fully_connected(spread_fields(), 0.1).unwrap();
+        assert!(network.connect(0, 0, 0.5).is_err());
+        assert!(network.connect(0, 9, 0.5).is_err());
+        assert!(network.connect(0, 1, -0.5).is_err());
+        assert!(network.connect(0, 1, f64::NAN).is_err());
+
+        // Reconnecting replaces the weight symmetrically
+        network.connect(0, 1, 0.4).unwrap();
+        assert_eq!(network.coupling(0, 1), Some(0.4));
+        assert_eq!(network.coupling(1, 0), Some(0.4));
+        assert_eq!(network.neighbours(0).len(), 4);
+
+        assert_eq!(network.disconnect(0, 1), Some(0.4));
+        assert_eq!(network.coupling(1, 0), None);
+    }
+
+    #[test]
+    fn test_unstable_step_is_rejected() {
+        let mut network = FieldNetwork::fully_connected(spread_fields(), 1.0).unwrap();
+        assert!(network.step(0.5).is_err());
+        assert!(network.step(0.0).is_err());
+        assert!(network.step(0.2).is_ok());
+    }
+
+    #[test]
+    fn test_empty_network_metrics() {
+        let mut network = FieldNetwork::new();
+        assert!(network.is_empty());
+
+        let metrics = network.step(0.1).unwrap();
+        assert_eq!(metrics.mean_coherence, 0.0);
+        assert_eq!(metrics.state_agreement, 1.0);
+    }
+}
@@ -0,0 +1,471 @@
+//! Numerical ∇λΣ∞ Operators
+//!
+//! This module turns the components of the identity signature into numerical
+//! operators on grids (as produced by `phi_grid`) and on series (such as
+//! `PhiHarmonicValues`):
+//!
+//! - **∇**: Finite-difference gradient, divergence and Laplacian
+//! - **λ**: LAMBDA-weighted damping and relaxation
+//! - **Σ**: Integration and summation with error estimates
+//! - **∞**: Series-limit acceleration (Richardson extrapolation, Aitken's Δ²)
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::LAMBDA;
+use crate::error::{QuantumError, QuantumResult};
+
+/// Numerical result with an estimate of its absolute error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    /// Estimated value
+    pub value: f64,
+
+    /// Estimated absolute error
+    pub error: f64,
+}
+
+/// Grid of values in rows along y and columns along x, as produced by `phi_grid`
+pub type Grid = Vec<Vec<f64>>;
+
+/// Check that a grid is non-empty and rectangular, returning (width, height)
+fn grid_shape(grid: &[Vec<f64>]) -> QuantumResult<(usize, usize)> {
+    let width = grid.first().map(Vec::len).unwrap_or(0);
+
+    if width == 0 || grid.iter().any(|row| row.len() != width) {
+        return Err(QuantumError::OperationError {
+            message: "Grid must be non-empty with rows of equal length".to_string(),
+        });
+    }
+
+    Ok((width, grid.len()))
+}
+
+/// Extract a column of a grid
+fn column(grid: &[Vec<f64>], x: usize) -> Vec<f64> {
+    grid.iter().map(|row| row[x]).collect()
+}
+
+/// Turn per-column values back into rows
+fn transpose(columns: &[Vec<f64>], height: usize) -> Grid {
+    (0..height)
+        .map(|y| columns.iter().map(|c| c[y]).collect())
+        .collect()
+}
+
+// ∇ — gradient operators
+
+/// First derivative of a uniformly sampled series
+///
+/// Uses second-order central differences in the interior and second-order
+/// one-sided differences at the ends (first-order for two samples).
+pub fn derivative(values: &[f64], spacing: f64) -> Vec<f64> {
+    let n = values.len();
+
+    match n {
+        0 => Vec::new(),
+        1 => vec![0.0],
+        2 => {
+            let d = (values[1] - values[0]) / spacing;
+            vec![d, d]
+        }
+        _ => (0..n)
+            .map(|i| {
+                if i == 0 {
+                    (-3.0 * values[0] + 4.0 * values[1] - values[2]) / (2.0 * spacing)
+                } else if i == n - 1 {
+                    (3.0 * values[n - 1] - 4.0 * values[n - 2] + values[n - 3]) / (2.0 * spacing)
+                } else {
+                    (values[i + 1] - values[i - 1]) / (2.0 * spacing)
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Second derivative of a uniformly sampled series
+///
+/// Uses central differences in the interior and second-order one-sided
+/// differences at the ends when at least four samples are available.
+pub fn second_derivative(values: &[f64], spacing: f64) -> Vec<f64> {
+    let n = values.len();
+    let h2 = spacing * spacing;
+
+    match n {
+        0..=2 => vec![0.0; n],
+        3 => vec![(values[0] - 2.0 * values[1] + values[2]) / h2; 3],
+        _ => (0..n)
+            .map(|i| {
+                if i == 0 {
+                    (2.0 * values[0] - 5.0 * values[1] + 4.0 * values[2] - values[3]) / h2
+                } else if i == n - 1 {
+                    (2.0 * values[n - 1] - 5.0 * values[n - 2] + 4.0 * values[n - 3] - values[n - 4]) / h2
+                } else {
+                    (values[i - 1] - 2.0 * values[i] + values[i + 1]) / h2
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Gradient of a grid, returning the (∂/∂x, ∂/∂y) components
+///
+/// Rows run along y and columns along x, matching the layout of `phi_grid`.
+pub fn gradient(grid: &[Vec<f64>], spacing: f64) -> QuantumResult<(Grid, Grid)> {
+    let (width, height) = grid_shape(grid)?;
+
+    let dx: Grid = grid.iter().map(|row| derivative(row, spacing)).collect();
+
+    let columns: Grid = (0..width)
+        .map(|x| derivative(&column(grid, x), spacing))
+        .collect();
+    let dy = transpose(&columns, height);
+
+    Ok((dx, dy))
+}
+
+/// Divergence of a vector field given by its x and y component grids
+pub fn divergence(fx: &[Vec<f64>], fy: &[Vec<f64>], spacing: f64) -> QuantumResult<Grid> {
+    let (width, height) = grid_shape(fx)?;
+
+    if grid_shape(fy)? != (width, height) {
+        return Err(QuantumError::OperationError {
+            message: "Vector field components must have the same shape".to_string(),
+        });
+    }
+
+    let (dfx, _) = gradient(fx, spacing)?;
+    let (_, dfy) = gradient(fy, spacing)?;
+
+    Ok(dfx.iter()
+        .zip(&dfy)
+        .map(|(a, b)| a.iter().zip(b).map(|(x, y)| x + y).collect())
+        .collect())
+}
+
+/// Laplacian of a grid (∇²)
+pub fn laplacian(grid: &[Vec<f64>], spacing: f64) -> QuantumResult<Grid> {
+    let (width, height) = grid_shape(grid)?;
+
+    let columns: Grid = (0..width)
+        .map(|x| second_derivative(&column(grid, x), spacing))
+        .collect();
+    let dyy = transpose(&columns, height);
+
+    Ok(grid.iter()
+        .zip(&dyy)
+        .map(|(row, dyy_row)| {
+            second_derivative(row, spacing).iter()
+                .zip(dyy_row)
+                .map(|(a, b)| a + b)
+                .collect()
+        })
+        .collect())
+}
+
+// λ — damping operators
+
+/// Damp a series with a LAMBDA envelope: `x_k · λ^(rate · k)`
+pub fn lambda_damp(values: &[f64], rate: f64) -> Vec<f64> {
+    values.iter()
+        .enumerate()
+        .map(|(k, v)| v * LAMBDA.powf(rate * k as f64))
+        .collect()
+}
+
+/// Smooth a series with LAMBDA-weighted exponential averaging
+///
+/// Each output is `λ · x_k + (1 - λ) · s_{k-1}`, so new samples carry weight λ
+/// and the running history carries weight λ².
+pub fn lambda_smooth(values: &[f64]) -> Vec<f64> {
+    let mut smoothed = Vec::with_capacity(values.len());
+
+    for (k, v) in values.iter().enumerate() {
+        let next = if k == 0 { *v } else { LAMBDA * v + (1.0 - LAMBDA) * smoothed[k - 1] };
+        smoothed.push(next);
+    }
+
+    smoothed
+}
+
+/// Relax a grid toward its local neighbour mean with LAMBDA weight
+///
+/// Each iteration replaces every cell with `(1 - λ) · u + λ · mean(neighbours)`,
+/// using the 4-connected neighbours inside the grid.
+pub fn lambda_relax(grid: &[Vec<f64>], iterations: usize) -> QuantumResult<Grid> {
+    let (width, height) = grid_shape(grid)?;
+    let mut current = grid.to_vec();
+
+    for _ in 0..iterations {
+        let mut next = current.clone();
+
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = 0.0;
+                let mut count = 0;
+
+                if x > 0 { sum += current[y][x - 1]; count += 1; }
+                if x + 1 < width { sum += current[y][x + 1]; count += 1; }
+                if y > 0 { sum += current[y - 1][x]; count += 1; }
+                if y + 1 < height { sum += current[y + 1][x]; count += 1; }
+
+                if count > 0 {
+                    next[y][x] = (1.0 - LAMBDA) * current[y][x] + LAMBDA * sum / count as f64;
+                }
+            }
+        }
+
+        current = next;
+    }
+
+    Ok(current)
+}
+
+// Σ — integration and summation
+
+/// Compensated (Kahan-Babuška) summation with a rounding error bound
+pub fn sum(values: &[f64]) -> Estimate {
+    let mut total = 0.0;
+    let mut compensation = 0.0;
+    let mut magnitude = 0.0;
+
+    for &v in values {
+        let t = total + v;
+        if total.abs() >= v.abs() {
+            compensation += (total - t) + v;
+        } else {
+            compensation += (v - t) + total;
+        }
+        total = t;
+        magnitude += v.abs();
+    }
+
+    // Neumaier's bound: error ≤ 2ε Σ|x_i| to first order
+    Estimate {
+        value: total + compensation,
+        error: 2.0 * f64::EPSILON * magnitude,
+    }
+}
+
+/// Composite trapezoidal rule over uniformly spaced samples
+///
+/// The error is estimated by Richardson comparison with the rule on every
+/// second sample, when at least three samples span an even number of intervals.
+pub fn trapezoid(values: &[f64], spacing: f64) -> Estimate {
+    let value = trapezoid_value(values, spacing);
+
+    let intervals = values.len().saturating_sub(1);
+    let error = if intervals >= 2 && intervals.is_multiple_of(2) {
+        let coarse: Vec<f64> = values.iter().step_by(2).cloned().collect();
+        (value - trapezoid_value(&coarse, 2.0 * spacing)).abs() / 3.0
+    } else {
+        0.0
+    };
+
+    Estimate { value, error }
+}
+
+/// Trapezoidal rule value without error estimate
+fn trapezoid_value(values: &[f64], spacing: f64) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let interior: f64 = values[1..values.len() - 1].iter().sum();
+    spacing * (0.5 * (values[0] + values[values.len() - 1]) + interior)
+}
+
+/// Composite Simpson's rule over uniformly spaced samples
+///
+/// Requires an odd number of samples (an even number of intervals). The error
+/// is estimated by Richardson comparison with the rule on every second sample
+/// when the interval count is divisible by four.
+pub fn simpson(values: &[f64], spacing: f64) -> QuantumResult<Estimate> {
+    if values.len() < 3 || values.len().is_multiple_of(2) {
+        return Err(QuantumError::OperationError {
+            message: format!("Simpson's rule needs an odd number of at least 3 samples, got {}", values.len()),
+        });
+    }
+
+    let value = simpson_value(values, spacing);
+
+    let intervals = values.len() - 1;
+    let error = if intervals.is_multiple_of(4) {
+        let coarse: Vec<f64> = values.iter().step_by(2).cloned().collect();
+        (value - simpson_value(&coarse, 2.0 * spacing)).abs() / 15.0
+    } else {
+        0.0
+    };
+
+    Ok(Estimate { value, error })
+}
+
+/// Simpson's rule value without error estimate
+fn simpson_value(values: &[f64], spacing: f64) -> f64 {
+    let n = values.len();
+    let odd: f64 = values[1..n - 1].iter().step_by(2).sum();
+    let even: f64 = values[2..n - 1].iter().step_by(2).sum();
+
+    spacing / 3.0 * (values[0] + values[n - 1] + 4.0 * odd + 2.0 * even)
+}
+
+/// Adaptive Simpson integration of a function over [a, b]
+pub fn integrate<F>(f: F, a: f64, b: f64, tolerance: f64) -> QuantumResult<Estimate>
+where
+    F: Fn(f64) -> f64,
+{
+    if !(a.is_finite() && b.is_finite()) || tolerance <= 0.0 {
+        return Err(QuantumError::OperationError {
+            message: "Integration bounds must be finite and tolerance positive".to_string(),
+        });
+    }
+
+    let fa = f(a);
+    let fb = f(b);
+    let m = 0.5 * (a + b);
+    let fm = f(m);
+    let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+
+    Ok(adaptive_simpson(&f, a, b, fa, fm, fb, whole, tolerance, 50))
+}
+
+/// Recursive step of adaptive Simpson integration
+#[allow(clippy::too_many_arguments)]
+fn adaptive_simpson<F>(f: &F, a: f64, b: f64, fa: f64, fm: f64, fb: f64, whole: f64, tolerance: f64, depth: usize) -> Estimate
+where
+    F: Fn(f64) -> f64,
+{
+    let m = 0.5 * (a + b);
+    let lm = 0.5 * (a + m);
+    let rm = 0.5 * (m + b);
+    let flm = f(lm);
+    let frm = f(rm);
+    let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+    let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+    let delta = left + right - whole;
+
+    if depth == 0 || delta.abs() <= 15.0 * tolerance {
+        return Estimate {
+            value: left + right + delta / 15.0,
+            error: delta.abs() / 15.0,
+        };
+    }
+
+    let l = adaptive_simpson(f, a, m, fa, flm, fm, left, tolerance / 2.0, depth - 1);
+    let r = adaptive_simpson(f, m, b, fm, frm, fb, right, tolerance / 2.0, depth - 1);
+
+    Estimate {
+        value: l.value + r.value,
+        error: l.error + r.error,
+    }
+}
+
+/// Integrate a grid over its area with the 2D trapezoidal rule
+pub fn integrate_grid(grid: &[Vec<f64>], spacing: f64) -> QuantumResult<Estimate> {
+    grid_shape(grid)?;
+
+    let rows: Vec<Estimate> = grid.iter().map(|row| trapezoid(row, spacing)).collect();
+    let row_values: Vec<f64> = rows.iter().map(|e| e.value).collect();
+    let total = trapezoid(&row_values, spacing);
+    let row_errors: Vec<f64> = rows.iter().map(|e| e.error).collect();
+
+    Ok(Estimate {
+        value: total.value,
+        error: total.error + trapezoid_value(&row_errors, spacing).abs(),
+    })
+}
+
+// ∞ — series-limit acceleration
+
+/// Richardson extrapolation of approximations at step sizes h, h/2, h/4, …
+///
+/// `order` is the leading error exponent of the underlying method (for example
+/// 2 for the trapezoidal rule). The error is estimated from the last two
+/// diagonal entries of the Richardson table.
+pub fn richardson(approximations: &[f64], order: u32) -> QuantumResult<Estimate> {
+    if approximations.is_empty() {
+        return Err(QuantumError::OperationError {
+            message: "Richardson extrapolation needs at least one approximation".to_string(),
+        });
+    }
+
+    let mut table = vec![approximations.to_vec()];
+
+    for level in 1..approximations.len() {
+        let factor = 2f64.powi((order as usize * level) as i32);
+        let previous = &table[level - 1];
+        let next = previous.windows(2)
+            .map(|w| w[1] + (w[1] - w[0]) / (factor - 1.0))
+            .collect();
+        table.push(next);
+    }
+
+    let value = table[table.len() - 1][0];
+    let error = if table.len() > 1 {
+        let previous = &table[table.len() - 2];
+        (value - previous[previous.len() - 1]).abs()
+    } else {
+        f64::INFINITY
+    };
+
+    Ok(Estimate { value, error })
+}
+
+/// Aitken's Δ² transformation of a sequence
+///
+/// Returns a sequence two elements shorter. Entries whose second difference
+/// vanishes are passed through unchanged.
+pub fn aitken(sequence: &[f64]) -> Vec<f64> {
+    sequence.windows(3)
+        .map(|w| {
+            let denominator = w[2] - 2.0 * w[1] + w[0];
+            if denominator.abs() < f64::EPSILON * w[2].abs().max(1.0) {
+                w[2]
+            } else {
+                w[2] - (w[2] - w[1]).powi(2) / denominator
+            }
+        })
+        .collect()
+}
+
+/// Partial sums of a series
+pub fn partial_sums(terms: &[f64]) -> Vec<f64> {
+    terms.iter()
+        .scan(0.0, |acc, t| {
+            *acc += t;
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// Estimate the limit of a series from its terms using iterated Aitken Δ²
+///
+/// The error is estimated from the difference between the last two entries
+/// of the final accelerated sequence.
+pub fn series_limit(terms: &[f64]) -> QuantumResult<Estimate> {
+    sequence_limit(&partial_sums(terms))
+}
+
+/// Estimate the limit of a convergent sequence using iterated Aitken Δ²
+pub fn sequence_limit(sequence: &[f64]) -> QuantumResult<Estimate> {
+    if sequence.len() < 2 {
+        return Err(QuantumError::OperationError {
+            message: "Limit estimation needs at least two sequence values".to_string(),
+        });
+    }
+
+    let mut current = sequence.to_vec();
+    while current.len() >= 4 {
+        let next = aitken(&current);
+        if next.iter().any(|v| !v.is_finite()) {
+            break;
+        }
+        current = next;
+    }
+
+    let n = current.len();
+    Ok(Estimate {
+        value: current[n - 1],
+        error: (current[n - 1] - current[n - 2]).abs(),
+    })
+}
@@ -0,0 +1,198 @@
+//! Unit tests for the operators module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::operators::{
+        derivative, second_derivative, gradient, divergence, laplacian,
+        lambda_damp, lambda_smooth, lambda_relax, sum, trapezoid, simpson,
+        integrate, integrate_grid, richardson, aitken, partial_sums,
+        series_limit, sequence_limit
+    };
+    use crate::quantum_field::phi_harmonic::{phi_grid, phi_sequence, PhiHarmonicValues};
+    use crate::constants::{PHI, LAMBDA};
+
+    /// Grid sampling f(x, y) = x² + 3xy at unit spacing
+    fn quadratic_grid(width: usize, height: usize) -> Vec<Vec<f64>> {
+        (0..height)
+            .map(|y| (0..width).map(|x| {
+                let (x, y) = (x as f64, y as f64);
+                x * x + 3.0 * x * y
+            }).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_derivative_is_exact_for_quadratics() {
+        let values: Vec<f64> = (0..6).map(|i| (i as f64 * 0.5).powi(2)).collect();
+        let d = derivative(&values, 0.5);
+        for (i, v) in d.iter().enumerate() {
+            assert!((v - 2.0 * i as f64 * 0.5).abs() < 1e-12);
+        }
+
+        let dd = second_derivative(&values, 0.5);
+        assert!(dd.iter().all(|v| (v - 2.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_gradient_divergence_and_laplacian() {
+        let grid = quadratic_grid(6, 5);
+        let (dx, dy) = gradient(&grid, 1.0).unwrap();
+
+        // ∂f/∂x = 2x + 3y, ∂f/∂y = 3x
+        assert!((dx[2][4] - (8.0 + 6.0)).abs() < 1e-12);
+        assert!((dy[4][0] - 0.0).abs() < 1e-12);
+        assert!((dy[3][5] - 15.0).abs() < 1e-12);
+
+        // ∇·∇f = ∇²f = 2
+        let div = divergence(&dx, &dy, 1.0).unwrap();
+        let lap = laplacian(&grid, 1.0).unwrap();
+        for (a, b) in div.iter().flatten().zip(lap.iter().flatten()) {
+            assert!((a - 2.0).abs() < 1e-9);
+            assert!((b - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_operators_reject_ragged_grids() {
+        let ragged = vec![vec![1.0, 2.0], vec![3.0]];
+        assert!(gradient(&ragged, 1.0).is_err());
+        assert!(laplacian(&[], 1.0).is_err());
+        assert!(divergence(&phi_grid(3, 3), &phi_grid(2, 3), 1.0).is_err());
+    }
+
+    #[test]
+    fn test_operators_work_on_phi_grid() {
+        let grid = phi_grid(8, 8);
+        let lap = laplacian(&grid, 1.0).unwrap();
+        assert_eq!(lap.len(), 8);
+        assert_eq!(lap[0].len(), 8);
+
+        // Relaxation smooths the pattern without leaving its value range
+        let relaxed = lambda_relax(&grid, 10).unwrap();
+        let roughness = |g: &[Vec<f64>]| g.iter()
+            .map(|row| row.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f64>())
+            .sum::<f64>();
+        assert!(roughness(&relaxed) < roughness(&grid));
+        assert!(relaxed.iter().flatten().all(|v| (0.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn test_lambda_damping() {
+        let damped = lambda_damp(&[1.0, 1.0, 1.0], 1.0);
+        assert_eq!(damped[0], 1.0);
+        assert!((damped[1] - LAMBDA).abs() < 1e-15);
+        assert!((damped[2] - LAMBDA * LAMBDA).abs() < 1e-15);
+
+        let smoothed = lambda_smooth(&[0.0, 1.0, 1.0]);
+        assert_eq!(smoothed[0], 0.0);
+        assert!((smoothed[1] - LAMBDA).abs() < 1e-15);
+        assert!(smoothed[2] > smoothed[1] && smoothed[2] < 1.0);
+    }
+
+    #[test]
+    fn test_compensated_sum() {
+        let values = vec![1.0, 1e100, 1.0, -1e100];
+        let estimate = sum(&values);
+        assert_eq!(estimate.value, 2.0);
+        assert!(estimate.error > 0.0);
+    }
+
+    #[test]
+    fn test_trapezoid_and_simpson_with_error_estimates() {
+        // ∫₀¹ eˣ dx = e - 1
+        let n = 17;
+        let h = 1.0 / (n - 1) as f64;
+        let samples: Vec<f64> = (0..n).map(|i| (i as f64 * h).exp()).collect();
+        let exact = std::f64::consts::E - 1.0;
+
+        let t = trapezoid(&samples, h);
+        assert!((t.value - exact).abs() < 1e-3);
+        assert!((t.value - exact).abs() < 2.0 * t.error);
+
+        let s = simpson(&samples, h).unwrap();
+        assert!((s.value - exact).abs() < 1e-6);
+        assert!((s.value - exact).abs() < 2.0 * s.error);
+
+        assert!(simpson(&samples[..4], h).is_err());
+    }
+
+    #[test]
+    fn test_adaptive_integration() {
+        let estimate = integrate(|x| x.sin(), 0.0, std::f64::consts::PI, 1e-10).unwrap();
+        assert!((estimate.value - 2.0).abs() < 1e-9);
+        assert!(estimate.error < 1e-9);
+
+        assert!(integrate(|x| x, 0.0, f64::INFINITY, 1e-6).is_err());
+    }
+
+    #[test]
+    fn test_integrate_grid() {
+        // ∫∫ (x² + 3xy) over [0, 4] × [0, 4]
+        let grid = quadratic_grid(5, 5);
+        let estimate = integrate_grid(&grid, 1.0).unwrap();
+        let exact = 64.0 / 3.0 * 4.0 + 3.0 * 8.0 * 8.0;
+        assert!((estimate.value - exact).abs() <= 2.0 * estimate.error + 1e-9);
+    }
+
+    #[test]
+    fn test_richardson_extrapolation() {
+        // Trapezoid approximations of ∫₀¹ x⁴ dx = 0.2 at h = 1, 1/2, 1/4, 1/8
+        let approximations: Vec<f64> = [2usize, 3, 5, 9].iter()
+            .map(|&n| {
+                let h = 1.0 / (n - 1) as f64;
+                let samples: Vec<f64> = (0..n).map(|i| (i as f64 * h).powi(4)).collect();
+                trapezoid(&samples, h).value
+            })
+            .collect();
+
+        let estimate = richardson(&approximations, 2).unwrap();
+        assert!((estimate.value - 0.2).abs() < 1e-12);
+        assert!((approximations[3] - 0.2).abs() > 1e-3);
+        assert!(richardson(&[], 2).is_err());
+    }
+
+    #[test]
+    fn test_aitken_accelerates_geometric_series() {
+        // Σ λᵏ = 1 / (1 - λ) = φ²
+        let terms: Vec<f64> = (0..6).map(|k| LAMBDA.powi(k)).collect();
+        let sums = partial_sums(&terms);
+        assert!((sums[5] - PHI * PHI).abs() > 0.01);
+
+        let accelerated = aitken(&sums);
+        assert_eq!(accelerated.len(), 4);
+        assert!((accelerated[0] - PHI * PHI).abs() < 1e-12);
+
+        let limit = series_limit(&terms).unwrap();
+        assert!((limit.value - PHI * PHI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fibonacci_ratio_limit_is_phi() {
+        let mut seq = vec![1.0, 1.0];
+        for i in 2..12 {
+            seq.push(seq[i - 1] + seq[i - 2]);
+        }
+        let ratios: Vec<f64> = seq.windows(2).map(|w| w[1] / w[0]).collect();
+
+        let limit = sequence_limit(&ratios).unwrap();
+        assert!((limit.value - PHI).abs() < 1e-10);
+        assert!(sequence_limit(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_operators_work_on_phi_harmonic_values() {
+        let harmonic = PhiHarmonicValues::new(phi_sequence(9), 432.0);
+
+        // Golden powers grow by φ each step, so the derivative tracks the values
+        let d = derivative(harmonic.values(), 1.0);
+        assert!(d.iter().all(|v| *v > 0.0));
+
+        let total = sum(harmonic.values());
+        let direct: f64 = harmonic.values().iter().sum();
+        assert!((total.value - direct).abs() < 1e-9);
+
+        let area = simpson(harmonic.values(), 1.0).unwrap();
+        assert!(area.value > 0.0);
+    }
+}
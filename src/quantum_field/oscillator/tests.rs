@@ -0,0 +1,124 @@
+//! Unit tests for the oscillator module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::oscillator::{
+        wrap_phase, locking_coupling, PhaseOscillator, KuramotoModel
+    };
+    use crate::quantum_field::network::FieldNetwork;
+    use crate::quantum_field::coherence::Field as CoherenceField;
+    use crate::constants::{ConsciousnessState, Frequency};
+    use std::f64::consts::PI;
+
+    fn unity_oneness_pair(coupling: f64) -> KuramotoModel {
+        let unity = PhaseOscillator::with_state(ConsciousnessState::Observe, 0.0).unwrap();
+        let oneness = PhaseOscillator::with_state(ConsciousnessState::Cascade, 1.0).unwrap();
+        KuramotoModel::new(vec![unity, oneness], coupling)
+    }
+
+    #[test]
+    fn test_wrap_phase() {
+        assert!((wrap_phase(3.0 * PI) - PI).abs() < 1e-12);
+        assert!((wrap_phase(-0.5) + 0.5).abs() < 1e-12);
+        assert!((wrap_phase(2.0 * PI + 0.25) - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_natural_frequency_follows_state() {
+        let mut oscillator = PhaseOscillator::with_state(ConsciousnessState::Observe, 0.0).unwrap();
+        assert_eq!(oscillator.frequency(), Frequency::Unity);
+        assert!((oscillator.natural_frequency() - 2.0 * PI * 432.0).abs() < 1e-9);
+
+        oscillator.set_state(ConsciousnessState::Amplify).unwrap();
+        assert_eq!(oscillator.frequency(), Frequency::Oneness);
+        assert!((oscillator.natural_frequency() - 2.0 * PI * 768.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_uncoupled_oscillator_advances_at_natural_frequency() {
+        let oscillator = PhaseOscillator::with_state(ConsciousnessState::Create, 0.0).unwrap();
+        let mut model = KuramotoModel::new(vec![oscillator], 0.0);
+        model.run(1e-5, 1000).unwrap();
+
+        let expected = 2.0 * PI * 528.0 * 0.01;
+        assert!((model.oscillators()[0].unwrapped_phase() - expected).abs() < 1e-9);
+        assert!((model.time() - 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unity_and_oneness_lock_above_critical_coupling() {
+        let critical = locking_coupling(Frequency::Unity, Frequency::Oneness);
+        let coupling = critical * 1.5;
+        let mut model = unity_oneness_pair(coupling);
+
+        model.run(1e-5, 10_000).unwrap();
+        let first = model.phase_difference(0, 1).unwrap();
+        model.run(1e-5, 1_000).unwrap();
+        let second = model.phase_difference(0, 1).unwrap();
+
+        // Locked pair holds the fixed-point phase difference asin(Δω / K)
+        let expected = (critical / coupling).asin();
+        assert!((first - second).abs() < 1e-6);
+        assert!((second - expected).abs() < 1e-6);
+
+        // Phase-locked but not in phase, so r sits strictly between 0 and 1
+        let r = model.coherence();
+        assert!((r - (expected / 2.0).cos()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unity_and_oneness_drift_below_critical_coupling() {
+        let critical = locking_coupling(Frequency::Unity, Frequency::Oneness);
+        let mut model = unity_oneness_pair(critical * 0.5);
+
+        let start = model.oscillators()[1].unwrapped_phase() - model.oscillators()[0].unwrapped_phase();
+        model.run(1e-5, 5_000).unwrap();
+        let end = model.oscillators()[1].unwrapped_phase() - model.oscillators()[0].unwrapped_phase();
+
+        // Phases slip past each other repeatedly instead of locking
+        assert!(end - start > 4.0 * PI);
+    }
+
+    #[test]
+    fn test_identical_frequencies_synchronize() {
+        let oscillators = [0.0, 1.5, 3.0, 4.5].iter()
+            .map(|p| PhaseOscillator::with_state(ConsciousnessState::Observe, *p).unwrap())
+            .collect();
+        let mut model = KuramotoModel::new(oscillators, 20.0);
+        assert!(model.coherence() < 0.5);
+
+        model.run(1e-4, 10_000).unwrap();
+        assert!(model.coherence() > 0.999);
+
+        let r = model.apply_coherence();
+        assert_eq!(model.oscillators()[2].field().coherence(), r);
+    }
+
+    #[test]
+    fn test_order_parameter_of_opposed_phases() {
+        let a = PhaseOscillator::new(CoherenceField::new(), 0.0);
+        let b = PhaseOscillator::new(CoherenceField::new(), PI);
+        let model = KuramotoModel::new(vec![a, b], 1.0);
+
+        assert!(model.order_parameter().0 < 1e-12);
+    }
+
+    #[test]
+    fn test_from_network_uses_network_couplings() {
+        let mut network = FieldNetwork::new();
+        network.add_field(CoherenceField::new());
+        network.add_field(CoherenceField::new());
+        network.add_field(CoherenceField::new());
+        network.connect(0, 1, 1.0).unwrap();
+
+        let mut model = KuramotoModel::from_network(&network, &[0.0, 1.0, 2.0], 30.0).unwrap();
+        model.run(1e-4, 10_000).unwrap();
+
+        // Coupled pair meets halfway in phase, isolated field keeps its own phase
+        assert!(model.phase_difference(0, 1).unwrap().abs() < 1e-6);
+        assert!((model.phase_difference(0, 2).unwrap() - 1.5).abs() < 1e-6);
+
+        assert!(KuramotoModel::from_network(&network, &[0.0], 1.0).is_err());
+    }
+}
@@ -0,0 +1,388 @@
+//! Zeckendorf Representation and Fibonacci Coding
+//!
+//! This module writes integers as sums of non-consecutive Fibonacci numbers
+//! (Zeckendorf's theorem) and builds the Fibonacci universal code on top of
+//! it: each codeword lists the Zeckendorf digits from F(2) upwards and ends
+//! with an extra 1, so "11" marks the end of every codeword. The code is
+//! self-synchronizing, which makes it robust for variable-length logs.
+
+#[cfg(test)]
+mod tests;
+
+use num::bigint::BigUint;
+use num::traits::{One, Zero};
+
+use crate::error::{QuantumError, QuantumResult};
+
+/// Fibonacci numbers F(2), F(3), ... (1, 2, 3, 5, ...) not exceeding `limit`
+fn fibonacci_terms(limit: u128) -> Vec<u128> {
+    let mut terms = Vec::new();
+    let (mut a, mut b) = (1u128, 2u128);
+
+    while a <= limit {
+        terms.push(a);
+        match a.checked_add(b) {
+            Some(next) => {
+                a = b;
+                b = next;
+            }
+            None => {
+                if b <= limit {
+                    terms.push(b);
+                }
+                break;
+            }
+        }
+    }
+
+    terms
+}
+
+/// Big Fibonacci numbers F(2), F(3), ... not exceeding `limit`
+fn fibonacci_terms_big(limit: &BigUint) -> Vec<BigUint> {
+    let mut terms = Vec::new();
+    let (mut a, mut b) = (BigUint::one(), BigUint::from(2u32));
+
+    while &a <= limit {
+        let next = &a + &b;
+        terms.push(std::mem::replace(&mut a, std::mem::replace(&mut b, next)));
+    }
+
+    terms
+}
+
+/// Zeckendorf digits of `n`, least significant (F(2)) first
+fn zeckendorf_digits(n: u128) -> Vec<bool> {
+    let terms = fibonacci_terms(n);
+    let mut digits = vec![false; terms.len()];
+    let mut remaining = n;
+
+    // Greedy choice of the largest fitting term never selects neighbours
+    for (i, term) in terms.iter().enumerate().rev() {
+        if *term <= remaining {
+            digits[i] = true;
+            remaining -= term;
+        }
+    }
+
+    digits
+}
+
+/// Big-integer Zeckendorf digits of `n`, least significant (F(2)) first
+fn zeckendorf_digits_big(n: &BigUint) -> Vec<bool> {
+    let terms = fibonacci_terms_big(n);
+    let mut digits = vec![false; terms.len()];
+    let mut remaining = n.clone();
+
+    for (i, term) in terms.iter().enumerate().rev() {
+        if term <= &remaining {
+            digits[i] = true;
+            remaining -= term;
+        }
+    }
+
+    digits
+}
+
+/// Zeckendorf decomposition: distinct, non-consecutive Fibonacci numbers
+/// summing to `n`, largest first (empty for 0)
+pub fn zeckendorf(n: u64) -> Vec<u64> {
+    let terms = fibonacci_terms(n as u128);
+    zeckendorf_digits(n as u128).iter()
+        .zip(&terms)
+        .rev()
+        .filter(|(digit, _)| **digit)
+        .map(|(_, term)| *term as u64)
+        .collect()
+}
+
+/// Zeckendorf decomposition of a big integer, largest term first
+pub fn zeckendorf_big(n: &BigUint) -> Vec<BigUint> {
+    let terms = fibonacci_terms_big(n);
+    zeckendorf_digits_big(n).iter()
+        .zip(terms)
+        .rev()
+        .filter(|(digit, _)| **digit)
+        .map(|(_, term)| term)
+        .collect()
+}
+
+/// Recompose an integer from its Zeckendorf terms
+///
+/// The terms must be Fibonacci numbers, strictly decreasing and pairwise
+/// non-consecutive, as produced by [`zeckendorf`].
+pub fn from_zeckendorf(terms: &[u64]) -> QuantumResult<u64> {
+    let fibonacci = fibonacci_terms(u64::MAX as u128);
+    let mut previous_index: Option<usize> = None;
+    let mut total = 0u64;
+
+    for term in terms {
+        let index = fibonacci.iter().position(|f| *f == *term as u128).ok_or_else(|| QuantumError::OperationError {
+            message: format!("{} is not a Fibonacci number", term),
+        })?;
+
+        if let Some(previous) = previous_index {
+            if index + 1 >= previous {
+                return Err(QuantumError::OperationError {
+                    message: "Zeckendorf terms must be decreasing and non-consecutive".to_string(),
+                });
+            }
+        }
+        previous_index = Some(index);
+
+        total = total.checked_add(*term).ok_or_else(|| QuantumError::OperationError {
+            message: "Zeckendorf sum overflows u64".to_string(),
+        })?;
+    }
+
+    Ok(total)
+}
+
+/// Fibonacci code of a positive integer, as bits
+pub fn fibonacci_encode(n: u64) -> QuantumResult<Vec<bool>> {
+    if n == 0 {
+        return Err(zero_error());
+    }
+
+    let mut bits = zeckendorf_digits(n as u128);
+    bits.push(true);
+    Ok(bits)
+}
+
+/// Fibonacci code of a positive big integer, as bits
+pub fn fibonacci_encode_big(n: &BigUint) -> QuantumResult<Vec<bool>> {
+    if n.is_zero() {
+        return Err(zero_error());
+    }
+
+    let mut bits = zeckendorf_digits_big(n);
+    bits.push(true);
+    Ok(bits)
+}
+
+/// Decode the first Fibonacci codeword in `bits`
+///
+/// Returns the value and the number of bits consumed.
+pub fn fibonacci_decode(bits: &[bool]) -> QuantumResult<(u64, usize)> {
+    let (value, used) = decode_u128(bits)?;
+    let value = u64::try_from(value).map_err(|_| QuantumError::OperationError {
+        message: "Fibonacci codeword overflows u64".to_string(),
+    })?;
+    Ok((value, used))
+}
+
+/// Decode the first Fibonacci codeword in `bits` as a big integer
+///
+/// Returns the value and the number of bits consumed.
+pub fn fibonacci_decode_big(bits: &[bool]) -> QuantumResult<(BigUint, usize)> {
+    let length = codeword_length(bits)?;
+    let mut value = BigUint::zero();
+    let (mut a, mut b) = (BigUint::one(), BigUint::from(2u32));
+
+    for bit in &bits[..length - 1] {
+        if *bit {
+            value += &a;
+        }
+        let next = &a + &b;
+        a = std::mem::replace(&mut b, next);
+    }
+
+    Ok((value, length))
+}
+
+/// Decode a codeword whose value fits in a u128
+fn decode_u128(bits: &[bool]) -> QuantumResult<(u128, usize)> {
+    let length = codeword_length(bits)?;
+    let terms = fibonacci_terms(u128::MAX);
+    if length - 1 > terms.len() {
+        return Err(QuantumError::OperationError {
+            message: "Fibonacci codeword too long for a fixed-width integer".to_string(),
+        });
+    }
+
+    let mut value = 0u128;
+    for (bit, term) in bits[..length - 1].iter().zip(&terms) {
+        if *bit {
+            value = value.checked_add(*term).ok_or_else(|| QuantumError::OperationError {
+                message: "Fibonacci codeword too long for a fixed-width integer".to_string(),
+            })?;
+        }
+    }
+
+    Ok((value, length))
+}
+
+/// Length of the first codeword, up to and including its terminating "11"
+fn codeword_length(bits: &[bool]) -> QuantumResult<usize> {
+    bits.windows(2)
+        .position(|pair| pair[0] && pair[1])
+        .map(|i| i + 2)
+        .ok_or_else(|| QuantumError::OperationError {
+            message: "Fibonacci codeword is missing its terminating 11".to_string(),
+        })
+}
+
+/// Error for encoding zero, which has no Fibonacci codeword
+fn zero_error() -> QuantumError {
+    QuantumError::OperationError {
+        message: "Fibonacci codes represent positive integers only".to_string(),
+    }
+}
+
+/// Bit-stream writer for sequences of integers in Fibonacci code
+///
+/// Values are shifted by one before encoding so that zero can be written.
+/// Bits are packed most significant first; the final byte is padded with
+/// zeros, which can never form a codeword.
+#[derive(Debug, Clone, Default)]
+pub struct FibonacciWriter {
+    /// Completed bytes
+    bytes: Vec<u8>,
+
+    /// Bits of the partially filled last byte
+    current: u8,
+
+    /// Number of bits in `current`
+    filled: u8,
+
+    /// Number of values written
+    count: usize,
+}
+
+impl FibonacciWriter {
+    /// Create an empty writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write a value
+    pub fn write(&mut self, value: u64) {
+        let mut bits = zeckendorf_digits(value as u128 + 1);
+        bits.push(true);
+        self.push_bits(&bits);
+    }
+
+    /// Write a big-integer value
+    pub fn write_big(&mut self, value: &BigUint) {
+        let mut bits = zeckendorf_digits_big(&(value + 1u32));
+        bits.push(true);
+        self.push_bits(&bits);
+    }
+
+    /// Get the number of values written
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the number of bits written so far
+    pub fn bit_len(&self) -> usize {
+        self.bytes.len() * 8 + self.filled as usize
+    }
+
+    /// Finish the stream, returning the packed bytes
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.bytes.push(self.current << (8 - self.filled));
+        }
+        self.bytes
+    }
+
+    /// Append bits to the stream
+    fn push_bits(&mut self, bits: &[bool]) {
+        for bit in bits {
+            self.current = (self.current << 1) | *bit as u8;
+            self.filled += 1;
+            if self.filled == 8 {
+                self.bytes.push(self.current);
+                self.current = 0;
+                self.filled = 0;
+            }
+        }
+        self.count += 1;
+    }
+}
+
+/// Bit-stream reader for Fibonacci-coded sequences written by [`FibonacciWriter`]
+#[derive(Debug, Clone)]
+pub struct FibonacciReader<'a> {
+    /// Packed stream
+    bytes: &'a [u8],
+
+    /// Position of the next unread bit
+    position: usize,
+}
+
+impl<'a> FibonacciReader<'a> {
+    /// Create a reader over packed bytes
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Read the next value, or `None` at the end of the stream
+    pub fn read(&mut self) -> QuantumResult<Option<u64>> {
+        match self.next_codeword()? {
+            Some(bits) => {
+                let (value, _) = decode_u128(&bits)?;
+                let value = u64::try_from(value - 1).map_err(|_| QuantumError::OperationError {
+                    message: "Fibonacci codeword overflows u64".to_string(),
+                })?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next big-integer value, or `None` at the end of the stream
+    pub fn read_big(&mut self) -> QuantumResult<Option<BigUint>> {
+        match self.next_codeword()? {
+            Some(bits) => Ok(Some(fibonacci_decode_big(&bits)?.0 - 1u32)),
+            None => Ok(None),
+        }
+    }
+
+    /// Collect the bits of the next codeword
+    fn next_codeword(&mut self) -> QuantumResult<Option<Vec<bool>>> {
+        let total = self.bytes.len() * 8;
+        let mut bits = Vec::new();
+
+        while self.position < total {
+            let bit = self.bytes[self.position / 8] & (0x80 >> (self.position % 8)) != 0;
+            self.position += 1;
+            let terminated = bit && bits.last() == Some(&true);
+            bits.push(bit);
+            if terminated {
+                return Ok(Some(bits));
+            }
+        }
+
+        // Trailing zeros are padding; anything else is a truncated codeword
+        if bits.iter().any(|bit| *bit) {
+            return Err(QuantumError::OperationError {
+                message: "Fibonacci stream ends inside a codeword".to_string(),
+            });
+        }
+        Ok(None)
+    }
+}
+
+impl Iterator for FibonacciReader<'_> {
+    type Item = QuantumResult<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+/// Encode a sequence of integers as a packed Fibonacci-coded stream
+pub fn encode_sequence(values: &[u64]) -> Vec<u8> {
+    let mut writer = FibonacciWriter::new();
+    for value in values {
+        writer.write(*value);
+    }
+    writer.finish()
+}
+
+/// Decode a packed Fibonacci-coded stream
+pub fn decode_sequence(bytes: &[u8]) -> QuantumResult<Vec<u64>> {
+    FibonacciReader::new(bytes).collect()
+}
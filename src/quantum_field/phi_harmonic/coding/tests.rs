@@ -0,0 +1,106 @@
+//! Unit tests for the coding module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::coding::{
+        decode_sequence, encode_sequence, fibonacci_decode, fibonacci_decode_big, fibonacci_encode,
+        fibonacci_encode_big, from_zeckendorf, zeckendorf, zeckendorf_big, FibonacciReader, FibonacciWriter,
+    };
+    use crate::quantum_field::phi_harmonic::exact::fibonacci;
+    use num::bigint::BigUint;
+
+    fn bits(text: &str) -> Vec<bool> {
+        text.chars().map(|c| c == '1').collect()
+    }
+
+    #[test]
+    fn test_zeckendorf_decomposition() {
+        assert_eq!(zeckendorf(0), Vec::<u64>::new());
+        assert_eq!(zeckendorf(100), vec![89, 8, 3]);
+        assert_eq!(zeckendorf(64), vec![55, 8, 1]);
+
+        for n in 0..2000u64 {
+            let terms = zeckendorf(n);
+            assert_eq!(from_zeckendorf(&terms).unwrap(), n);
+        }
+        assert_eq!(from_zeckendorf(&zeckendorf(u64::MAX)).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_recomposition_validates_terms() {
+        assert!(from_zeckendorf(&[4]).is_err());
+        assert!(from_zeckendorf(&[8, 5]).is_err());
+        assert!(from_zeckendorf(&[3, 8]).is_err());
+        assert!(from_zeckendorf(&[8, 8]).is_err());
+        assert_eq!(from_zeckendorf(&[13, 5, 1]).unwrap(), 19);
+    }
+
+    #[test]
+    fn test_big_zeckendorf() {
+        let n = fibonacci(300).to_biguint().unwrap() + BigUint::from(5u32);
+        let terms = zeckendorf_big(&n);
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms.iter().sum::<BigUint>(), n);
+    }
+
+    #[test]
+    fn test_known_codewords() {
+        assert_eq!(fibonacci_encode(1).unwrap(), bits("11"));
+        assert_eq!(fibonacci_encode(4).unwrap(), bits("1011"));
+        assert_eq!(fibonacci_encode(11).unwrap(), bits("001011"));
+        assert!(fibonacci_encode(0).is_err());
+
+        assert_eq!(fibonacci_decode(&bits("0010110101")).unwrap(), (11, 6));
+        assert!(fibonacci_decode(&bits("0101")).is_err());
+    }
+
+    #[test]
+    fn test_codeword_round_trips() {
+        for n in [1u64, 2, 3, 1000, 1 << 40, u64::MAX] {
+            let code = fibonacci_encode(n).unwrap();
+            assert_eq!(fibonacci_decode(&code).unwrap(), (n, code.len()));
+        }
+
+        let big = fibonacci(500).to_biguint().unwrap() * 7u32;
+        let code = fibonacci_encode_big(&big).unwrap();
+        assert_eq!(fibonacci_decode_big(&code).unwrap(), (big, code.len()));
+        assert!(fibonacci_decode(&code).is_err());
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let values = vec![0, 1, 2, 3, 42, 0, 987, u64::MAX, 7];
+        let bytes = encode_sequence(&values);
+        assert_eq!(decode_sequence(&bytes).unwrap(), values);
+        assert!(decode_sequence(&[]).unwrap().is_empty());
+
+        // Small values take few bits
+        let mut writer = FibonacciWriter::new();
+        writer.write(0);
+        writer.write(2);
+        assert_eq!((writer.count(), writer.bit_len()), (2, 2 + 4));
+    }
+
+    #[test]
+    fn test_stream_with_big_values() {
+        let big = fibonacci(200).to_biguint().unwrap();
+        let mut writer = FibonacciWriter::new();
+        writer.write(5);
+        writer.write_big(&big);
+        writer.write(9);
+        let bytes = writer.finish();
+
+        let mut reader = FibonacciReader::new(&bytes);
+        assert_eq!(reader.read().unwrap(), Some(5));
+        assert_eq!(reader.read_big().unwrap(), Some(big));
+        assert_eq!(reader.read().unwrap(), Some(9));
+        assert_eq!(reader.read().unwrap(), None);
+    }
+
+    #[test]
+    fn test_truncated_stream_is_rejected() {
+        let bytes = encode_sequence(&[1000, 2000]);
+        assert!(decode_sequence(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,532 @@
+//! Reversible Phi Compression
+//!
+//! This module wraps `phi_compress` in an encoder/decoder pair. The encoded
+//! form keeps the phi-weighted samples together with the original length and
+//! factor, so the input can be reconstructed by interpolating between the
+//! sample centroids. Three modes trade size for fidelity:
+//!
+//! - **Lossy**: samples only; the reconstruction error is measured at encode time
+//! - **Bounded**: residuals quantized so every value is within a tolerance
+//! - **Lossless**: exact integer residuals between order-preserving f64 bit patterns
+//!
+//! Residuals are stored as Fibonacci-coded integers, which keeps the many
+//! small corrections of smooth histories to a few bits each. Lossless
+//! residuals count the representable doubles between a value and its
+//! approximation, which runs to dozens of bits, so each one is split: the
+//! high part is Fibonacci-coded and the low bits are packed verbatim into a
+//! separate block after the Fibonacci stream. For
+//! smooth histories this stays below the eight bytes of a raw f64, but the
+//! samples are stored raw on top, so a lossless encoding is only smaller
+//! than its input at small factors.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{LAMBDA, PHI};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::phi_harmonic::coding::{FibonacciReader, FibonacciWriter};
+use crate::quantum_field::phi_harmonic::phi_compress;
+
+/// Magic bytes at the start of the serialized format
+const MAGIC: &[u8; 4] = b"PHIC";
+
+/// Serialized format version
+const VERSION: u8 = 1;
+
+/// Bound on quantized residuals, so their zigzag codes fit in a u64
+const QUANTIZATION_LIMIT: f64 = (1u64 << 62) as f64;
+
+/// How faithfully compressed values are reconstructed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionMode {
+    /// Keep only the phi-weighted samples
+    Lossy,
+
+    /// Keep quantized residuals so every value is within this tolerance
+    Bounded(f64),
+
+    /// Keep exact residuals for bit-for-bit reconstruction
+    Lossless,
+}
+
+/// Reconstructed values with their error against the original input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reconstruction {
+    /// Reconstructed values
+    pub values: Vec<f64>,
+
+    /// Largest absolute error
+    pub max_error: f64,
+
+    /// Root-mean-square error
+    pub rms_error: f64,
+}
+
+/// Encoded phi-compressed values
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhiCompressed {
+    /// Number of values before compression
+    original_len: usize,
+
+    /// Compression factor passed to `phi_compress`
+    factor: f64,
+
+    /// Reconstruction mode
+    mode: CompressionMode,
+
+    /// Phi-weighted samples
+    samples: Vec<f64>,
+
+    /// Fibonacci-coded residuals, or their high parts in lossless mode (empty in lossy mode)
+    residuals: Vec<u8>,
+
+    /// Number of low bits split off each lossless residual
+    low_bit_width: u32,
+
+    /// Packed low bits of the lossless residuals, most significant first
+    low_bits: Vec<u8>,
+
+    /// Largest absolute reconstruction error
+    max_error: f64,
+
+    /// Root-mean-square reconstruction error
+    rms_error: f64,
+}
+
+impl PhiCompressed {
+    /// Encode values with `phi_compress` at the given factor
+    ///
+    /// The factor must lie in (0, φ], where `phi_compress` keeps at most one
+    /// sample per value. In bounded mode, fails when a value lies too far
+    /// from its approximation to quantize, or when rounding at the value's
+    /// magnitude already exceeds the tolerance.
+    pub fn encode(values: &[f64], factor: f64, mode: CompressionMode) -> QuantumResult<Self> {
+        check_parameters(factor, mode).map_err(|message| QuantumError::OperationError { message })?;
+
+        let samples = phi_compress(values, factor);
+        if samples.is_empty() && !values.is_empty() {
+            return Err(QuantumError::OperationError {
+                message: format!("Factor {} leaves no samples for {} values", factor, values.len()),
+            });
+        }
+
+        let base = interpolate(&samples, values.len());
+        let mut writer = FibonacciWriter::new();
+        let (mut low_bit_width, mut low_bits) = (0, Vec::new());
+        let reconstructed: Vec<f64> = match mode {
+            CompressionMode::Lossy => base,
+            CompressionMode::Bounded(tolerance) => {
+                let step = 2.0 * tolerance;
+                let mut reconstructed = Vec::with_capacity(values.len());
+                for (value, approx) in values.iter().zip(&base) {
+                    let q = ((value - approx) / step).round();
+                    if q.is_nan() || q.abs() >= QUANTIZATION_LIMIT {
+                        return Err(QuantumError::OperationError {
+                            message: format!(
+                                "Value {} is too far from its approximation {} to quantize at tolerance {}",
+                                value, approx, tolerance
+                            ),
+                        });
+                    }
+                    writer.write(zigzag(q as i64));
+                    reconstructed.push(approx + q * step);
+                }
+                reconstructed
+            }
+            CompressionMode::Lossless => {
+                let codes: Vec<u64> = values.iter()
+                    .zip(&base)
+                    .map(|(value, approx)| zigzag(ordered_bits(*value).wrapping_sub(ordered_bits(*approx))))
+                    .collect();
+
+                low_bit_width = split_shift(&codes);
+                low_bits = pack_low_bits(&codes, low_bit_width);
+                for code in codes {
+                    writer.write(code >> low_bit_width);
+                }
+                values.to_vec()
+            }
+        };
+
+        let (max_error, rms_error) = error_stats(values, &reconstructed);
+        if let CompressionMode::Bounded(tolerance) = mode {
+            if max_error > tolerance {
+                return Err(QuantumError::OperationError {
+                    message: format!("Bounded reconstruction error {} exceeds tolerance {}", max_error, tolerance),
+                });
+            }
+        }
+        let residuals = if mode == CompressionMode::Lossy { Vec::new() } else { writer.finish() };
+
+        Ok(Self {
+            original_len: values.len(),
+            factor,
+            mode,
+            samples,
+            residuals,
+            low_bit_width,
+            low_bits,
+            max_error,
+            rms_error,
+        })
+    }
+
+    /// Get the number of values before compression
+    pub fn original_len(&self) -> usize {
+        self.original_len
+    }
+
+    /// Get the compression factor
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Get the reconstruction mode
+    pub fn mode(&self) -> CompressionMode {
+        self.mode
+    }
+
+    /// Get the phi-weighted samples, as `phi_compress` returns them
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Get the largest absolute reconstruction error
+    pub fn max_error(&self) -> f64 {
+        self.max_error
+    }
+
+    /// Get the root-mean-square reconstruction error
+    pub fn rms_error(&self) -> f64 {
+        self.rms_error
+    }
+
+    /// Reconstruct the original values
+    pub fn decode(&self) -> QuantumResult<Reconstruction> {
+        let base = interpolate(&self.samples, self.original_len);
+
+        let values = match self.mode {
+            CompressionMode::Lossy => base,
+            CompressionMode::Bounded(tolerance) => {
+                let step = 2.0 * tolerance;
+                let codes = self.read_residuals()?;
+                base.iter()
+                    .zip(codes)
+                    .map(|(approx, code)| approx + unzigzag(code) as f64 * step)
+                    .collect()
+            }
+            CompressionMode::Lossless => {
+                let codes = self.read_split_residuals()?;
+                base.iter()
+                    .zip(codes)
+                    .map(|(approx, code)| from_ordered_bits(ordered_bits(*approx).wrapping_add(unzigzag(code))))
+                    .collect()
+            }
+        };
+
+        Ok(Reconstruction { values, max_error: self.max_error, rms_error: self.rms_error })
+    }
+
+    /// Serialize to bytes
+    ///
+    /// Layout (little-endian): magic "PHIC", version, mode tag, original
+    /// length (u64), factor, tolerance, max and RMS error (f64), sample count
+    /// (u64) and samples, residual byte count (u64) and residual bytes, then
+    /// the low-bit width (u8) and the packed low bits of every residual.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (tag, tolerance) = match self.mode {
+            CompressionMode::Lossy => (0u8, 0.0),
+            CompressionMode::Bounded(tolerance) => (1, tolerance),
+            CompressionMode::Lossless => (2, 0.0),
+        };
+
+        let mut bytes = Vec::with_capacity(63 + 8 * self.samples.len() + self.residuals.len() + self.low_bits.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.push(tag);
+        bytes.extend_from_slice(&(self.original_len as u64).to_le_bytes());
+        for value in [self.factor, tolerance, self.max_error, self.rms_error] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(self.samples.len() as u64).to_le_bytes());
+        for sample in &self.samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(self.residuals.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.residuals);
+        bytes.push(self.low_bit_width as u8);
+        bytes.extend_from_slice(&self.low_bits);
+        bytes
+    }
+
+    /// Deserialize from bytes written by [`PhiCompressed::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> QuantumResult<Self> {
+        let mut cursor = Cursor { bytes, position: 0 };
+        if cursor.take(4)? != MAGIC {
+            return Err(format_error("missing PHIC header"));
+        }
+        let version = cursor.take(1)?[0];
+        if version != VERSION {
+            return Err(format_error(&format!("unsupported version {}", version)));
+        }
+
+        let tag = cursor.take(1)?[0];
+        let original_len = cursor.read_len()?;
+        let factor = cursor.read_f64()?;
+        let tolerance = cursor.read_f64()?;
+        let max_error = cursor.read_f64()?;
+        let rms_error = cursor.read_f64()?;
+
+        let mode = match tag {
+            0 => CompressionMode::Lossy,
+            1 => CompressionMode::Bounded(tolerance),
+            2 => CompressionMode::Lossless,
+            _ => return Err(format_error(&format!("unknown mode {}", tag))),
+        };
+        check_parameters(factor, mode).map_err(|message| format_error(&message))?;
+
+        let sample_count = cursor.read_len()?;
+        let expected = expected_sample_count(original_len, factor);
+        if sample_count != expected || (sample_count == 0 && original_len > 0) {
+            return Err(format_error(&format!(
+                "{} samples for {} values at factor {} (expected {})",
+                sample_count, original_len, factor, expected
+            )));
+        }
+        let samples = (0..sample_count).map(|_| cursor.read_f64()).collect::<QuantumResult<Vec<f64>>>()?;
+
+        // Every residual codeword takes at least two bits
+        let residual_len = cursor.read_len()?;
+        let plausible = match mode {
+            CompressionMode::Lossy => residual_len == 0,
+            _ => residual_len >= original_len.div_ceil(4),
+        };
+        if !plausible {
+            return Err(format_error(&format!("{} residual bytes for {} values", residual_len, original_len)));
+        }
+        let residuals = cursor.take(residual_len)?.to_vec();
+
+        let low_bit_width = cursor.take(1)?[0] as u32;
+        if low_bit_width >= 64 || (low_bit_width > 0 && mode != CompressionMode::Lossless) {
+            return Err(format_error(&format!("low-bit width {} out of range", low_bit_width)));
+        }
+        let low_bits = cursor.take(low_bit_bytes(original_len, low_bit_width))?.to_vec();
+
+        if cursor.position != bytes.len() {
+            return Err(format_error("trailing bytes"));
+        }
+
+        Ok(Self { original_len, factor, mode, samples, residuals, low_bit_width, low_bits, max_error, rms_error })
+    }
+
+    /// Read one residual code per original value
+    fn read_residuals(&self) -> QuantumResult<Vec<u64>> {
+        let mut reader = FibonacciReader::new(&self.residuals);
+        (0..self.original_len)
+            .map(|_| reader.read()?.ok_or_else(|| format_error("residual stream ends early")))
+            .collect()
+    }
+
+    /// Join the coded high parts with the packed low bits of each residual
+    fn read_split_residuals(&self) -> QuantumResult<Vec<u64>> {
+        let width = self.low_bit_width;
+        self.read_residuals()?
+            .into_iter()
+            .enumerate()
+            .map(|(i, high)| {
+                high.checked_mul(1 << width)
+                    .map(|code| code | read_low_bits(&self.low_bits, i, width))
+                    .ok_or_else(|| format_error("residual overflows u64"))
+            })
+            .collect()
+    }
+}
+
+/// Check a compression factor and the bounded-mode tolerance
+fn check_parameters(factor: f64, mode: CompressionMode) -> Result<(), String> {
+    if !factor.is_finite() || factor <= 0.0 || factor > PHI {
+        return Err(format!("Compression factor must be in (0, φ], got {}", factor));
+    }
+    if let CompressionMode::Bounded(tolerance) = mode {
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(format!("Error bound must be positive, got {}", tolerance));
+        }
+    }
+    Ok(())
+}
+
+/// Number of samples `phi_compress` produces for `original_len` values
+fn expected_sample_count(original_len: usize, factor: f64) -> usize {
+    (original_len as f64 * LAMBDA * factor) as usize
+}
+
+/// Map a signed integer to an unsigned one, interleaving signs (0, -1, 1, -2, ...)
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of [`zigzag`]
+fn unzigzag(code: u64) -> i64 {
+    ((code >> 1) as i64) ^ -((code & 1) as i64)
+}
+
+/// f64 bit pattern as an integer that increases with the value
+///
+/// Adjacent doubles differ by one, so the difference between a value and
+/// its approximation counts the representable doubles between them.
+fn ordered_bits(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 { bits ^ i64::MAX } else { bits }
+}
+
+/// Inverse of [`ordered_bits`]
+fn from_ordered_bits(bits: i64) -> f64 {
+    f64::from_bits((if bits < 0 { bits ^ i64::MAX } else { bits }) as u64)
+}
+
+/// Number of low bits to store verbatim for a set of residual codes
+///
+/// One bit less than the mean code's bit length leaves high parts of a few
+/// units, which take only a few bits in Fibonacci code.
+fn split_shift(codes: &[u64]) -> u32 {
+    if codes.is_empty() {
+        return 0;
+    }
+
+    let mean = codes.iter().map(|c| *c as u128).sum::<u128>() / codes.len() as u128;
+    (128 - mean.leading_zeros()).saturating_sub(1)
+}
+
+/// Bytes needed to pack `width` low bits of `count` residuals
+fn low_bit_bytes(count: usize, width: u32) -> usize {
+    ((count as u128 * width as u128).div_ceil(8)).min(usize::MAX as u128) as usize
+}
+
+/// Pack the low `width` bits of each code, most significant first
+fn pack_low_bits(codes: &[u64], width: u32) -> Vec<u8> {
+    let mut bytes = vec![0u8; low_bit_bytes(codes.len(), width)];
+    let mut position = 0;
+    for code in codes {
+        for k in (0..width).rev() {
+            if (code >> k) & 1 == 1 {
+                bytes[position / 8] |= 0x80 >> (position % 8);
+            }
+            position += 1;
+        }
+    }
+    bytes
+}
+
+/// Low `width` bits of the code at `index`, as packed by [`pack_low_bits`]
+fn read_low_bits(bytes: &[u8], index: usize, width: u32) -> u64 {
+    let start = index * width as usize;
+    (start..start + width as usize).fold(0, |value, position| {
+        (value << 1) | ((bytes[position / 8] >> (7 - position % 8)) & 1) as u64
+    })
+}
+
+/// Position of each `phi_compress` sample within the input
+///
+/// Mirrors the windows of `phi_compress`: sample i averages indices from
+/// ⌊i·step⌋ to ⌊(i+1)·step⌋ with weights φ^-offset, so it represents the
+/// weighted centroid of that window.
+fn sample_positions(original_len: usize, sample_count: usize) -> Vec<f64> {
+    let step = original_len as f64 / sample_count as f64;
+
+    (0..sample_count)
+        .map(|i| {
+            let index = (i as f64 * step) as usize;
+            let next_index = (((i as f64 + 1.0) * step) as usize).min(original_len - 1);
+
+            let (mut moment, mut weight_sum) = (0.0, 0.0);
+            for offset in 0..=next_index.saturating_sub(index) {
+                let weight = PHI.powf(-(offset as f64));
+                moment += offset as f64 * weight;
+                weight_sum += weight;
+            }
+            index as f64 + moment / weight_sum
+        })
+        .collect()
+}
+
+/// Piecewise-linear reconstruction of `original_len` values from samples
+fn interpolate(samples: &[f64], original_len: usize) -> Vec<f64> {
+    if samples.is_empty() || original_len == 0 {
+        return Vec::new();
+    }
+
+    let positions = sample_positions(original_len, samples.len());
+    let mut k = 0;
+
+    (0..original_len)
+        .map(|j| {
+            let x = j as f64;
+            while k + 1 < positions.len() && positions[k + 1] <= x {
+                k += 1;
+            }
+
+            if x <= positions[0] {
+                samples[0]
+            } else if k + 1 == positions.len() {
+                samples[k]
+            } else {
+                let t = (x - positions[k]) / (positions[k + 1] - positions[k]);
+                samples[k] + t * (samples[k + 1] - samples[k])
+            }
+        })
+        .collect()
+}
+
+/// Largest and root-mean-square absolute differences
+fn error_stats(original: &[f64], reconstructed: &[f64]) -> (f64, f64) {
+    if original.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let (max, squares) = original.iter()
+        .zip(reconstructed)
+        .map(|(a, b)| (a - b).abs())
+        .fold((0.0f64, 0.0), |(max, squares), e| (max.max(e), squares + e * e));
+    (max, (squares / original.len() as f64).sqrt())
+}
+
+/// Error for malformed serialized data
+fn format_error(reason: &str) -> QuantumError {
+    QuantumError::OperationError {
+        message: format!("Invalid phi-compressed data: {}", reason),
+    }
+}
+
+/// Sequential reader over serialized bytes
+struct Cursor<'a> {
+    /// Serialized bytes
+    bytes: &'a [u8],
+
+    /// Offset of the next unread byte
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Take the next `count` bytes
+    fn take(&mut self, count: usize) -> QuantumResult<&'a [u8]> {
+        let end = self.position.checked_add(count).filter(|end| *end <= self.bytes.len());
+        let end = end.ok_or_else(|| format_error("unexpected end of data"))?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    /// Read a little-endian u64 as a length
+    fn read_len(&mut self) -> QuantumResult<usize> {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(self.take(8)?);
+        usize::try_from(u64::from_le_bytes(word)).map_err(|_| format_error("length overflows usize"))
+    }
+
+    /// Read a little-endian f64
+    fn read_f64(&mut self) -> QuantumResult<f64> {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(self.take(8)?);
+        Ok(f64::from_le_bytes(word))
+    }
+}
@@ -0,0 +1,180 @@
+//! Unit tests for the compression module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::compression::{CompressionMode, PhiCompressed};
+    use crate::quantum_field::phi_harmonic::phi_compress;
+    use crate::constants::{LAMBDA, PHI};
+
+    fn history(len: usize) -> Vec<f64> {
+        (0..len).map(|i| 0.8 + 0.1 * (i as f64 * 0.05).sin() + 0.01 * (i as f64 * 0.9).cos()).collect()
+    }
+
+    #[test]
+    fn test_records_length_factor_and_samples() {
+        let values = history(200);
+        let encoded = PhiCompressed::encode(&values, 0.5, CompressionMode::Lossy).unwrap();
+
+        assert_eq!(encoded.original_len(), 200);
+        assert_eq!(encoded.factor(), 0.5);
+        assert_eq!(encoded.samples(), phi_compress(&values, 0.5).as_slice());
+        assert_eq!(encoded.decode().unwrap().values.len(), 200);
+    }
+
+    #[test]
+    fn test_lossy_reconstruction_reports_its_error() {
+        let values = history(300);
+        let encoded = PhiCompressed::encode(&values, 0.5, CompressionMode::Lossy).unwrap();
+        let decoded = encoded.decode().unwrap();
+
+        let actual_max = values.iter().zip(&decoded.values).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+        assert_eq!(decoded.max_error, actual_max);
+        assert!(decoded.rms_error <= decoded.max_error);
+        assert!(decoded.max_error < 0.05);
+
+        // A linear ramp is reconstructed almost exactly away from the ends
+        let ramp: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let decoded = PhiCompressed::encode(&ramp, 0.5, CompressionMode::Lossy).unwrap().decode().unwrap();
+        assert!(decoded.values[10..90].iter().zip(&ramp[10..90]).all(|(a, b)| (a - b).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_bounded_mode_respects_tolerance() {
+        let values = history(500);
+        for tolerance in [1e-2, 1e-4, 1e-7] {
+            let encoded = PhiCompressed::encode(&values, 0.5, CompressionMode::Bounded(tolerance)).unwrap();
+            let decoded = encoded.decode().unwrap();
+
+            let worst = values.iter().zip(&decoded.values).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+            assert!(worst <= tolerance * (1.0 + 1e-9));
+            assert_eq!(worst, decoded.max_error);
+        }
+
+        assert!(PhiCompressed::encode(&values, 0.5, CompressionMode::Bounded(0.0)).is_err());
+    }
+
+    #[test]
+    fn test_bounded_mode_rejects_unreachable_tolerances() {
+        // Residuals beyond 2^62 steps cannot be quantized
+        let extreme = [1e300, -1e300, 0.0, 5.0];
+        assert!(PhiCompressed::encode(&extreme, 0.5, CompressionMode::Bounded(1e-3)).is_err());
+        assert!(PhiCompressed::encode(&extreme, 0.5, CompressionMode::Lossless).is_ok());
+
+        // The residuals fit, but reconstructing the small values from an
+        // approximation near 1e15 rounds to the spacing of doubles there
+        let coarse = [0.1, 3e15, 0.3, 0.7, 3e15, 0.1];
+        assert!(PhiCompressed::encode(&coarse, 0.5, CompressionMode::Bounded(1e-3)).is_err());
+        assert!(PhiCompressed::encode(&coarse, 0.5, CompressionMode::Bounded(1.0)).is_ok());
+
+        assert!(PhiCompressed::encode(&[f64::NAN, 1.0], 0.5, CompressionMode::Bounded(1e-3)).is_err());
+    }
+
+    #[test]
+    fn test_lossless_mode_is_bit_exact() {
+        let mut values = history(257);
+        values[17] = -0.0;
+        values[99] = 1e300;
+        values[200] = f64::MIN_POSITIVE;
+
+        let encoded = PhiCompressed::encode(&values, 0.7, CompressionMode::Lossless).unwrap();
+        let decoded = encoded.decode().unwrap();
+
+        assert!(decoded.values.iter().zip(&values).all(|(a, b)| a.to_bits() == b.to_bits()));
+        assert_eq!(decoded.max_error, 0.0);
+
+        let extremes = [f64::MAX, -f64::MAX, f64::INFINITY, f64::NEG_INFINITY, 0.0, -0.0, 5e-324];
+        let decoded = PhiCompressed::encode(&extremes, 0.5, CompressionMode::Lossless).unwrap().decode().unwrap();
+        assert!(decoded.values.iter().zip(&extremes).all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
+
+    #[test]
+    fn test_lossless_mode_is_smaller_than_raw() {
+        for len in [100, 1000, 5000] {
+            let values = history(len);
+
+            // Residuals alone stay below the raw f64s at any factor
+            for factor in [0.05, 0.5] {
+                let encoded = PhiCompressed::encode(&values, factor, CompressionMode::Lossless).unwrap();
+                let residual_len = encoded.residuals.len() + encoded.low_bits.len();
+                assert!(residual_len < len * 8, "{} values took {} residual bytes", len, residual_len);
+            }
+
+            // With few samples the whole encoding does too
+            let bytes = PhiCompressed::encode(&values, 0.05, CompressionMode::Lossless).unwrap().to_bytes();
+            assert!(bytes.len() < len * 8, "{} values took {} bytes", len, bytes.len());
+        }
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let values = history(150);
+        for mode in [CompressionMode::Lossy, CompressionMode::Bounded(1e-3), CompressionMode::Lossless] {
+            let encoded = PhiCompressed::encode(&values, 0.5, mode).unwrap();
+            let bytes = encoded.to_bytes();
+            let restored = PhiCompressed::from_bytes(&bytes).unwrap();
+
+            assert_eq!(restored, encoded);
+            assert_eq!(restored.decode().unwrap(), encoded.decode().unwrap());
+        }
+
+        // Bounded residuals of a smooth history take far fewer bytes than raw f64s
+        let bounded = PhiCompressed::encode(&values, 0.5, CompressionMode::Bounded(1e-3)).unwrap();
+        assert!(bounded.to_bytes().len() < values.len() * 8 / 2);
+
+        let bytes = bounded.to_bytes();
+        assert!(PhiCompressed::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(PhiCompressed::from_bytes(b"NOPE").is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_inconsistent_headers() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+        let lossy = PhiCompressed::encode(&values, 0.5, CompressionMode::Lossy).unwrap().to_bytes();
+        let bounded = PhiCompressed::encode(&values, 0.5, CompressionMode::Bounded(1e-3)).unwrap().to_bytes();
+
+        // Header fields: original length at 6, factor at 14, tolerance at 22
+        let patched = |bytes: &[u8], offset: usize, field: [u8; 8]| {
+            let mut bytes = bytes.to_vec();
+            bytes[offset..offset + 8].copy_from_slice(&field);
+            PhiCompressed::from_bytes(&bytes)
+        };
+
+        assert!(patched(&lossy, 6, (1u64 << 61).to_le_bytes()).is_err());
+        assert!(patched(&lossy, 14, 0.9f64.to_le_bytes()).is_err());
+        assert!(patched(&lossy, 14, f64::NAN.to_le_bytes()).is_err());
+        assert!(patched(&lossy, 14, 2.0f64.to_le_bytes()).is_err());
+        assert!(patched(&bounded, 22, (-1e-3f64).to_le_bytes()).is_err());
+        assert!(patched(&bounded, 22, f64::INFINITY.to_le_bytes()).is_err());
+
+        // A claimed length and factor that agree on one sample still need residuals for every value
+        let mut long = bounded.clone();
+        long[6..14].copy_from_slice(&400u64.to_le_bytes());
+        long[14..22].copy_from_slice(&(1.5 / (400.0 * LAMBDA)).to_le_bytes());
+        assert!(PhiCompressed::from_bytes(&long).unwrap_err().to_string().contains("residual bytes"));
+
+        // The low-bit width ends the data; only lossless residuals are split
+        let mut split = bounded.clone();
+        *split.last_mut().unwrap() = 3;
+        assert!(PhiCompressed::from_bytes(&split).is_err());
+
+        let lossless = PhiCompressed::encode(&history(100), 0.5, CompressionMode::Lossless).unwrap();
+        assert!(lossless.low_bit_width > 0);
+        let bytes = lossless.to_bytes();
+        assert!(PhiCompressed::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert!(PhiCompressed::encode(&[1.0, 2.0], 0.0, CompressionMode::Lossy).is_err());
+        assert!(PhiCompressed::encode(&[1.0, 2.0], 0.1, CompressionMode::Lossy).is_err());
+        assert!(PhiCompressed::encode(&[1.0; 1000], 1e15, CompressionMode::Lossy).is_err());
+        assert!(PhiCompressed::encode(&[1.0; 1000], 1.7, CompressionMode::Lossy).is_err());
+
+        let densest = PhiCompressed::encode(&[1.0; 1000], PHI, CompressionMode::Lossy).unwrap();
+        assert!(densest.samples().len() <= 1000);
+
+        let empty = PhiCompressed::encode(&[], 0.5, CompressionMode::Lossless).unwrap();
+        assert!(empty.decode().unwrap().values.is_empty());
+    }
+}
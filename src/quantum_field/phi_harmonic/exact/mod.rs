@@ -0,0 +1,369 @@
+//! Exact Golden-Field Arithmetic
+//!
+//! This module provides exact arithmetic in Q(√5), the field generated by φ.
+//! Every element is written a + b·φ with rational coefficients, so identities
+//! such as φ·λ = 1 or φ² = φ + 1 hold exactly rather than to f64 precision.
+//! Exact big-integer Fibonacci and Lucas numbers are computed by fast doubling.
+
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::bigint::BigInt;
+use num::rational::BigRational;
+use num::traits::{One, Signed, ToPrimitive, Zero};
+
+use crate::error::{QuantumError, QuantumResult};
+
+/// Exact element a + b·φ of the golden field Q(√5)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhiNumber {
+    /// Rational part a
+    a: BigRational,
+
+    /// Coefficient b of φ
+    b: BigRational,
+}
+
+impl PhiNumber {
+    /// Create a + b·φ from rational coefficients
+    pub fn new(a: BigRational, b: BigRational) -> Self {
+        Self { a, b }
+    }
+
+    /// Create a + b·φ from integer coefficients
+    pub fn from_integers(a: i64, b: i64) -> Self {
+        Self::new(BigRational::from_integer(a.into()), BigRational::from_integer(b.into()))
+    }
+
+    /// Create a + b·φ from big-integer coefficients
+    pub fn from_big_integers(a: BigInt, b: BigInt) -> Self {
+        Self::new(BigRational::from_integer(a), BigRational::from_integer(b))
+    }
+
+    /// Exact value of an f64 (every finite f64 is a dyadic rational)
+    pub fn from_f64(value: f64) -> QuantumResult<Self> {
+        let rational = BigRational::from_float(value).ok_or_else(|| QuantumError::OperationError {
+            message: format!("Cannot represent {} exactly", value),
+        })?;
+        Ok(Self::rational(rational))
+    }
+
+    /// Create a rational number (b = 0)
+    pub fn rational(value: BigRational) -> Self {
+        Self::new(value, BigRational::zero())
+    }
+
+    /// Zero
+    pub fn zero() -> Self {
+        Self::from_integers(0, 0)
+    }
+
+    /// One
+    pub fn one() -> Self {
+        Self::from_integers(1, 0)
+    }
+
+    /// The golden ratio φ
+    pub fn phi() -> Self {
+        Self::from_integers(0, 1)
+    }
+
+    /// The divine complement λ = φ - 1 = 1/φ
+    pub fn lambda() -> Self {
+        Self::from_integers(-1, 1)
+    }
+
+    /// √5 = 2φ - 1
+    pub fn sqrt5() -> Self {
+        Self::from_integers(-1, 2)
+    }
+
+    /// Get the rational part a
+    pub fn rational_part(&self) -> &BigRational {
+        &self.a
+    }
+
+    /// Get the coefficient b of φ
+    pub fn phi_part(&self) -> &BigRational {
+        &self.b
+    }
+
+    /// Check whether the number is zero
+    pub fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+
+    /// Check whether the number is rational (b = 0)
+    pub fn is_rational(&self) -> bool {
+        self.b.is_zero()
+    }
+
+    /// Galois conjugate: φ ↦ 1 - φ, so a + b·φ ↦ (a + b) - b·φ
+    pub fn conjugate(&self) -> Self {
+        Self::new(&self.a + &self.b, -&self.b)
+    }
+
+    /// Field norm x·x̄ = a² + ab - b², always rational
+    pub fn norm(&self) -> BigRational {
+        &self.a * &self.a + &self.a * &self.b - &self.b * &self.b
+    }
+
+    /// Field trace x + x̄ = 2a + b, always rational
+    pub fn trace(&self) -> BigRational {
+        &self.a + &self.a + &self.b
+    }
+
+    /// Multiplicative inverse x̄ / N(x)
+    pub fn inverse(&self) -> QuantumResult<Self> {
+        if self.is_zero() {
+            return Err(QuantumError::OperationError {
+                message: "Zero has no inverse in Q(√5)".to_string(),
+            });
+        }
+
+        let norm = self.norm();
+        let conjugate = self.conjugate();
+        Ok(Self::new(conjugate.a / &norm, conjugate.b / norm))
+    }
+
+    /// Integer power, negative exponents inverting first
+    pub fn pow(&self, exponent: i64) -> QuantumResult<Self> {
+        let mut base = if exponent < 0 { self.inverse()? } else { self.clone() };
+        let mut remaining = exponent.unsigned_abs();
+        let mut result = Self::one();
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            remaining >>= 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Sign of the number: -1, 0 or 1
+    pub fn signum(&self) -> i32 {
+        // x = (p + b√5)/2 with p = 2a + b; compare p² with 5b² when signs differ
+        let p = self.trace();
+        let p_sign = sign(&p);
+        let b_sign = sign(&self.b);
+
+        if p_sign == b_sign || b_sign == 0 {
+            return p_sign;
+        }
+        if p_sign == 0 {
+            return b_sign;
+        }
+
+        let five = BigRational::from_integer(5.into());
+        match (&p * &p).cmp(&(five * &self.b * &self.b)) {
+            Ordering::Greater => p_sign,
+            Ordering::Less => b_sign,
+            Ordering::Equal => 0,
+        }
+    }
+
+    /// Approximate f64 value, computed without cancellation
+    ///
+    /// When the two terms of (p + b√5)/2 have opposite signs the value is
+    /// evaluated as N(x)/x̄ instead, so even tiny numbers such as λ¹⁰⁰ keep
+    /// their relative precision. The result is within a few ulps of the
+    /// exact value but is not guaranteed to be correctly rounded.
+    pub fn to_f64(&self) -> f64 {
+        let p = ratio_to_f64(&self.trace());
+        let q = ratio_to_f64(&self.b) * 5f64.sqrt();
+
+        if p * q >= 0.0 {
+            (p + q) / 2.0
+        } else {
+            // x·x̄ = N(x) and x̄ = (p - q)/2 has no cancellation
+            2.0 * ratio_to_f64(&self.norm()) / (p - q)
+        }
+    }
+}
+
+impl Default for PhiNumber {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl fmt::Display for PhiNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.b.is_zero() {
+            return write!(f, "{}", self.a);
+        }
+        if self.a.is_zero() {
+            return write!(f, "{}φ", self.b);
+        }
+        if self.b.is_negative() {
+            write!(f, "{} - {}φ", self.a, -&self.b)
+        } else {
+            write!(f, "{} + {}φ", self.a, self.b)
+        }
+    }
+}
+
+impl PartialOrd for PhiNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PhiNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self - other).signum().cmp(&0)
+    }
+}
+
+impl Add for &PhiNumber {
+    type Output = PhiNumber;
+
+    fn add(self, other: &PhiNumber) -> PhiNumber {
+        PhiNumber::new(&self.a + &other.a, &self.b + &other.b)
+    }
+}
+
+impl Sub for &PhiNumber {
+    type Output = PhiNumber;
+
+    fn sub(self, other: &PhiNumber) -> PhiNumber {
+        PhiNumber::new(&self.a - &other.a, &self.b - &other.b)
+    }
+}
+
+impl Mul for &PhiNumber {
+    type Output = PhiNumber;
+
+    fn mul(self, other: &PhiNumber) -> PhiNumber {
+        // (a + bφ)(c + dφ) = (ac + bd) + (ad + bc + bd)φ using φ² = φ + 1
+        let bd = &self.b * &other.b;
+        PhiNumber::new(
+            &self.a * &other.a + &bd,
+            &self.a * &other.b + &self.b * &other.a + bd,
+        )
+    }
+}
+
+impl Div for &PhiNumber {
+    type Output = PhiNumber;
+
+    /// Division; panics on division by zero like the rational coefficients
+    fn div(self, other: &PhiNumber) -> PhiNumber {
+        let norm = other.norm();
+        if norm.is_zero() {
+            panic!("division by zero in Q(√5)");
+        }
+        let product = self * &other.conjugate();
+        PhiNumber::new(product.a / &norm, product.b / norm)
+    }
+}
+
+impl Neg for &PhiNumber {
+    type Output = PhiNumber;
+
+    fn neg(self) -> PhiNumber {
+        PhiNumber::new(-&self.a, -&self.b)
+    }
+}
+
+impl Neg for PhiNumber {
+    type Output = PhiNumber;
+
+    fn neg(self) -> PhiNumber {
+        -&self
+    }
+}
+
+/// Forward owned operands to the reference implementations
+macro_rules! forward_owned {
+    ($($trait:ident :: $method:ident),*) => {
+        $(
+            impl $trait for PhiNumber {
+                type Output = PhiNumber;
+
+                fn $method(self, other: PhiNumber) -> PhiNumber {
+                    (&self).$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned!(Add::add, Sub::sub, Mul::mul, Div::div);
+
+/// Exact Fibonacci number F(n), with F(0) = 0 and F(1) = 1
+pub fn fibonacci(n: u64) -> BigInt {
+    fibonacci_pair(n).0
+}
+
+/// Exact Lucas number L(n) = F(n-1) + F(n+1), with L(0) = 2 and L(1) = 1
+pub fn lucas(n: u64) -> BigInt {
+    let (f, next) = fibonacci_pair(n);
+    // L(n) = 2F(n+1) - F(n)
+    &next + &next - f
+}
+
+/// Consecutive Fibonacci numbers (F(n), F(n+1)) by fast doubling
+///
+/// Uses F(2k) = F(k)·(2F(k+1) - F(k)) and F(2k+1) = F(k)² + F(k+1)², taking
+/// O(log n) big-integer multiplications.
+pub fn fibonacci_pair(n: u64) -> (BigInt, BigInt) {
+    let mut f = BigInt::zero();
+    let mut next = BigInt::one();
+
+    for bit in (0..64 - n.leading_zeros()).rev() {
+        let doubled = &f * (&next + &next - &f);
+        let doubled_next = &f * &f + &next * &next;
+
+        if (n >> bit) & 1 == 1 {
+            next = &doubled + &doubled_next;
+            f = doubled_next;
+        } else {
+            f = doubled;
+            next = doubled_next;
+        }
+    }
+
+    (f, next)
+}
+
+/// Exact counterpart of `phi_sequence`: 1, φ, φ², ... where φⁿ = F(n-1) + F(n)·φ
+pub fn exact_phi_sequence(length: usize) -> Vec<PhiNumber> {
+    let mut sequence = Vec::with_capacity(length);
+    if length > 0 {
+        sequence.push(PhiNumber::one());
+    }
+    if length > 1 {
+        sequence.push(PhiNumber::phi());
+    }
+    for i in 2..length {
+        let next = &sequence[i - 1] + &sequence[i - 2];
+        sequence.push(next);
+    }
+    sequence
+}
+
+/// Sign of a rational number: -1, 0 or 1
+fn sign(value: &BigRational) -> i32 {
+    if value.is_zero() {
+        0
+    } else if value.is_negative() {
+        -1
+    } else {
+        1
+    }
+}
+
+/// Nearest f64 to a rational, saturating to ±∞ when out of range
+fn ratio_to_f64(value: &BigRational) -> f64 {
+    value.to_f64().unwrap_or_else(|| {
+        if value.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY }
+    })
+}
@@ -0,0 +1,127 @@
+//! Unit tests for the exact module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{LAMBDA, PHI};
+    use crate::quantum_field::phi_harmonic::exact::{
+        exact_phi_sequence, fibonacci, fibonacci_pair, lucas, PhiNumber,
+    };
+    use crate::quantum_field::phi_harmonic::phi_sequence;
+    use num::bigint::BigInt;
+    use num::rational::BigRational;
+
+    #[test]
+    fn test_golden_identities_hold_exactly() {
+        let phi = PhiNumber::phi();
+        let lambda = PhiNumber::lambda();
+        let one = PhiNumber::one();
+
+        assert_eq!(&phi * &lambda, one);
+        assert_eq!(&phi * &phi, &phi + &one);
+        assert_eq!(&phi - &lambda, one);
+        assert_eq!(phi.inverse().unwrap(), lambda);
+        assert_eq!(&PhiNumber::sqrt5() * &PhiNumber::sqrt5(), PhiNumber::from_integers(5, 0));
+        assert_eq!(&one / &phi, lambda);
+    }
+
+    #[test]
+    fn test_conjugate_norm_and_trace() {
+        let x = PhiNumber::from_integers(3, -7);
+        let product = &x * &x.conjugate();
+
+        assert!(product.is_rational());
+        assert_eq!(product.rational_part(), &x.norm());
+        assert_eq!(&x + &x.conjugate(), PhiNumber::rational(x.trace()));
+
+        // φ is a unit of norm -1, and its conjugate is -λ
+        assert_eq!(PhiNumber::phi().norm(), BigRational::from_integer((-1).into()));
+        assert_eq!(PhiNumber::phi().conjugate(), -PhiNumber::lambda());
+    }
+
+    #[test]
+    fn test_division_and_inverse() {
+        let x = PhiNumber::new(
+            BigRational::new(2.into(), 3.into()),
+            BigRational::new((-5).into(), 7.into()),
+        );
+        let y = PhiNumber::from_integers(4, 9);
+
+        assert_eq!(&(&x / &y) * &y, x);
+        assert_eq!(&x * &x.inverse().unwrap(), PhiNumber::one());
+        assert!(PhiNumber::zero().inverse().is_err());
+    }
+
+    #[test]
+    fn test_powers_follow_fibonacci() {
+        let phi = PhiNumber::phi();
+        for n in 1..40u64 {
+            // φⁿ = F(n-1) + F(n)·φ
+            let expected = PhiNumber::from_big_integers(fibonacci(n - 1), fibonacci(n));
+            assert_eq!(phi.pow(n as i64).unwrap(), expected);
+        }
+
+        assert_eq!(phi.pow(-3).unwrap(), PhiNumber::lambda().pow(3).unwrap());
+        assert_eq!(phi.pow(0).unwrap(), PhiNumber::one());
+        assert!(PhiNumber::zero().pow(-1).is_err());
+    }
+
+    #[test]
+    fn test_exact_ordering() {
+        let phi = PhiNumber::phi();
+        let lambda = PhiNumber::lambda();
+        assert!(phi > lambda && lambda > PhiNumber::zero());
+
+        // 1.6180339887 < φ < 1.6180339888, decided exactly
+        let below = PhiNumber::rational(BigRational::new(16180339887i64.into(), 10000000000i64.into()));
+        let above = PhiNumber::rational(BigRational::new(16180339888i64.into(), 10000000000i64.into()));
+        assert!(below < phi && phi < above);
+
+        assert_eq!(PhiNumber::from_integers(1, -1).signum(), -1);
+        assert_eq!(PhiNumber::zero().signum(), 0);
+    }
+
+    #[test]
+    fn test_f64_conversion_keeps_precision() {
+        assert_eq!(PhiNumber::phi().to_f64(), PHI);
+        assert!((PhiNumber::lambda().to_f64() - LAMBDA).abs() < 1e-15);
+
+        // λ¹⁰⁰ = F(101) - F(100)·φ cancels catastrophically if evaluated naively
+        let tiny = PhiNumber::lambda().pow(100).unwrap();
+        let expected = LAMBDA.powi(100);
+        assert!(((tiny.to_f64() - expected) / expected).abs() < 1e-13);
+
+        let exact = PhiNumber::from_f64(0.1).unwrap();
+        assert_eq!(exact.to_f64(), 0.1);
+        assert!(PhiNumber::from_f64(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_big_fibonacci_and_lucas() {
+        assert_eq!(fibonacci(0), BigInt::from(0));
+        assert_eq!(fibonacci(10), BigInt::from(55));
+        assert_eq!(lucas(0), BigInt::from(2));
+        assert_eq!(lucas(10), BigInt::from(123));
+
+        let f100: BigInt = "354224848179261915075".parse().unwrap();
+        assert_eq!(fibonacci(100), f100);
+
+        // Identities: L(n) = F(n-1) + F(n+1) and F(2n) = F(n)·L(n)
+        for n in [1u64, 7, 64, 300] {
+            let (f, next) = fibonacci_pair(n);
+            assert_eq!(lucas(n), fibonacci(n - 1) + &next);
+            assert_eq!(fibonacci(2 * n), &f * lucas(n));
+        }
+    }
+
+    #[test]
+    fn test_exact_sequence_matches_phi_sequence() {
+        let exact = exact_phi_sequence(60);
+        let approximate = phi_sequence(60);
+
+        for (k, (x, y)) in exact.iter().zip(&approximate).enumerate() {
+            assert_eq!(x, &PhiNumber::phi().pow(k as i64).unwrap());
+            assert!(((x.to_f64() - y) / y).abs() < 1e-12);
+        }
+    }
+}
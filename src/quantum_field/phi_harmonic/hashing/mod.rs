@@ -0,0 +1,96 @@
+//! Fibonacci Hashing
+//!
+//! This module provides a fast hasher built on multiplicative Fibonacci
+//! hashing: each word is folded into the state and multiplied by 2^64/φ,
+//! whose bit pattern spreads consecutive keys evenly (the three-distance
+//! theorem). It suits small trusted keys such as `Dimension` or integer ids
+//! on hot lookup paths.
+//!
+//! The hasher is not keyed and offers no protection against hash flooding.
+//! Maps whose keys come from untrusted input should keep the standard
+//! library's SipHash-based `RandomState`.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// 2^64/φ rounded to an odd integer, the Fibonacci hashing multiplier
+pub const FIBONACCI_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Map `key` to a table index with `bits` bits by Fibonacci hashing
+///
+/// Takes the top bits of key·2^64/φ, which are the best-mixed ones.
+pub fn fibonacci_hash(key: u64, bits: u32) -> u64 {
+    if bits == 0 {
+        return 0;
+    }
+    key.wrapping_mul(FIBONACCI_MULTIPLIER) >> (64 - bits.min(64))
+}
+
+/// Hasher using multiplicative Fibonacci hashing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FibonacciHasher {
+    /// Running hash state
+    hash: u64,
+}
+
+impl FibonacciHasher {
+    /// Fold one word into the state
+    fn mix(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(FIBONACCI_MULTIPLIER);
+    }
+}
+
+impl Hasher for FibonacciHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(chunk);
+            self.mix(u64::from_le_bytes(word));
+        }
+
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            let mut word = [0u8; 8];
+            word[..rest.len()].copy_from_slice(rest);
+            self.mix(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.mix(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.mix(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.mix(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.mix(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.mix(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        // Tables index by the low bits, so fold the well-mixed high half down
+        self.hash ^ (self.hash >> 32)
+    }
+}
+
+/// `BuildHasher` producing [`FibonacciHasher`]s
+pub type FibonacciBuildHasher = BuildHasherDefault<FibonacciHasher>;
+
+/// `HashMap` using Fibonacci hashing; create with `PhiHashMap::default()`
+pub type PhiHashMap<K, V> = HashMap<K, V, FibonacciBuildHasher>;
+
+/// `HashSet` using Fibonacci hashing; create with `PhiHashSet::default()`
+pub type PhiHashSet<T> = HashSet<T, FibonacciBuildHasher>;
@@ -0,0 +1,66 @@
+//! Unit tests for the hashing module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{Dimension, PHI};
+    use crate::quantum_field::phi_harmonic::hashing::{
+        fibonacci_hash, FibonacciBuildHasher, FibonacciHasher, PhiHashMap, PhiHashSet, FIBONACCI_MULTIPLIER,
+    };
+    use std::hash::{BuildHasher, Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        FibonacciBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_multiplier_is_two_to_the_64_over_phi() {
+        let expected = 2f64.powi(64) / PHI;
+        assert!(((FIBONACCI_MULTIPLIER as f64 - expected) / expected).abs() < 1e-15);
+        assert_eq!(FIBONACCI_MULTIPLIER % 2, 1);
+    }
+
+    #[test]
+    fn test_fibonacci_hash_spreads_sequential_keys() {
+        // 1024 consecutive keys into 1024 slots: no slot holds more than two
+        let mut load = vec![0u32; 1024];
+        for key in 0..1024u64 {
+            load[fibonacci_hash(key, 10) as usize] += 1;
+        }
+        assert!(load.iter().all(|l| *l <= 2));
+        assert!(load.iter().filter(|l| **l > 0).count() > 600);
+
+        assert_eq!(fibonacci_hash(12345, 0), 0);
+        assert!(fibonacci_hash(u64::MAX, 64) > 0);
+    }
+
+    #[test]
+    fn test_hasher_is_deterministic_and_uses_low_bits() {
+        assert_eq!(hash_of(&"coherence"), hash_of(&"coherence"));
+        assert_ne!(hash_of(&"coherence"), hash_of(&"coherenc"));
+        assert_ne!(hash_of(&[1u8, 2, 3]), hash_of(&[1u8, 2, 3, 0]));
+
+        // Sequential integers must differ in the low bits tables index by
+        let low: PhiHashSet<u64> = (0..64u64).map(|k| hash_of(&k) & 63).collect();
+        assert!(low.len() > 32);
+
+        let mut hasher = FibonacciHasher::default();
+        hasher.write_u64(7);
+        assert_eq!(hasher.finish(), hash_of(&7u64));
+    }
+
+    #[test]
+    fn test_phi_hash_map_with_dimensions() {
+        let mut map: PhiHashMap<Dimension, f64> = PhiHashMap::default();
+        map.insert(Dimension::Physical, 0.95);
+        map.insert(Dimension::Cosmic, 0.75);
+        map.insert(Dimension::Absolute, 0.5);
+
+        assert_eq!(map.get(&Dimension::Cosmic), Some(&0.75));
+        assert_eq!(map.get(&Dimension::Mental), None);
+        assert_eq!(map.len(), 3);
+
+        let strings: PhiHashSet<String> = ["a", "b", "a"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(strings.len(), 2);
+    }
+}
@@ -0,0 +1,470 @@
+//! Fibonacci Heap Priority Queue
+//!
+//! This module provides a Fibonacci heap: a min-priority queue with O(1)
+//! amortized insert, find-min, merge and decrease-key and O(log n) amortized
+//! extract-min and delete. Trees of degree k hold at least F(k+2) nodes,
+//! which bounds the degrees by log_φ n.
+//!
+//! Nodes live in arena segments addressed by index, so no `unsafe` or
+//! reference cycles are needed. Insert returns a [`HeapHandle`] carrying a
+//! generation counter; handles to removed elements, or to elements of another
+//! heap, are rejected rather than silently touching the wrong node. Merging
+//! moves arena segments between heaps, so handles stay valid after a merge.
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::phi_harmonic::hashing::PhiHashMap;
+
+/// Source of globally unique segment ids
+static NEXT_SEGMENT: AtomicU64 = AtomicU64::new(0);
+
+/// Handle to an element of a [`FibonacciHeap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeapHandle {
+    /// Segment holding the element
+    segment: u64,
+
+    /// Slot within the segment
+    slot: usize,
+
+    /// Generation of the slot when the element was inserted
+    generation: u64,
+}
+
+/// Location of a live node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NodeId {
+    /// Segment holding the node
+    segment: u64,
+
+    /// Slot within the segment
+    slot: usize,
+}
+
+/// Heap node in a circular doubly linked sibling list
+#[derive(Debug)]
+struct Node<K, V> {
+    /// Priority key
+    key: K,
+
+    /// Payload
+    value: V,
+
+    /// Parent node, if not a root
+    parent: Option<NodeId>,
+
+    /// Any one child
+    child: Option<NodeId>,
+
+    /// Previous sibling
+    left: NodeId,
+
+    /// Next sibling
+    right: NodeId,
+
+    /// Number of children
+    degree: usize,
+
+    /// Whether the node has lost a child since it became a child itself
+    marked: bool,
+}
+
+/// Arena slot; the generation advances whenever its node is removed
+#[derive(Debug)]
+struct Slot<K, V> {
+    /// Generation counter
+    generation: u64,
+
+    /// The node, if the slot is occupied
+    node: Option<Node<K, V>>,
+}
+
+/// Block of arena slots
+#[derive(Debug)]
+struct Segment<K, V> {
+    /// Slots of the segment
+    slots: Vec<Slot<K, V>>,
+
+    /// Vacant slots available for reuse
+    free: Vec<usize>,
+
+    /// Number of occupied slots
+    live: usize,
+}
+
+impl<K, V> Segment<K, V> {
+    /// Create an empty segment
+    fn new() -> Self {
+        Self { slots: Vec::new(), free: Vec::new(), live: 0 }
+    }
+}
+
+/// Min-priority queue with decrease-key, backed by a Fibonacci heap
+#[derive(Debug)]
+pub struct FibonacciHeap<K, V> {
+    /// Arena segments by id
+    segments: PhiHashMap<u64, Segment<K, V>>,
+
+    /// Segment receiving new insertions
+    home: u64,
+
+    /// Root with the smallest key
+    min: Option<NodeId>,
+
+    /// Number of elements
+    len: usize,
+}
+
+impl<K: Ord, V> Default for FibonacciHeap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FibonacciHeap<K, V> {
+    /// Create an empty heap
+    pub fn new() -> Self {
+        let home = NEXT_SEGMENT.fetch_add(1, Ordering::Relaxed);
+        let mut segments = PhiHashMap::default();
+        segments.insert(home, Segment::new());
+
+        Self { segments, home, min: None, len: 0 }
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert an element, returning a handle for later updates
+    pub fn insert(&mut self, key: K, value: V) -> HeapHandle {
+        let home = self.home;
+        let segment = self.segments.get_mut(&home).expect("home segment exists");
+        let slot = segment.free.pop().unwrap_or(segment.slots.len());
+        let id = NodeId { segment: home, slot };
+
+        let node = Node { key, value, parent: None, child: None, left: id, right: id, degree: 0, marked: false };
+        if slot == segment.slots.len() {
+            segment.slots.push(Slot { generation: 0, node: Some(node) });
+        } else {
+            segment.slots[slot].node = Some(node);
+        }
+        segment.live += 1;
+        let generation = segment.slots[slot].generation;
+
+        self.add_root(id);
+        self.len += 1;
+
+        HeapHandle { segment: home, slot, generation }
+    }
+
+    /// Get the element with the smallest key
+    pub fn find_min(&self) -> Option<(&K, &V)> {
+        self.min.map(|id| {
+            let node = self.node(id);
+            (&node.key, &node.value)
+        })
+    }
+
+    /// Get the element behind a handle, if it is still in this heap
+    pub fn get(&self, handle: HeapHandle) -> Option<(&K, &V)> {
+        self.resolve(handle).ok().map(|id| {
+            let node = self.node(id);
+            (&node.key, &node.value)
+        })
+    }
+
+    /// Check whether a handle refers to an element still in this heap
+    pub fn contains(&self, handle: HeapHandle) -> bool {
+        self.resolve(handle).is_ok()
+    }
+
+    /// Remove and return the element with the smallest key
+    pub fn extract_min(&mut self) -> Option<(K, V)> {
+        let z = self.min?;
+
+        // Promote the children of the minimum to roots
+        if let Some(child) = self.node(z).child {
+            for c in self.siblings(child) {
+                let node = self.node_mut(c);
+                node.parent = None;
+                node.marked = false;
+                node.left = c;
+                node.right = c;
+                self.splice(z, c);
+            }
+            self.node_mut(z).child = None;
+        }
+
+        let next = self.node(z).right;
+        self.unlink(z);
+        if next == z {
+            self.min = None;
+        } else {
+            self.min = Some(next);
+            self.consolidate();
+        }
+
+        self.len -= 1;
+        let node = self.release(z);
+        Some((node.key, node.value))
+    }
+
+    /// Lower the key of an element
+    ///
+    /// Fails if the handle is stale or foreign, or if the new key is larger.
+    pub fn decrease_key(&mut self, handle: HeapHandle, key: K) -> QuantumResult<()> {
+        let id = self.resolve(handle)?;
+        if key > self.node(id).key {
+            return Err(QuantumError::OperationError {
+                message: "decrease_key cannot increase a key".to_string(),
+            });
+        }
+        self.node_mut(id).key = key;
+
+        if let Some(parent) = self.node(id).parent {
+            if self.less(id, parent) {
+                self.cut(id, parent);
+                self.cascading_cut(parent);
+            }
+        }
+        if self.min.is_none_or(|m| self.less(id, m)) {
+            self.min = Some(id);
+        }
+        Ok(())
+    }
+
+    /// Remove an element by handle, returning it
+    pub fn delete(&mut self, handle: HeapHandle) -> QuantumResult<(K, V)> {
+        let id = self.resolve(handle)?;
+
+        // Move the node to the root list and treat it as the minimum
+        if let Some(parent) = self.node(id).parent {
+            self.cut(id, parent);
+            self.cascading_cut(parent);
+        }
+        self.min = Some(id);
+
+        Ok(self.extract_min().expect("heap holds the deleted element"))
+    }
+
+    /// Move all elements of `other` into this heap
+    ///
+    /// Handles from either heap remain valid. Only the smaller segment table
+    /// is moved, so merging is O(1) amortized in the number of elements.
+    pub fn merge(&mut self, mut other: Self) {
+        if other.segments.len() > self.segments.len() {
+            std::mem::swap(self, &mut other);
+        }
+
+        for (id, segment) in other.segments.drain() {
+            if segment.live > 0 {
+                self.segments.insert(id, segment);
+            }
+        }
+
+        match (self.min, other.min) {
+            (Some(a), Some(b)) => {
+                // Concatenate the two circular root lists
+                let a_right = self.node(a).right;
+                let b_left = self.node(b).left;
+                self.node_mut(a).right = b;
+                self.node_mut(b).left = a;
+                self.node_mut(b_left).right = a_right;
+                self.node_mut(a_right).left = b_left;
+                if self.less(b, a) {
+                    self.min = Some(b);
+                }
+            }
+            (None, Some(b)) => self.min = Some(b),
+            _ => {}
+        }
+        self.len += other.len;
+    }
+
+    /// Remove all elements in ascending key order
+    pub fn into_sorted_vec(mut self) -> Vec<(K, V)> {
+        let mut sorted = Vec::with_capacity(self.len);
+        while let Some(entry) = self.extract_min() {
+            sorted.push(entry);
+        }
+        sorted
+    }
+
+    /// Link equal-degree roots until all root degrees differ, then find the minimum
+    fn consolidate(&mut self) {
+        let start = self.min.expect("consolidate needs a root");
+        let mut by_degree: Vec<Option<NodeId>> = Vec::new();
+
+        for root in self.siblings(start) {
+            let mut x = root;
+            let mut degree = self.node(x).degree;
+            loop {
+                if degree >= by_degree.len() {
+                    by_degree.resize(degree + 1, None);
+                }
+                match by_degree[degree].take() {
+                    Some(mut y) => {
+                        if self.less(y, x) {
+                            std::mem::swap(&mut x, &mut y);
+                        }
+                        self.link(y, x);
+                        degree += 1;
+                    }
+                    None => {
+                        by_degree[degree] = Some(x);
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.min = None;
+        for x in by_degree.into_iter().flatten() {
+            if self.min.is_none_or(|m| self.less(x, m)) {
+                self.min = Some(x);
+            }
+        }
+    }
+
+    /// Make root `y` a child of root `x`
+    fn link(&mut self, y: NodeId, x: NodeId) {
+        self.unlink(y);
+        match self.node(x).child {
+            Some(child) => self.splice(child, y),
+            None => self.node_mut(x).child = Some(y),
+        }
+
+        let node = self.node_mut(y);
+        node.parent = Some(x);
+        node.marked = false;
+        self.node_mut(x).degree += 1;
+    }
+
+    /// Cut `x` from its parent `y` and make it a root
+    fn cut(&mut self, x: NodeId, y: NodeId) {
+        if self.node(y).child == Some(x) {
+            let next = self.node(x).right;
+            self.node_mut(y).child = if next == x { None } else { Some(next) };
+        }
+        self.unlink(x);
+        self.node_mut(y).degree -= 1;
+
+        let node = self.node_mut(x);
+        node.parent = None;
+        node.marked = false;
+        self.add_root(x);
+    }
+
+    /// Cut marked ancestors, marking the first unmarked one
+    fn cascading_cut(&mut self, mut y: NodeId) {
+        while let Some(parent) = self.node(y).parent {
+            if !self.node(y).marked {
+                self.node_mut(y).marked = true;
+                return;
+            }
+            self.cut(y, parent);
+            y = parent;
+        }
+    }
+
+    /// Add a detached node to the root list, updating the minimum
+    fn add_root(&mut self, id: NodeId) {
+        match self.min {
+            Some(min) => {
+                self.splice(min, id);
+                if self.less(id, min) {
+                    self.min = Some(id);
+                }
+            }
+            None => self.min = Some(id),
+        }
+    }
+
+    /// Insert detached node `id` to the right of `anchor`
+    fn splice(&mut self, anchor: NodeId, id: NodeId) {
+        let right = self.node(anchor).right;
+        self.node_mut(id).left = anchor;
+        self.node_mut(id).right = right;
+        self.node_mut(anchor).right = id;
+        self.node_mut(right).left = id;
+    }
+
+    /// Remove `id` from its sibling list, leaving it detached
+    fn unlink(&mut self, id: NodeId) {
+        let (left, right) = (self.node(id).left, self.node(id).right);
+        self.node_mut(left).right = right;
+        self.node_mut(right).left = left;
+
+        let node = self.node_mut(id);
+        node.left = id;
+        node.right = id;
+    }
+
+    /// All nodes in the sibling list starting at `start`
+    fn siblings(&self, start: NodeId) -> Vec<NodeId> {
+        let mut ids = vec![start];
+        let mut current = self.node(start).right;
+        while current != start {
+            ids.push(current);
+            current = self.node(current).right;
+        }
+        ids
+    }
+
+    /// Compare the keys of two nodes
+    fn less(&self, a: NodeId, b: NodeId) -> bool {
+        self.node(a).key < self.node(b).key
+    }
+
+    /// Look up a live handle
+    fn resolve(&self, handle: HeapHandle) -> QuantumResult<NodeId> {
+        self.segments
+            .get(&handle.segment)
+            .and_then(|segment| segment.slots.get(handle.slot))
+            .filter(|slot| slot.generation == handle.generation && slot.node.is_some())
+            .map(|_| NodeId { segment: handle.segment, slot: handle.slot })
+            .ok_or_else(|| QuantumError::OperationError {
+                message: "Heap handle does not refer to an element of this heap".to_string(),
+            })
+    }
+
+    /// Take a node out of its slot, retiring the slot's generation
+    fn release(&mut self, id: NodeId) -> Node<K, V> {
+        let home = self.home;
+        let segment = self.segments.get_mut(&id.segment).expect("node segment exists");
+        let slot = &mut segment.slots[id.slot];
+        let node = slot.node.take().expect("released node is live");
+        slot.generation += 1;
+        segment.live -= 1;
+
+        if id.segment == home {
+            segment.free.push(id.slot);
+        } else if segment.live == 0 {
+            self.segments.remove(&id.segment);
+        }
+        node
+    }
+
+    /// Get a live node
+    fn node(&self, id: NodeId) -> &Node<K, V> {
+        self.segments[&id.segment].slots[id.slot].node.as_ref().expect("linked node is live")
+    }
+
+    /// Get a live node mutably
+    fn node_mut(&mut self, id: NodeId) -> &mut Node<K, V> {
+        self.segments
+            .get_mut(&id.segment)
+            .and_then(|segment| segment.slots[id.slot].node.as_mut())
+            .expect("linked node is live")
+    }
+}
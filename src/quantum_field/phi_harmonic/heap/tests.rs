@@ -0,0 +1,125 @@
+//! Unit tests for the heap module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::heap::FibonacciHeap;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_extracts_in_key_order() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut heap = FibonacciHeap::new();
+        let mut keys: Vec<u32> = (0..500).map(|_| rng.gen_range(0..1000)).collect();
+        for (i, key) in keys.iter().enumerate() {
+            heap.insert(*key, i);
+        }
+
+        assert_eq!(heap.len(), 500);
+        assert_eq!(heap.find_min().map(|(k, _)| *k), keys.iter().min().copied());
+
+        keys.sort_unstable();
+        let extracted: Vec<u32> = heap.into_sorted_vec().into_iter().map(|(k, _)| k).collect();
+        assert_eq!(extracted, keys);
+    }
+
+    #[test]
+    fn test_decrease_key_and_delete() {
+        let mut heap = FibonacciHeap::new();
+        let handles: Vec<_> = (0..20).map(|i| heap.insert(100 + i, format!("op{}", i))).collect();
+
+        // Consolidate into trees so decreases cut children from parents
+        assert_eq!(heap.extract_min(), Some((100, "op0".to_string())));
+
+        heap.decrease_key(handles[15], 5).unwrap();
+        heap.decrease_key(handles[9], 7).unwrap();
+        assert_eq!(heap.find_min(), Some((&5, &"op15".to_string())));
+        assert!(heap.decrease_key(handles[3], 500).is_err());
+
+        assert_eq!(heap.delete(handles[9]).unwrap(), (7, "op9".to_string()));
+        assert_eq!(heap.delete(handles[12]).unwrap(), (112, "op12".to_string()));
+        assert_eq!(heap.len(), 17);
+
+        let keys: Vec<u32> = heap.into_sorted_vec().into_iter().map(|(k, _)| k).collect();
+        let mut expected = vec![5];
+        expected.extend((101..120).filter(|k| ![109, 112, 115].contains(k)));
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn test_stale_and_foreign_handles_are_rejected() {
+        let mut heap = FibonacciHeap::new();
+        let first = heap.insert(1, ());
+        let second = heap.insert(2, ());
+
+        heap.extract_min();
+        assert!(!heap.contains(first));
+        assert!(heap.decrease_key(first, 0).is_err());
+        assert!(heap.delete(first).is_err());
+
+        // The freed slot is reused, but the old handle stays stale
+        let third = heap.insert(0, ());
+        assert!(heap.get(first).is_none());
+        assert_eq!(heap.get(third), Some((&0, &())));
+
+        let other: FibonacciHeap<i32, ()> = FibonacciHeap::new();
+        assert!(!other.contains(second));
+    }
+
+    #[test]
+    fn test_merge_keeps_handles_valid() {
+        let mut a = FibonacciHeap::new();
+        let mut b = FibonacciHeap::new();
+        let ha: Vec<_> = (0..10).map(|i| a.insert(2 * i + 10, 'a')).collect();
+        let hb: Vec<_> = (0..10).map(|i| b.insert(2 * i + 11, 'b')).collect();
+        b.extract_min();
+
+        a.merge(b);
+        assert_eq!(a.len(), 19);
+        assert_eq!(a.find_min(), Some((&10, &'a')));
+
+        a.decrease_key(hb[7], 1).unwrap();
+        assert_eq!(a.delete(ha[4]).unwrap(), (18, 'a'));
+        assert_eq!(a.extract_min(), Some((1, 'b')));
+        assert!(!a.contains(hb[0]));
+
+        let mut c = FibonacciHeap::new();
+        c.insert(0, 'c');
+        c.merge(a);
+        assert_eq!(c.len(), 18);
+        assert_eq!(c.get(hb[9]), Some((&29, &'b')));
+        assert_eq!(c.extract_min(), Some((0, 'c')));
+    }
+
+    #[test]
+    fn test_random_operations_match_reference() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut heap = FibonacciHeap::new();
+        let mut live: Vec<(crate::quantum_field::phi_harmonic::heap::HeapHandle, i64)> = Vec::new();
+
+        for step in 0..3000 {
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let key = rng.gen_range(-1000..1000);
+                    live.push((heap.insert(key, step), key));
+                }
+                2 if !live.is_empty() => {
+                    let i = rng.gen_range(0..live.len());
+                    let key = live[i].1 - rng.gen_range(0..100);
+                    heap.decrease_key(live[i].0, key).unwrap();
+                    live[i].1 = key;
+                }
+                _ => {
+                    let expected = live.iter().map(|(_, k)| *k).min();
+                    assert_eq!(heap.find_min().map(|(k, _)| *k), expected);
+                    if let Some((key, _)) = heap.extract_min() {
+                        let i = live.iter().position(|(h, k)| *k == key && !heap.contains(*h)).unwrap();
+                        live.swap_remove(i);
+                    }
+                }
+            }
+            assert_eq!(heap.len(), live.len());
+        }
+    }
+}
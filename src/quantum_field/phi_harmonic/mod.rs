@@ -2,13 +2,53 @@
 //!
 //! This module provides algorithms and data structures based on phi relationships,
 //! including phi-scaled calculations, phi-spiral patterns, and phi-harmonic optimization.
+//!
+//! ## Submodules
+//!
+//! - **Exact**: Exact arithmetic in Q(√5) and big-integer Fibonacci/Lucas numbers
+//! - **Coding**: Zeckendorf representation and Fibonacci universal codes
+//! - **Compression**: Reversible phi compression with bounded-error and lossless modes
+//! - **Heap**: Fibonacci heap priority queue with safe decrease-key handles
+//! - **Hashing**: Fibonacci hashing `BuildHasher` and `PhiHashMap`
+//! - **Quasirandom**: R1, R2 and Rd low-discrepancy sequences and QMC integration
+//! - **Search**: Golden-section and Fibonacci search for scalar minima
+//! - **Sphere**: Fibonacci sphere lattices, 3D phyllotaxis and CSV/PLY export
+//! - **Simplex**: Bounded Nelder-Mead minimization with phi-scaled steps
+//! - **Spectrum**: Resonance spectrum sweeps with peak widths and dominant harmonics
+//! - **Wavelet**: Multiresolution lifting transform with φ-ratio scales
+//!
+//! ## Precision
+//!
+//! The free functions and [`PhiHarmonicValues`] are generic over [`PhiFloat`],
+//! which is implemented for f32 and f64. Functions whose float type cannot be
+//! inferred from their arguments take f64 and have a generic `_of` variant.
+//! The submodules work
+//! in f64 only, and so do the `denoise`, `encode` and `from_compressed`
+//! methods of `PhiHarmonicValues` that build on them.
 
 #[cfg(test)]
 mod tests;
 
-use crate::constants::{PHI, LAMBDA, PHI_PHI};
+pub mod coding;
+pub mod compression;
+pub mod exact;
+pub mod hashing;
+pub mod heap;
+pub mod quasirandom;
+pub mod search;
+pub mod simplex;
+pub mod spectrum;
+pub mod sphere;
+pub mod wavelet;
+
+use num::traits::{Float, ToPrimitive};
+
+use crate::constants::{Frequency, PHI, LAMBDA, PHI_PHI};
 use crate::error::QuantumResult;
 
+use self::compression::{CompressionMode, PhiCompressed};
+use self::wavelet::ThresholdMode;
+
 /// Phi-harmonic algorithm types
 #[derive(Debug, Clone, Copy)]
 pub enum Algorithm {
@@ -37,26 +77,64 @@ pub enum Algorithm {
     PhiResonance,
 }
 
+/// Float types with phi-harmonic constants
+///
+/// Implemented for `f32` and `f64`. Like `num::traits::FloatConst`, the
+/// constants are provided per type, so a custom float type implements this
+/// trait with its own φ, λ and φ^φ to use the generic functions.
+pub trait PhiFloat: Float {
+    /// Golden ratio (φ)
+    const PHI: Self;
+    
+    /// Divine complement (λ)
+    const LAMBDA: Self;
+    
+    /// Hyperdimensional constant (φ^φ)
+    const PHI_PHI: Self;
+}
+
+impl PhiFloat for f64 {
+    const PHI: f64 = PHI;
+    const LAMBDA: f64 = LAMBDA;
+    const PHI_PHI: f64 = PHI_PHI;
+}
+
+impl PhiFloat for f32 {
+    const PHI: f32 = PHI as f32;
+    const LAMBDA: f32 = LAMBDA as f32;
+    const PHI_PHI: f32 = PHI_PHI as f32;
+}
+
+/// Convert an f64 literal or a count to any float type
+fn cast<T: Float, N: ToPrimitive>(value: N) -> T {
+    T::from(value).expect("numeric literals convert to every float type")
+}
+
 /// Phi-scaling function
-pub fn phi_scale(value: f64, multiplier: f64) -> f64 {
-    value * (PHI * multiplier)
+pub fn phi_scale<T: PhiFloat>(value: T, multiplier: T) -> T {
+    value * (T::PHI * multiplier)
 }
 
 /// Lambda-scaling function
-pub fn lambda_scale(value: f64, multiplier: f64) -> f64 {
-    value * (LAMBDA * multiplier)
+pub fn lambda_scale<T: PhiFloat>(value: T, multiplier: T) -> T {
+    value * (T::LAMBDA * multiplier)
 }
 
 /// Phi-sequence generator
 pub fn phi_sequence(length: usize) -> Vec<f64> {
+    phi_sequence_of(length)
+}
+
+/// Phi-sequence generator for any float type, e.g. `phi_sequence_of::<f32>(10)`
+pub fn phi_sequence_of<T: PhiFloat>(length: usize) -> Vec<T> {
     let mut sequence = Vec::with_capacity(length);
     
     if length > 0 {
-        sequence.push(1.0);
+        sequence.push(T::one());
     }
     
     if length > 1 {
-        sequence.push(PHI);
+        sequence.push(T::PHI);
     }
     
     for i in 2..length {
@@ -68,35 +146,35 @@ pub fn phi_sequence(length: usize) -> Vec<f64> {
 }
 
 /// Phi-ratio calculator
-pub fn phi_ratio(a: f64, b: f64) -> f64 {
-    if b == 0.0 {
-        return 0.0;
+pub fn phi_ratio<T: PhiFloat>(a: T, b: T) -> T {
+    if b == T::zero() {
+        return T::zero();
     }
     
     let ratio = a / b;
-    let phi_diff = (ratio - PHI).abs();
+    let phi_diff = (ratio - T::PHI).abs();
     
-    // Calculate how close the ratio is to � (1.0 = exact match, 0.0 = far off)
-    1.0 / (1.0 + phi_diff * 5.0)
+    // Calculate how close the ratio is to φ (1.0 = exact match, 0.0 = far off)
+    T::one() / (T::one() + phi_diff * cast(5.0))
 }
 
 /// Phi-optimized value
-pub fn phi_optimize(value: f64, target: f64, iterations: usize) -> f64 {
+pub fn phi_optimize<T: PhiFloat>(value: T, target: T, iterations: usize) -> T {
     let mut current = value;
     
     for _ in 0..iterations {
         let diff = target - current;
-        let adjustment = diff * LAMBDA;
-        current += adjustment;
+        let adjustment = diff * T::LAMBDA;
+        current = current + adjustment;
     }
     
     current
 }
 
 /// Phi-spiral point generator
-pub fn phi_spiral_point(index: usize, scale: f64) -> (f64, f64) {
-    let angle = index as f64 * PHI * std::f64::consts::PI * 2.0;
-    let radius = scale * (index as f64).sqrt();
+pub fn phi_spiral_point<T: PhiFloat>(index: usize, scale: T) -> (T, T) {
+    let angle = cast::<T, _>(index) * T::PHI * cast(std::f64::consts::PI) * cast(2.0);
+    let radius = scale * cast::<T, _>(index).sqrt();
     
     let x = radius * angle.cos();
     let y = radius * angle.sin();
@@ -105,42 +183,53 @@ pub fn phi_spiral_point(index: usize, scale: f64) -> (f64, f64) {
 }
 
 /// Phi-spiral points generator
-pub fn phi_spiral(count: usize, scale: f64) -> Vec<(f64, f64)> {
+pub fn phi_spiral<T: PhiFloat>(count: usize, scale: T) -> Vec<(T, T)> {
     (0..count).map(|i| phi_spiral_point(i, scale)).collect()
 }
 
 /// Phi-harmonic resonance calculator
-pub fn phi_resonance(value: f64, base_frequency: f64) -> f64 {
-    // Calculate resonant frequency
-    let resonant_frequency = base_frequency * PHI.powf(value.abs().ln());
-    
-    // Calculate resonance strength
-    let harmonics = [0.5, 1.0, PHI, PHI_PHI];
-    
-    let max_resonance = harmonics.iter()
-        .map(|h| {
-            let harmonic_freq = base_frequency * h;
-            let distance = (resonant_frequency - harmonic_freq).abs();
-            let normalized_distance = distance / base_frequency;
-            (-normalized_distance * 10.0).exp()
-        })
-        .fold(0.0, f64::max);
-    
-    max_resonance
+pub fn phi_resonance<T: PhiFloat>(value: T, base_frequency: T) -> T {
+    let resonant_frequency = resonant_frequency_of(value, base_frequency);
+    
+    standard_harmonics::<T>().iter()
+        .map(|h| harmonic_strength(resonant_frequency, base_frequency, *h))
+        .fold(T::zero(), T::max)
+}
+
+/// Harmonic ratios scored by `phi_resonance`: ½, 1, φ and φ^φ
+fn standard_harmonics<T: PhiFloat>() -> [T; 4] {
+    [cast(0.5), T::one(), T::PHI, T::PHI_PHI]
+}
+
+/// Frequency at which a value resonates when tuned at a reference frequency
+fn resonant_frequency_of<T: PhiFloat>(value: T, reference_frequency: T) -> T {
+    reference_frequency * T::PHI.powf(value.abs().ln())
+}
+
+/// Strength of one harmonic of a base frequency at a resonant frequency
+fn harmonic_strength<T: PhiFloat>(resonant_frequency: T, base_frequency: T, harmonic: T) -> T {
+    let distance = (resonant_frequency - base_frequency * harmonic).abs();
+    (-distance / base_frequency * cast(10.0)).exp()
 }
 
 /// Phi-gridding function
 pub fn phi_grid(width: usize, height: usize) -> Vec<Vec<f64>> {
+    phi_grid_of(width, height)
+}
+
+/// Phi-gridding function for any float type, e.g. `phi_grid_of::<f32>(4, 3)`
+pub fn phi_grid_of<T: PhiFloat>(width: usize, height: usize) -> Vec<Vec<T>> {
     let mut grid = Vec::with_capacity(height);
+    let half: T = cast(0.5);
     
     for y in 0..height {
         let mut row = Vec::with_capacity(width);
         
         for x in 0..width {
-            let phi_x = phi_scale(x as f64, 0.1);
-            let phi_y = phi_scale(y as f64, 0.1);
+            let phi_x = phi_scale(cast::<T, _>(x), cast(0.1));
+            let phi_y = phi_scale(cast::<T, _>(y), cast(0.1));
             
-            let value = (phi_x * phi_y).sin() * 0.5 + 0.5;
+            let value = (phi_x * phi_y).sin() * half + half;
             row.push(value);
         }
         
@@ -151,19 +240,19 @@ pub fn phi_grid(width: usize, height: usize) -> Vec<Vec<f64>> {
 }
 
 /// Phi-compression algorithm
-pub fn phi_compress(values: &[f64], factor: f64) -> Vec<f64> {
-    let compressed_len = (values.len() as f64 * LAMBDA * factor) as usize;
+pub fn phi_compress<T: PhiFloat>(values: &[T], factor: T) -> Vec<T> {
+    let compressed_len = (cast::<T, _>(values.len()) * T::LAMBDA * factor).to_usize().unwrap_or(0);
     let mut compressed = Vec::with_capacity(compressed_len);
     
     if values.is_empty() || compressed_len == 0 {
         return compressed;
     }
     
-    let step = values.len() as f64 / compressed_len as f64;
+    let step = cast::<T, _>(values.len()) / cast(compressed_len);
     
     for i in 0..compressed_len {
-        let index = (i as f64 * step) as usize;
-        let next_index = ((i as f64 + 1.0) * step) as usize;
+        let index = (cast::<T, _>(i) * step).to_usize().unwrap_or(0);
+        let next_index = ((cast::<T, _>(i) + T::one()) * step).to_usize().unwrap_or(0);
         
         if index >= values.len() {
             break;
@@ -175,13 +264,13 @@ pub fn phi_compress(values: &[f64], factor: f64) -> Vec<f64> {
             compressed.push(values[index]);
         } else {
             // Calculate weighted average
-            let mut sum = 0.0;
-            let mut weight_sum = 0.0;
+            let mut sum = T::zero();
+            let mut weight_sum = T::zero();
             
-            for j in index..=next_index {
-                let weight = PHI.powf(-((j - index) as f64).abs());
-                sum += values[j] * weight;
-                weight_sum += weight;
+            for (offset, value) in values[index..=next_index].iter().enumerate() {
+                let weight = T::PHI.powf(-cast::<T, _>(offset));
+                sum = sum + *value * weight;
+                weight_sum = weight_sum + weight;
             }
             
             compressed.push(sum / weight_sum);
@@ -192,20 +281,21 @@ pub fn phi_compress(values: &[f64], factor: f64) -> Vec<f64> {
 }
 
 /// Phi-harmonic optimization function
-pub fn phi_harmonic_optimize<F>(mut value: f64, target: f64, cost_function: F, iterations: usize) -> QuantumResult<f64>
+pub fn phi_harmonic_optimize<T, F>(mut value: T, target: T, cost_function: F, iterations: usize) -> QuantumResult<T>
 where
-    F: Fn(f64) -> f64,
+    T: PhiFloat,
+    F: Fn(T) -> T,
 {
     let mut best_value = value;
     let mut best_cost = cost_function(value);
     
     for i in 0..iterations {
         // Calculate phi-scaled adjustment
-        let progress = i as f64 / iterations as f64;
-        let scale = (1.0 - progress).powf(0.5); // Square root decay
+        let progress = cast::<T, _>(i) / cast(iterations);
+        let scale = (T::one() - progress).powf(cast(0.5)); // Square root decay
         
         let diff = target - value;
-        let adjustment = diff * LAMBDA * scale;
+        let adjustment = diff * T::LAMBDA * scale;
         
         // Try new value
         let new_value = value + adjustment;
@@ -218,7 +308,7 @@ where
         }
         
         // Phi-harmonic oscillation to escape local minima
-        let oscillation = (i as f64 * PHI).sin() * scale * LAMBDA;
+        let oscillation = (cast::<T, _>(i) * T::PHI).sin() * scale * T::LAMBDA;
         value = best_value + oscillation;
     }
     
@@ -226,77 +316,296 @@ where
 }
 
 /// Container for phi-harmonic values
+///
+/// The mean phi resonance is kept as a running sum, so pushing, popping,
+/// removing and replacing values update it in O(1). An empty container has a
+/// resonance of zero. With a window set, pushing beyond the capacity evicts
+/// the oldest value, so the container can follow a live data stream.
 #[derive(Debug, Clone)]
-pub struct PhiHarmonicValues {
-    /// The values
-    values: Vec<f64>,
+pub struct PhiHarmonicValues<T = f64> {
+    /// Value storage; the live values are `values[start..]`
+    values: Vec<T>,
+    
+    /// Index of the oldest live value, advanced by window evictions
+    start: usize,
     
     /// Base frequency
-    base_frequency: f64,
+    base_frequency: T,
+    
+    /// Sum of the phi resonances of the live values
+    resonance_sum: T,
     
-    /// Phi resonance
-    phi_resonance: f64,
+    /// Maximum number of values kept, if windowed
+    window: Option<usize>,
 }
 
-impl PhiHarmonicValues {
+impl<T: PhiFloat> PhiHarmonicValues<T> {
     /// Create a new container from raw values
-    pub fn new(values: Vec<f64>, base_frequency: f64) -> Self {
-        // Calculate phi resonance
-        let resonance = values.iter()
-            .map(|v| phi_resonance(*v, base_frequency))
-            .sum::<f64>() / values.len() as f64;
-        
-        Self {
-            values,
+    pub fn new(values: Vec<T>, base_frequency: T) -> Self {
+        let mut harmonic = Self {
+            values: Vec::new(),
+            start: 0,
             base_frequency,
-            phi_resonance: resonance,
-        }
+            resonance_sum: T::zero(),
+            window: None,
+        };
+        harmonic.set_values(values);
+        harmonic
+    }
+    
+    /// Keep at most `capacity` values, dropping the oldest ones beyond it
+    ///
+    /// A capacity of zero keeps no values at all.
+    pub fn with_window(mut self, capacity: usize) -> Self {
+        self.window = Some(capacity);
+        let values = self.values.split_off(self.start);
+        self.set_values(values);
+        self
+    }
+    
+    /// Use a different base frequency, recomputing the resonance
+    pub fn with_base_frequency(mut self, base_frequency: T) -> Self {
+        self.base_frequency = base_frequency;
+        self.resonance_sum = self.total_resonance();
+        self
     }
     
     /// Apply phi optimization to all values
-    pub fn optimize(&mut self, target: f64, iterations: usize) {
-        self.values = self.values.iter()
+    pub fn optimize(&mut self, target: T, iterations: usize) {
+        let values = self.values().iter()
             .map(|v| phi_optimize(*v, target, iterations))
             .collect();
         
-        // Recalculate resonance
-        self.phi_resonance = self.values.iter()
-            .map(|v| phi_resonance(*v, self.base_frequency))
-            .sum::<f64>() / self.values.len() as f64;
+        self.set_values(values);
     }
     
     /// Apply phi compression
-    pub fn compress(&mut self, factor: f64) {
-        self.values = phi_compress(&self.values, factor);
+    pub fn compress(&mut self, factor: T) {
+        let values = phi_compress(self.values(), factor);
         
-        // Recalculate resonance
-        self.phi_resonance = self.values.iter()
-            .map(|v| phi_resonance(*v, self.base_frequency))
-            .sum::<f64>() / self.values.len() as f64;
+        self.set_values(values);
+    }
+    
+    /// Get the values, oldest first
+    pub fn values(&self) -> &[T] {
+        &self.values[self.start..]
+    }
+    
+    /// Get the value at an index
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values().get(index)
+    }
+    
+    /// Iterate over the values, oldest first
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.values().iter()
     }
     
-    /// Get the values
-    pub fn values(&self) -> &[f64] {
-        &self.values
+    /// Get the number of values
+    pub fn len(&self) -> usize {
+        self.values.len() - self.start
+    }
+    
+    /// Check whether the container holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    /// Get the window capacity, if windowed
+    pub fn window(&self) -> Option<usize> {
+        self.window
     }
     
     /// Get the base frequency
-    pub fn base_frequency(&self) -> f64 {
+    pub fn base_frequency(&self) -> T {
         self.base_frequency
     }
     
-    /// Get the phi resonance
-    pub fn phi_resonance(&self) -> f64 {
-        self.phi_resonance
+    /// Get the phi resonance (mean over the values, zero when empty)
+    pub fn phi_resonance(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        self.resonance_sum / cast(self.len())
     }
     
     /// Add a value
-    pub fn add_value(&mut self, value: f64) {
+    pub fn add_value(&mut self, value: T) {
+        self.push(value);
+    }
+    
+    /// Add a value, returning the value evicted by the window if any
+    pub fn push(&mut self, value: T) -> Option<T> {
+        match self.window {
+            Some(0) => Some(value),
+            Some(capacity) if self.len() >= capacity => {
+                let evicted = self.pop_front();
+                self.push_unbounded(value);
+                evicted
+            }
+            _ => {
+                self.push_unbounded(value);
+                None
+            }
+        }
+    }
+    
+    /// Remove and return the newest value
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.values.pop()?;
+        self.forget(value);
+        Some(value)
+    }
+    
+    /// Remove and return the oldest value
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = *self.values.get(self.start)?;
+        self.start += 1;
+        
+        // Reclaim the evicted prefix once it outgrows the live values, and
+        // resum the survivors so rounding drift cannot build up over a stream
+        if self.start * 2 >= self.values.len() {
+            self.values.drain(..self.start);
+            self.start = 0;
+            self.resonance_sum = self.total_resonance();
+        } else {
+            self.forget(value);
+        }
+        
+        Some(value)
+    }
+    
+    /// Remove and return the value at an index
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        let value = self.values.remove(self.start + index);
+        self.forget(value);
+        Some(value)
+    }
+    
+    /// Replace the value at an index, returning the previous value
+    pub fn replace(&mut self, index: usize, value: T) -> Option<T> {
+        let slot = self.values.get_mut(self.start + index)?;
+        let previous = std::mem::replace(slot, value);
+        self.resonance_sum = self.resonance_sum
+            + phi_resonance(value, self.base_frequency)
+            - phi_resonance(previous, self.base_frequency);
+        Some(previous)
+    }
+    
+    /// Remove all values
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.start = 0;
+        self.resonance_sum = T::zero();
+    }
+    
+    /// Append a value without consulting the window
+    fn push_unbounded(&mut self, value: T) {
         self.values.push(value);
+        self.resonance_sum = self.resonance_sum + phi_resonance(value, self.base_frequency);
+    }
+    
+    /// Take a removed value out of the resonance sum
+    fn forget(&mut self, value: T) {
+        if self.is_empty() {
+            // Reset exactly so rounding drift cannot outlive the values
+            self.clear();
+        } else {
+            self.resonance_sum = self.resonance_sum - phi_resonance(value, self.base_frequency);
+        }
+    }
+    
+    /// Replace all values, keeping the newest ones that fit the window
+    fn set_values(&mut self, mut values: Vec<T>) {
+        if let Some(capacity) = self.window {
+            let excess = values.len().saturating_sub(capacity);
+            values.drain(..excess);
+        }
+        self.values = values;
+        self.start = 0;
+        self.resonance_sum = self.total_resonance();
+    }
+    
+    /// Sum the phi resonances of the live values from scratch
+    fn total_resonance(&self) -> T {
+        self.values().iter()
+            .map(|v| phi_resonance(*v, self.base_frequency))
+            .fold(T::zero(), |sum, r| sum + r)
+    }
+}
+
+/// Operations backed by the f64-only wavelet and compression submodules
+impl PhiHarmonicValues<f64> {
+    /// Denoise the values with the phi wavelet at the universal threshold
+    pub fn denoise(&mut self, levels: usize, mode: ThresholdMode) -> QuantumResult<()> {
+        let values = wavelet::denoise(self.values(), levels, mode)?;
         
-        // Update resonance
-        let value_resonance = phi_resonance(value, self.base_frequency);
-        let prev_resonance = self.phi_resonance * (self.values.len() - 1) as f64;
-        self.phi_resonance = (prev_resonance + value_resonance) / self.values.len() as f64;
+        self.set_values(values);
+        Ok(())
+    }
+    
+    /// Encode the values for archiving without modifying them
+    pub fn encode(&self, factor: f64, mode: CompressionMode) -> QuantumResult<PhiCompressed> {
+        PhiCompressed::encode(self.values(), factor, mode)
+    }
+    
+    /// Restore a container from encoded values
+    pub fn from_compressed(compressed: &PhiCompressed, base_frequency: f64) -> QuantumResult<Self> {
+        Ok(Self::new(compressed.decode()?.values, base_frequency))
+    }
+}
+
+impl<T: PhiFloat> Default for PhiHarmonicValues<T> {
+    /// An empty container at the Unity frequency (432 Hz)
+    fn default() -> Self {
+        Self::new(Vec::new(), cast(Frequency::Unity.value()))
     }
-}
\ No newline at end of file
+}
+
+impl<T: PhiFloat> Extend<T> for PhiHarmonicValues<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: PhiFloat> Extend<&'a T> for PhiHarmonicValues<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T: PhiFloat> FromIterator<T> for PhiHarmonicValues<T> {
+    /// Collect values at the Unity frequency; see [`PhiHarmonicValues::with_base_frequency`]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut harmonic = Self::default();
+        harmonic.extend(iter);
+        harmonic
+    }
+}
+
+impl<'a, T: PhiFloat> IntoIterator for &'a PhiHarmonicValues<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PhiFloat> IntoIterator for PhiHarmonicValues<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.values.drain(..self.start);
+        self.values.into_iter()
+    }
+}
@@ -0,0 +1,305 @@
+//! Golden-Ratio Low-Discrepancy Sequences
+//!
+//! This module generates Kronecker (Weyl) sequences x_n = frac(s + n·α) whose
+//! step α comes from the generalized golden ratio: the unique positive root g_d
+//! of x^(d+1) = x + 1. For d = 1 this is φ (the R1 sequence), for d = 2 the
+//! plastic number (R2), and higher roots give Rd. The points fill the unit
+//! cube far more evenly than pseudo-random samples, which makes them a good
+//! basis for quasi-Monte Carlo integration.
+
+#[cfg(test)]
+mod tests;
+
+use rand::Rng;
+
+use crate::error::{QuantumError, QuantumResult};
+
+/// Generalized golden ratio g_d, the positive root of x^(d+1) = x + 1
+///
+/// g_1 = φ and g_2 is the plastic number ≈ 1.3247.
+pub fn generalized_golden_ratio(dimension: usize) -> f64 {
+    let exponent = dimension.max(1) as i32 + 1;
+
+    // Newton's method from 2.0 converges monotonically since the polynomial is convex there
+    let mut x: f64 = 2.0;
+    for _ in 0..64 {
+        let f = x.powi(exponent) - x - 1.0;
+        let df = exponent as f64 * x.powi(exponent - 1) - 1.0;
+        let next = x - f / df;
+        if (next - x).abs() < 1e-16 {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Kronecker sequence in the unit cube with generalized-golden-ratio steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct KroneckerSequence {
+    /// Step per coordinate, α_i = g_d^-(i+1)
+    alpha: Vec<f64>,
+
+    /// Starting offset per coordinate
+    offset: Vec<f64>,
+
+    /// Index of the next point
+    index: u64,
+}
+
+impl KroneckerSequence {
+    /// The Rd sequence in `dimension` dimensions, offset by ½
+    pub fn new(dimension: usize) -> QuantumResult<Self> {
+        if dimension == 0 {
+            return Err(QuantumError::OperationError {
+                message: "Low-discrepancy sequences need at least one dimension".to_string(),
+            });
+        }
+
+        let g = generalized_golden_ratio(dimension);
+        let alpha = (1..=dimension).map(|i| g.powi(-(i as i32)).fract()).collect();
+
+        Ok(Self { alpha, offset: vec![0.5; dimension], index: 0 })
+    }
+
+    /// The one-dimensional R1 sequence (golden-ratio steps)
+    pub fn r1() -> Self {
+        Self::new(1).expect("one dimension is valid")
+    }
+
+    /// The two-dimensional R2 sequence (plastic-number steps)
+    pub fn r2() -> Self {
+        Self::new(2).expect("two dimensions is valid")
+    }
+
+    /// Use a different starting offset, one value per coordinate
+    pub fn with_offset(mut self, offset: Vec<f64>) -> QuantumResult<Self> {
+        if offset.len() != self.alpha.len() {
+            return Err(QuantumError::OperationError {
+                message: format!("Offset has {} coordinates, expected {}", offset.len(), self.alpha.len()),
+            });
+        }
+        self.offset = offset.into_iter().map(|s| s.rem_euclid(1.0)).collect();
+        Ok(self)
+    }
+
+    /// Get the number of dimensions
+    pub fn dimension(&self) -> usize {
+        self.alpha.len()
+    }
+
+    /// Get the step per coordinate
+    pub fn alpha(&self) -> &[f64] {
+        &self.alpha
+    }
+
+    /// Get the n-th point without advancing the sequence
+    pub fn point(&self, n: u64) -> Vec<f64> {
+        self.alpha.iter()
+            .zip(&self.offset)
+            .map(|(alpha, offset)| {
+                // n·α mod 1 in 64-bit fixed point: the wrapping product drops
+                // the integer part exactly, so no precision is lost for large n
+                let step = (alpha * 2f64.powi(64)) as u64;
+                let fraction = (n.wrapping_mul(step) >> 11) as f64 * 2f64.powi(-53);
+                (offset + fraction).fract()
+            })
+            .collect()
+    }
+
+    /// Skip ahead so the next point is the n-th
+    pub fn seek(&mut self, n: u64) {
+        self.index = n;
+    }
+
+    /// Take the next `count` points
+    pub fn take_points(&mut self, count: usize) -> Vec<Vec<f64>> {
+        self.by_ref().take(count).collect()
+    }
+}
+
+impl Iterator for KroneckerSequence {
+    type Item = Vec<f64>;
+
+    fn next(&mut self) -> Option<Vec<f64>> {
+        let point = self.point(self.index);
+        self.index += 1;
+        Some(point)
+    }
+}
+
+/// The first `count` values of the R1 sequence in [0, 1)
+pub fn r1_sequence(count: usize) -> Vec<f64> {
+    KroneckerSequence::r1().take(count).map(|p| p[0]).collect()
+}
+
+/// The first `count` points of the R2 sequence in [0, 1)²
+pub fn r2_sequence(count: usize) -> Vec<(f64, f64)> {
+    KroneckerSequence::r2().take(count).map(|p| (p[0], p[1])).collect()
+}
+
+/// Exact star discrepancy of a one-dimensional point set in [0, 1)
+pub fn star_discrepancy_1d(points: &[f64]) -> f64 {
+    if points.is_empty() {
+        return 1.0;
+    }
+
+    let mut sorted = points.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n = sorted.len() as f64;
+
+    let worst = sorted.iter()
+        .enumerate()
+        .map(|(i, x)| (x - (2 * i + 1) as f64 / (2.0 * n)).abs())
+        .fold(0.0, f64::max);
+    1.0 / (2.0 * n) + worst
+}
+
+/// Lower bound on the star discrepancy of a point set in [0, 1)^d
+///
+/// Checks the anchored boxes [0, p) and [0, p] at every point p, where the
+/// local discrepancy attains its extremes; O(N²·d).
+pub fn star_discrepancy_estimate(points: &[Vec<f64>]) -> QuantumResult<f64> {
+    let dimension = check_points(points)?;
+    let n = points.len() as f64;
+    let mut worst: f64 = 0.0;
+
+    for corner in points {
+        let volume: f64 = corner.iter().product();
+        let (mut open, mut closed) = (0usize, 0usize);
+        for p in points {
+            let inside = |strict: bool| (0..dimension).all(|k| if strict { p[k] < corner[k] } else { p[k] <= corner[k] });
+            open += inside(true) as usize;
+            closed += inside(false) as usize;
+        }
+        worst = worst.max(volume - open as f64 / n).max(closed as f64 / n - volume);
+    }
+
+    Ok(worst)
+}
+
+/// L2 star discrepancy of a point set in [0, 1)^d by Warnock's formula
+pub fn l2_star_discrepancy(points: &[Vec<f64>]) -> QuantumResult<f64> {
+    let dimension = check_points(points)?;
+    let n = points.len() as f64;
+
+    let single: f64 = points.iter()
+        .map(|p| p.iter().map(|x| (1.0 - x * x) / 2.0).product::<f64>())
+        .sum();
+
+    let mut pairs = 0.0;
+    for p in points {
+        for q in points {
+            pairs += p.iter().zip(q).map(|(a, b)| 1.0 - a.max(*b)).product::<f64>();
+        }
+    }
+
+    let squared = 3f64.powi(-(dimension as i32)) - 2.0 / n * single + pairs / (n * n);
+    Ok(squared.max(0.0).sqrt())
+}
+
+/// Quasi-Monte Carlo estimate with a sampling error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QmcEstimate {
+    /// Estimated integral
+    pub value: f64,
+
+    /// Standard error across randomized replicates (zero for a single replicate)
+    pub standard_error: f64,
+
+    /// Total number of integrand evaluations
+    pub evaluations: usize,
+}
+
+/// Integrate over a hyperrectangle with `samples` points of the Rd sequence
+pub fn qmc_integrate<F>(mut f: F, bounds: &[(f64, f64)], samples: usize) -> QuantumResult<f64>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    let sequence = KroneckerSequence::new(bounds.len())?;
+    integrate_with(&mut f, bounds, samples, sequence)
+}
+
+/// Randomized QMC integration with an error estimate
+///
+/// Averages `replicates` independent Cranley-Patterson random shifts of the
+/// Rd sequence, each using `samples` points; the spread between replicates
+/// gives the standard error.
+pub fn randomized_qmc_integrate<F, R>(
+    mut f: F,
+    bounds: &[(f64, f64)],
+    samples: usize,
+    replicates: usize,
+    rng: &mut R,
+) -> QuantumResult<QmcEstimate>
+where
+    F: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    if replicates == 0 {
+        return Err(QuantumError::OperationError {
+            message: "Randomized QMC needs at least one replicate".to_string(),
+        });
+    }
+
+    let mut estimates = Vec::with_capacity(replicates);
+    for _ in 0..replicates {
+        let shift = (0..bounds.len()).map(|_| rng.gen::<f64>()).collect();
+        let sequence = KroneckerSequence::new(bounds.len())?.with_offset(shift)?;
+        estimates.push(integrate_with(&mut f, bounds, samples, sequence)?);
+    }
+
+    let r = replicates as f64;
+    let value = estimates.iter().sum::<f64>() / r;
+    let standard_error = if replicates > 1 {
+        let variance = estimates.iter().map(|e| (e - value).powi(2)).sum::<f64>() / (r - 1.0);
+        (variance / r).sqrt()
+    } else {
+        0.0
+    };
+
+    Ok(QmcEstimate { value, standard_error, evaluations: samples * replicates })
+}
+
+/// Average `f` over points of `sequence` mapped into the bounds, times the volume
+fn integrate_with<F>(f: &mut F, bounds: &[(f64, f64)], samples: usize, sequence: KroneckerSequence) -> QuantumResult<f64>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    if samples == 0 {
+        return Err(QuantumError::OperationError {
+            message: "QMC integration needs at least one sample".to_string(),
+        });
+    }
+    for (i, (lower, upper)) in bounds.iter().enumerate() {
+        if !lower.is_finite() || !upper.is_finite() || lower > upper {
+            return Err(QuantumError::OperationError {
+                message: format!("Invalid bounds [{}, {}] for coordinate {}", lower, upper, i),
+            });
+        }
+    }
+
+    let volume: f64 = bounds.iter().map(|(lower, upper)| upper - lower).product();
+    let mut x = vec![0.0; bounds.len()];
+    let mut sum = 0.0;
+
+    for point in sequence.take(samples) {
+        for ((xi, u), (lower, upper)) in x.iter_mut().zip(&point).zip(bounds) {
+            *xi = lower + u * (upper - lower);
+        }
+        sum += f(&x);
+    }
+
+    Ok(volume * sum / samples as f64)
+}
+
+/// Validate a non-empty point set of equal dimensions, returning the dimension
+fn check_points(points: &[Vec<f64>]) -> QuantumResult<usize> {
+    let dimension = points.first().map(Vec::len).unwrap_or(0);
+    if dimension == 0 || points.iter().any(|p| p.len() != dimension) {
+        return Err(QuantumError::OperationError {
+            message: "Discrepancy needs a non-empty set of points with equal dimensions".to_string(),
+        });
+    }
+    Ok(dimension)
+}
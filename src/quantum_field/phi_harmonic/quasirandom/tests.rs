@@ -0,0 +1,113 @@
+//! Unit tests for the quasirandom module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::PHI;
+    use crate::quantum_field::phi_harmonic::quasirandom::{
+        generalized_golden_ratio, l2_star_discrepancy, qmc_integrate, r1_sequence, r2_sequence,
+        randomized_qmc_integrate, star_discrepancy_1d, star_discrepancy_estimate, KroneckerSequence,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_generalized_golden_ratios() {
+        assert!((generalized_golden_ratio(1) - PHI).abs() < 1e-15);
+
+        // The plastic number satisfies x³ = x + 1
+        let plastic = generalized_golden_ratio(2);
+        assert!((plastic - 1.324717957244746).abs() < 1e-15);
+
+        for d in 1..8 {
+            let g = generalized_golden_ratio(d);
+            assert!((g.powi(d as i32 + 1) - g - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_points_keep_full_precision_for_large_indices() {
+        let sequence = KroneckerSequence::new(3).unwrap().with_offset(vec![0.0; 3]).unwrap();
+
+        for n in [1_000_003u64, (1 << 40) + 7, (1 << 52) + 12345, u64::MAX / 3] {
+            let point = sequence.point(n);
+            for (x, alpha) in point.iter().zip(sequence.alpha()) {
+                // Exact n·α mod 1 from the step's binary expansion α = m·2^-k
+                let (mantissa, exponent, _) = num::Float::integer_decode(*alpha);
+                let shift = (-exponent) as u32;
+                let numerator = (n as u128).wrapping_mul(mantissa as u128) & ((1u128 << shift) - 1);
+                let exact = numerator as f64 / 2f64.powi(shift as i32);
+                assert!((x - exact).abs() < 1e-15, "n = {}: {} vs {}", n, x, exact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequences_start_at_offset_and_stay_in_unit_cube() {
+        let r1 = r1_sequence(100);
+        assert_eq!(r1[0], 0.5);
+        assert!(((r1[1] - 0.5 - 1.0 / PHI).rem_euclid(1.0)).abs() < 1e-12);
+
+        let r2 = r2_sequence(100);
+        assert_eq!(r2.len(), 100);
+        assert!(r2.iter().all(|(x, y)| (0.0..1.0).contains(x) && (0.0..1.0).contains(y)));
+
+        let mut rd = KroneckerSequence::new(5).unwrap();
+        let points = rd.take_points(50);
+        assert!(points.iter().flatten().all(|x| (0.0..1.0).contains(x)));
+
+        rd.seek(10);
+        assert_eq!(rd.next().unwrap(), points[10]);
+        assert!(KroneckerSequence::new(0).is_err());
+    }
+
+    #[test]
+    fn test_r1_discrepancy_beats_random() {
+        let n = 1000;
+        let mut rng = StdRng::seed_from_u64(42);
+        let random: Vec<f64> = (0..n).map(|_| rng.gen()).collect();
+
+        let golden = star_discrepancy_1d(&r1_sequence(n));
+        assert!(golden < 0.005);
+        assert!(golden < star_discrepancy_1d(&random) / 3.0);
+
+        // A perfectly centred grid has the minimal discrepancy 1/(2N)
+        let grid: Vec<f64> = (0..10).map(|i| (2 * i + 1) as f64 / 20.0).collect();
+        assert!((star_discrepancy_1d(&grid) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_r2_discrepancy_beats_random() {
+        let n = 256;
+        let mut rng = StdRng::seed_from_u64(7);
+        let random: Vec<Vec<f64>> = (0..n).map(|_| vec![rng.gen(), rng.gen()]).collect();
+        let golden = KroneckerSequence::r2().take_points(n);
+
+        assert!(star_discrepancy_estimate(&golden).unwrap() < star_discrepancy_estimate(&random).unwrap());
+        assert!(l2_star_discrepancy(&golden).unwrap() < l2_star_discrepancy(&random).unwrap() / 2.0);
+
+        // A single point at x has squared L2 star discrepancy x² - x + 1/3
+        assert!((l2_star_discrepancy(&[vec![0.5]]).unwrap() - (1.0f64 / 12.0).sqrt()).abs() < 1e-12);
+        assert!(star_discrepancy_estimate(&[]).is_err());
+        assert!(l2_star_discrepancy(&[vec![0.1], vec![0.2, 0.3]]).is_err());
+    }
+
+    #[test]
+    fn test_qmc_integration_over_hyperrectangle() {
+        // ∫∫∫ x + y² + z³ over [0,1]×[0,2]×[1,3] = 2 + 16/3 + 40
+        let bounds = [(0.0, 1.0), (0.0, 2.0), (1.0, 3.0)];
+        let exact = 2.0 + 16.0 / 3.0 + 40.0;
+        let value = qmc_integrate(|x: &[f64]| x[0] + x[1].powi(2) + x[2].powi(3), &bounds, 4096).unwrap();
+        assert!(((value - exact) / exact).abs() < 1e-3);
+
+        let gaussian = |x: &[f64]| (-x.iter().map(|v| v * v).sum::<f64>()).exp();
+        let mut rng = StdRng::seed_from_u64(3);
+        let estimate = randomized_qmc_integrate(gaussian, &[(-4.0, 4.0); 2], 2048, 8, &mut rng).unwrap();
+        assert!((estimate.value - std::f64::consts::PI).abs() < 1e-3);
+        assert!(estimate.standard_error < 1e-3);
+        assert_eq!(estimate.evaluations, 2048 * 8);
+
+        assert!(qmc_integrate(|_: &[f64]| 1.0, &[(1.0, 0.0)], 10).is_err());
+        assert!(qmc_integrate(|_: &[f64]| 1.0, &[(0.0, 1.0)], 0).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+//! Golden-Section and Fibonacci Search
+//!
+//! This module minimizes scalar cost functions whose minimum is unknown.
+//! Golden-section search shrinks the interval by λ per evaluation; Fibonacci
+//! search achieves the optimal reduction for a fixed evaluation budget. A
+//! bracketing step expanding by φ finds a starting interval automatically.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{LAMBDA, PHI};
+use crate::error::{QuantumError, QuantumResult};
+
+/// Why a search stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStatus {
+    /// The interval shrank below the tolerance
+    Converged,
+
+    /// The iteration limit was reached first
+    MaxIterations,
+}
+
+/// Outcome of a scalar minimization
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchResult {
+    /// Location of the best point found
+    pub argmin: f64,
+
+    /// Cost at `argmin`
+    pub minimum: f64,
+
+    /// Number of interval reductions performed
+    pub iterations: usize,
+
+    /// Number of cost-function evaluations
+    pub evaluations: usize,
+
+    /// Final interval width
+    pub interval: f64,
+
+    /// Why the search stopped
+    pub status: SearchStatus,
+}
+
+impl SearchResult {
+    /// Check whether the search converged
+    pub fn converged(&self) -> bool {
+        self.status == SearchStatus::Converged
+    }
+}
+
+/// Interval a < b < c (or a > b > c) with f(b) below both ends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bracket {
+    /// First end point
+    pub a: f64,
+
+    /// Interior point with the lowest cost
+    pub b: f64,
+
+    /// Second end point
+    pub c: f64,
+
+    /// Cost at `b`
+    pub fb: f64,
+
+    /// Number of cost-function evaluations used
+    pub evaluations: usize,
+}
+
+impl Bracket {
+    /// Get the bracketed interval as (lower, upper)
+    pub fn interval(&self) -> (f64, f64) {
+        (self.a.min(self.c), self.a.max(self.c))
+    }
+}
+
+/// Bracket a minimum by stepping downhill with φ-growing steps
+///
+/// Starts from `start` and `start + step`, turning around if the first step
+/// goes uphill.
+pub fn bracket_minimum<F>(mut cost: F, start: f64, step: f64, max_evaluations: usize) -> QuantumResult<Bracket>
+where
+    F: FnMut(f64) -> f64,
+{
+    if step == 0.0 || !step.is_finite() || !start.is_finite() {
+        return Err(QuantumError::OperationError {
+            message: format!("Bracketing needs a finite start and non-zero step, got {} and {}", start, step),
+        });
+    }
+
+    let (mut a, mut b) = (start, start + step);
+    let (mut fa, mut fb) = (cost(a), cost(b));
+    if fb > fa {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+
+    let mut c = b + PHI * (b - a);
+    let mut fc = cost(c);
+    let mut evaluations = 3;
+
+    while fc < fb {
+        if evaluations >= max_evaluations {
+            return Err(QuantumError::OperationError {
+                message: format!("No minimum bracketed within {} evaluations", max_evaluations),
+            });
+        }
+
+        a = b;
+        b = c;
+        fb = fc;
+        c = b + PHI * (b - a);
+        fc = cost(c);
+        evaluations += 1;
+    }
+
+    Ok(Bracket { a, b, c, fb, evaluations })
+}
+
+/// Golden-section search for a minimum on [lower, upper]
+///
+/// Each iteration keeps the λ fraction of the interval that must contain a
+/// minimum of a unimodal function, reusing one interior evaluation.
+pub fn golden_section_search<F>(
+    mut cost: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> QuantumResult<SearchResult>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_interval(lower, upper, tolerance)?;
+
+    let (mut a, mut b) = (lower, upper);
+    let mut c = b - LAMBDA * (b - a);
+    let mut d = a + LAMBDA * (b - a);
+    let (mut fc, mut fd) = (cost(c), cost(d));
+    let mut evaluations = 2;
+    let mut iterations = 0;
+
+    while b - a > tolerance && iterations < max_iterations {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - LAMBDA * (b - a);
+            fc = cost(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + LAMBDA * (b - a);
+            fd = cost(d);
+        }
+        evaluations += 1;
+        iterations += 1;
+    }
+
+    let (argmin, minimum) = if fc < fd { (c, fc) } else { (d, fd) };
+    Ok(SearchResult {
+        argmin,
+        minimum,
+        iterations,
+        evaluations,
+        interval: b - a,
+        status: status(b - a, tolerance),
+    })
+}
+
+/// Fibonacci search for a minimum on [lower, upper]
+///
+/// The number of reductions is fixed up front from the smallest F(n) with
+/// 2/F(n) of the interval below the tolerance, capped at `max_iterations`;
+/// probe points sit at ratios of consecutive Fibonacci numbers.
+pub fn fibonacci_search<F>(
+    mut cost: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> QuantumResult<SearchResult>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_interval(lower, upper, tolerance)?;
+
+    // fib[k] = F(k); n - 3 reductions shrink the interval to 2/F(n) of its width
+    let mut fib = vec![0.0, 1.0, 1.0, 2.0];
+    let ratio = 2.0 * (upper - lower) / tolerance;
+    while fib[fib.len() - 1] < ratio && fib.len() < max_iterations.saturating_add(4) {
+        let next = fib[fib.len() - 1] + fib[fib.len() - 2];
+        fib.push(next);
+    }
+    let n = fib.len() - 1;
+
+    let (mut a, mut b) = (lower, upper);
+    let mut c = a + fib[n - 2] / fib[n] * (b - a);
+    let mut d = a + fib[n - 1] / fib[n] * (b - a);
+    let (mut fc, mut fd) = (cost(c), cost(d));
+    let mut evaluations = 2;
+    let mut iterations = 0;
+
+    for k in 1..n - 2 {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = a + fib[n - k - 2] / fib[n - k] * (b - a);
+            fc = cost(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + fib[n - k - 1] / fib[n - k] * (b - a);
+            fd = cost(d);
+        }
+        evaluations += 1;
+        iterations += 1;
+    }
+
+    let (argmin, minimum) = if fc < fd { (c, fc) } else { (d, fd) };
+    Ok(SearchResult {
+        argmin,
+        minimum,
+        iterations,
+        evaluations,
+        interval: b - a,
+        status: status(b - a, tolerance),
+    })
+}
+
+/// Minimize without a known interval: bracket from `start`, then golden-section search
+///
+/// `max_iterations` budgets each phase separately: bracketing may spend up
+/// to that many cost evaluations, and the golden-section search then runs
+/// up to that many iterations on the bracket it found.
+pub fn golden_section_minimize<F>(
+    mut cost: F,
+    start: f64,
+    step: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> QuantumResult<SearchResult>
+where
+    F: FnMut(f64) -> f64,
+{
+    let bracket = bracket_minimum(&mut cost, start, step, max_iterations)?;
+    let (lower, upper) = bracket.interval();
+
+    let mut result = golden_section_search(&mut cost, lower, upper, tolerance, max_iterations)?;
+    result.evaluations += bracket.evaluations;
+    if bracket.fb < result.minimum {
+        result.argmin = bracket.b;
+        result.minimum = bracket.fb;
+    }
+    Ok(result)
+}
+
+/// Validate a search interval and tolerance
+fn check_interval(lower: f64, upper: f64, tolerance: f64) -> QuantumResult<()> {
+    if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        return Err(QuantumError::OperationError {
+            message: format!("Invalid search interval [{}, {}]", lower, upper),
+        });
+    }
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(QuantumError::OperationError {
+            message: format!("Search tolerance must be positive, got {}", tolerance),
+        });
+    }
+    Ok(())
+}
+
+/// Status for a final interval width
+fn status(width: f64, tolerance: f64) -> SearchStatus {
+    if width <= tolerance {
+        SearchStatus::Converged
+    } else {
+        SearchStatus::MaxIterations
+    }
+}
@@ -0,0 +1,111 @@
+//! Unit tests for the search module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::search::{
+        bracket_minimum, fibonacci_search, golden_section_minimize, golden_section_search, SearchStatus,
+    };
+
+    fn shifted_quadratic(x: f64) -> f64 {
+        (x - 1.234).powi(2) + 0.5
+    }
+
+    #[test]
+    fn test_golden_section_finds_minimum() {
+        let result = golden_section_search(shifted_quadratic, -10.0, 10.0, 1e-8, 200).unwrap();
+
+        assert!(result.converged());
+        assert!((result.argmin - 1.234).abs() < 1e-7);
+        assert!((result.minimum - 0.5).abs() < 1e-12);
+        assert!(result.interval <= 1e-8);
+        assert_eq!(result.evaluations, result.iterations + 2);
+    }
+
+    #[test]
+    fn test_golden_section_reports_iteration_limit() {
+        let result = golden_section_search(shifted_quadratic, -10.0, 10.0, 1e-12, 5).unwrap();
+        assert_eq!(result.status, SearchStatus::MaxIterations);
+        assert_eq!(result.iterations, 5);
+
+        // Each iteration keeps a λ fraction of the interval
+        assert!((result.interval - 20.0 * crate::constants::LAMBDA.powi(5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fibonacci_search_finds_minimum() {
+        let result = fibonacci_search(|x: f64| x.cos(), 2.0, 4.0, 1e-6, 100).unwrap();
+
+        assert!(result.converged());
+        assert!((result.argmin - std::f64::consts::PI).abs() < 1e-6);
+        assert!((result.minimum + 1.0).abs() < 1e-12);
+
+        // Fibonacci search needs no more evaluations than golden-section search
+        let golden = golden_section_search(|x: f64| x.cos(), 2.0, 4.0, 1e-6, 100).unwrap();
+        assert!(result.evaluations <= golden.evaluations);
+    }
+
+    #[test]
+    fn test_evaluations_are_counted() {
+        let mut calls = 0;
+        let result = fibonacci_search(
+            |x| {
+                calls += 1;
+                shifted_quadratic(x)
+            },
+            0.0,
+            3.0,
+            1e-4,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.evaluations, calls);
+
+        let limited = fibonacci_search(shifted_quadratic, 0.0, 3.0, 1e-12, 10).unwrap();
+        assert_eq!((limited.iterations, limited.status), (10, SearchStatus::MaxIterations));
+
+        // An unlimited budget is bounded by the tolerance alone
+        let unlimited = fibonacci_search(shifted_quadratic, 0.0, 3.0, 1e-9, usize::MAX).unwrap();
+        assert!(unlimited.converged());
+    }
+
+    #[test]
+    fn test_bracketing() {
+        let bracket = bracket_minimum(shifted_quadratic, 20.0, 0.1, 100).unwrap();
+        let (lower, upper) = bracket.interval();
+        assert!(lower < 1.234 && 1.234 < upper);
+        assert!(bracket.fb < shifted_quadratic(bracket.a) && bracket.fb < shifted_quadratic(bracket.c));
+
+        // Monotone functions cannot be bracketed
+        assert!(bracket_minimum(|x| x, 0.0, 1.0, 30).is_err());
+        assert!(bracket_minimum(shifted_quadratic, 0.0, 0.0, 30).is_err());
+    }
+
+    #[test]
+    fn test_minimize_without_interval() {
+        let mut calls = 0;
+        let result = golden_section_minimize(
+            |x| {
+                calls += 1;
+                (x + 7.5).powi(4) - 2.0
+            },
+            3.0,
+            0.5,
+            1e-6,
+            200,
+        )
+        .unwrap();
+
+        assert!(result.converged());
+        assert!((result.argmin + 7.5).abs() < 1e-3);
+        assert!((result.minimum + 2.0).abs() < 1e-9);
+        assert_eq!(result.evaluations, calls);
+    }
+
+    #[test]
+    fn test_invalid_intervals() {
+        assert!(golden_section_search(shifted_quadratic, 1.0, 1.0, 1e-6, 10).is_err());
+        assert!(fibonacci_search(shifted_quadratic, 0.0, 1.0, 0.0, 10).is_err());
+        assert!(golden_section_search(shifted_quadratic, f64::NAN, 1.0, 1e-6, 10).is_err());
+    }
+}
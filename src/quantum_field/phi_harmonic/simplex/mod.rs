@@ -0,0 +1,333 @@
+//! Phi-Scaled Nelder-Mead Optimization
+//!
+//! This module minimizes cost functions of several parameters within box
+//! bounds using the Nelder-Mead simplex method. Expansion steps grow by φ and
+//! contraction and shrink steps scale by λ. Callers can watch progress through
+//! a callback that may stop the search, and cap the number of evaluations.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{LAMBDA, PHI};
+use crate::error::{QuantumError, QuantumResult};
+
+/// Why a simplex search stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimplexStatus {
+    /// Cost spread and simplex size fell below the tolerance
+    Converged,
+
+    /// The evaluation budget was used up
+    MaxEvaluations,
+
+    /// The progress callback asked to stop
+    Stopped,
+}
+
+/// Snapshot of the search passed to progress callbacks
+#[derive(Debug, Clone, Copy)]
+pub struct Progress<'a> {
+    /// Iterations completed
+    pub iteration: usize,
+
+    /// Cost-function evaluations so far
+    pub evaluations: usize,
+
+    /// Best point so far
+    pub best_point: &'a [f64],
+
+    /// Cost at the best point
+    pub best_value: f64,
+
+    /// Difference between the worst and best costs in the simplex
+    pub spread: f64,
+}
+
+/// Outcome of a multivariate minimization
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplexResult {
+    /// Location of the best point found
+    pub argmin: Vec<f64>,
+
+    /// Cost at `argmin`
+    pub minimum: f64,
+
+    /// Number of simplex iterations
+    pub iterations: usize,
+
+    /// Number of cost-function evaluations
+    pub evaluations: usize,
+
+    /// Why the search stopped
+    pub status: SimplexStatus,
+}
+
+impl SimplexResult {
+    /// Check whether the search converged
+    pub fn converged(&self) -> bool {
+        self.status == SimplexStatus::Converged
+    }
+}
+
+/// Bounded Nelder-Mead optimizer with phi-scaled steps
+#[derive(Debug, Clone)]
+pub struct NelderMead {
+    /// Lower and upper bound per parameter
+    bounds: Vec<(f64, f64)>,
+
+    /// Convergence tolerance on cost spread and simplex size
+    tolerance: f64,
+
+    /// Maximum number of cost-function evaluations
+    max_evaluations: usize,
+
+    /// Initial simplex edge as a fraction of each bound's width
+    initial_step: f64,
+}
+
+impl NelderMead {
+    /// Create an optimizer for the given parameter bounds
+    pub fn new(bounds: Vec<(f64, f64)>) -> QuantumResult<Self> {
+        if bounds.is_empty() {
+            return Err(QuantumError::OperationError {
+                message: "Nelder-Mead needs at least one parameter".to_string(),
+            });
+        }
+
+        for (i, (lower, upper)) in bounds.iter().enumerate() {
+            if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+                return Err(QuantumError::OperationError {
+                    message: format!("Invalid bounds [{}, {}] for parameter {}", lower, upper, i),
+                });
+            }
+        }
+
+        Ok(Self {
+            bounds,
+            tolerance: 1e-8,
+            max_evaluations: 10_000,
+            initial_step: LAMBDA * LAMBDA,
+        })
+    }
+
+    /// Set the convergence tolerance (finite and non-negative)
+    pub fn with_tolerance(mut self, tolerance: f64) -> QuantumResult<Self> {
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(QuantumError::OperationError {
+                message: format!("Tolerance must be finite and non-negative, got {}", tolerance),
+            });
+        }
+
+        self.tolerance = tolerance;
+        Ok(self)
+    }
+
+    /// Set the maximum number of cost-function evaluations (at least one)
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> QuantumResult<Self> {
+        if max_evaluations == 0 {
+            return Err(QuantumError::OperationError {
+                message: "Nelder-Mead needs at least one cost-function evaluation".to_string(),
+            });
+        }
+
+        self.max_evaluations = max_evaluations;
+        Ok(self)
+    }
+
+    /// Set the initial simplex edge as a fraction of each bound's width
+    ///
+    /// The fraction must lie in (0, 1]: a zero step collapses the simplex
+    /// onto the start point, and larger steps cannot fit inside the bounds.
+    pub fn with_initial_step(mut self, initial_step: f64) -> QuantumResult<Self> {
+        if !(initial_step > 0.0 && initial_step <= 1.0) {
+            return Err(QuantumError::OperationError {
+                message: format!("Initial step must be in (0, 1], got {}", initial_step),
+            });
+        }
+
+        self.initial_step = initial_step;
+        Ok(self)
+    }
+
+    /// Get the parameter bounds
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        &self.bounds
+    }
+
+    /// Get the number of parameters
+    pub fn dimension(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Minimize a cost function starting from `start`
+    pub fn minimize<F>(&self, cost: F, start: &[f64]) -> QuantumResult<SimplexResult>
+    where
+        F: FnMut(&[f64]) -> f64,
+    {
+        self.minimize_with_progress(cost, start, |_| true)
+    }
+
+    /// Minimize, reporting progress after each iteration
+    ///
+    /// The callback returns `false` to stop the search early.
+    pub fn minimize_with_progress<F, P>(&self, cost: F, start: &[f64], mut progress: P) -> QuantumResult<SimplexResult>
+    where
+        F: FnMut(&[f64]) -> f64,
+        P: FnMut(&Progress) -> bool,
+    {
+        let n = self.dimension();
+        if start.len() != n {
+            return Err(QuantumError::OperationError {
+                message: format!("Start point has {} parameters, expected {}", start.len(), n),
+            });
+        }
+
+        let mut budget = Budget { cost, used: 0, limit: self.max_evaluations };
+        let mut simplex: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n + 1);
+
+        // Initial simplex: the start point plus one step along each axis,
+        // cut short at the farther bound when neither full step fits
+        let origin = self.clamp(start.to_vec());
+        for i in 0..=n {
+            let mut vertex = origin.clone();
+            if i > 0 {
+                let (lower, upper) = self.bounds[i - 1];
+                let step = self.initial_step * (upper - lower);
+                let x = vertex[i - 1];
+                vertex[i - 1] = if x + step <= upper {
+                    x + step
+                } else if x - step >= lower {
+                    x - step
+                } else if upper - x >= x - lower {
+                    upper
+                } else {
+                    lower
+                };
+            }
+            match budget.evaluate(&vertex) {
+                Some(value) => simplex.push((vertex, value)),
+                None => return Ok(finish(simplex, 0, budget.used, SimplexStatus::MaxEvaluations)),
+            }
+        }
+
+        let mut iteration = 0;
+        let status = loop {
+            simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            let spread = simplex[n].1 - simplex[0].1;
+            let size = simplex[1..].iter()
+                .flat_map(|(x, _)| x.iter().zip(&simplex[0].0).map(|(a, b)| (a - b).abs()))
+                .fold(0.0, f64::max);
+
+            let snapshot = Progress {
+                iteration,
+                evaluations: budget.used,
+                best_point: &simplex[0].0,
+                best_value: simplex[0].1,
+                spread,
+            };
+            if !progress(&snapshot) {
+                break SimplexStatus::Stopped;
+            }
+            if spread <= self.tolerance && size <= self.tolerance {
+                break SimplexStatus::Converged;
+            }
+
+            match self.step(&mut simplex, &mut budget) {
+                Some(()) => iteration += 1,
+                None => break SimplexStatus::MaxEvaluations,
+            }
+        };
+
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        Ok(finish(simplex, iteration, budget.used, status))
+    }
+
+    /// One Nelder-Mead iteration on a sorted simplex; `None` when out of budget
+    fn step<F>(&self, simplex: &mut [(Vec<f64>, f64)], budget: &mut Budget<F>) -> Option<()>
+    where
+        F: FnMut(&[f64]) -> f64,
+    {
+        let n = self.dimension();
+        let worst = simplex[n].clone();
+
+        // Centroid of all but the worst vertex
+        let mut centroid = vec![0.0; n];
+        for (x, _) in &simplex[..n] {
+            for (c, v) in centroid.iter_mut().zip(x) {
+                *c += v / n as f64;
+            }
+        }
+        let toward = |scale: f64| -> Vec<f64> {
+            self.clamp(centroid.iter().zip(&worst.0).map(|(c, w)| c + scale * (c - w)).collect())
+        };
+
+        let reflected = toward(1.0);
+        let fr = budget.evaluate(&reflected)?;
+
+        if fr < simplex[0].1 {
+            let expanded = toward(PHI);
+            let fe = budget.evaluate(&expanded)?;
+            simplex[n] = if fe < fr { (expanded, fe) } else { (reflected, fr) };
+        } else if fr < simplex[n - 1].1 {
+            simplex[n] = (reflected, fr);
+        } else {
+            // Contract toward the better of the reflected and worst points
+            let (contracted, limit) = if fr < worst.1 { (toward(LAMBDA), fr) } else { (toward(-LAMBDA), worst.1) };
+            let fc = budget.evaluate(&contracted)?;
+
+            if fc < limit {
+                simplex[n] = (contracted, fc);
+            } else {
+                let best = simplex[0].0.clone();
+                for vertex in simplex[1..].iter_mut() {
+                    let shrunk: Vec<f64> = best.iter().zip(&vertex.0).map(|(b, x)| b + LAMBDA * (x - b)).collect();
+                    let value = budget.evaluate(&shrunk)?;
+                    *vertex = (shrunk, value);
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    /// Project a point into the bounds
+    fn clamp(&self, mut point: Vec<f64>) -> Vec<f64> {
+        for (x, (lower, upper)) in point.iter_mut().zip(&self.bounds) {
+            *x = x.clamp(*lower, *upper);
+        }
+        point
+    }
+}
+
+/// Cost function with an evaluation budget
+struct Budget<F> {
+    /// The cost function
+    cost: F,
+
+    /// Evaluations used
+    used: usize,
+
+    /// Maximum evaluations
+    limit: usize,
+}
+
+impl<F: FnMut(&[f64]) -> f64> Budget<F> {
+    /// Evaluate the cost, or `None` once the budget is spent
+    fn evaluate(&mut self, point: &[f64]) -> Option<f64> {
+        if self.used >= self.limit {
+            return None;
+        }
+        self.used += 1;
+        Some((self.cost)(point))
+    }
+}
+
+/// Build the result from the best vertex of a sorted simplex
+fn finish(simplex: Vec<(Vec<f64>, f64)>, iterations: usize, evaluations: usize, status: SimplexStatus) -> SimplexResult {
+    let (argmin, minimum) = simplex.into_iter()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((Vec::new(), f64::INFINITY));
+
+    SimplexResult { argmin, minimum, iterations, evaluations, status }
+}
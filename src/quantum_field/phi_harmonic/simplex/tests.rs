@@ -0,0 +1,112 @@
+//! Unit tests for the simplex module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::simplex::{NelderMead, SimplexStatus};
+
+    fn rosenbrock(x: &[f64]) -> f64 {
+        (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    #[test]
+    fn test_minimizes_rosenbrock() {
+        let optimizer = NelderMead::new(vec![(-2.0, 2.0), (-1.0, 3.0)]).unwrap().with_tolerance(1e-10).unwrap();
+        let result = optimizer.minimize(rosenbrock, &[-1.2, 1.0]).unwrap();
+
+        assert!(result.converged());
+        assert!((result.argmin[0] - 1.0).abs() < 1e-4);
+        assert!((result.argmin[1] - 1.0).abs() < 1e-4);
+        assert!(result.minimum < 1e-8);
+    }
+
+    #[test]
+    fn test_respects_bounds() {
+        // Unconstrained minimum at (3, -3) lies outside the box
+        let cost = |x: &[f64]| (x[0] - 3.0).powi(2) + (x[1] + 3.0).powi(2) + x[2].powi(2);
+        let optimizer = NelderMead::new(vec![(0.0, 1.0), (-1.0, 1.0), (-1.0, 1.0)]).unwrap();
+
+        let mut inside = true;
+        let result = optimizer
+            .minimize(|x: &[f64]| {
+                inside &= x[0] >= 0.0 && x[0] <= 1.0 && x[1] >= -1.0 && x[1] <= 1.0;
+                cost(x)
+            }, &[0.5, 0.0, 0.5])
+            .unwrap();
+
+        assert!(inside);
+        assert!((result.argmin[0] - 1.0).abs() < 1e-6);
+        assert!((result.argmin[1] + 1.0).abs() < 1e-6);
+        assert!(result.argmin[2].abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_evaluation_budget_is_a_hard_limit() {
+        let mut calls = 0;
+        let optimizer = NelderMead::new(vec![(-2.0, 2.0), (-1.0, 3.0)]).unwrap().with_max_evaluations(25).unwrap();
+        let result = optimizer
+            .minimize(|x: &[f64]| {
+                calls += 1;
+                rosenbrock(x)
+            }, &[-1.2, 1.0])
+            .unwrap();
+
+        assert_eq!(result.status, SimplexStatus::MaxEvaluations);
+        assert_eq!(result.evaluations, 25);
+        assert_eq!(calls, 25);
+        assert!(result.minimum <= rosenbrock(&[-1.2, 1.0]));
+    }
+
+    #[test]
+    fn test_progress_callback_can_stop() {
+        let optimizer = NelderMead::new(vec![(-5.0, 5.0); 4]).unwrap();
+        let cost = |x: &[f64]| x.iter().map(|v| v * v).sum::<f64>();
+
+        let mut best = Vec::new();
+        let result = optimizer
+            .minimize_with_progress(cost, &[3.0, -2.0, 1.0, 4.0], |progress| {
+                best.push(progress.best_value);
+                progress.iteration < 10
+            })
+            .unwrap();
+
+        assert_eq!(result.status, SimplexStatus::Stopped);
+        assert_eq!(result.iterations, 10);
+        assert_eq!(best.len(), 11);
+        assert!(best.windows(2).all(|pair| pair[1] <= pair[0]));
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert!(NelderMead::new(Vec::new()).is_err());
+        assert!(NelderMead::new(vec![(1.0, 1.0)]).is_err());
+        assert!(NelderMead::new(vec![(0.0, f64::INFINITY)]).is_err());
+
+        let optimizer = NelderMead::new(vec![(0.0, 1.0); 2]).unwrap();
+        assert!(optimizer.minimize(|x: &[f64]| x[0], &[0.5]).is_err());
+
+        assert!(optimizer.clone().with_tolerance(-1e-6).is_err());
+        assert!(optimizer.clone().with_tolerance(f64::NAN).is_err());
+        assert!(optimizer.clone().with_max_evaluations(0).is_err());
+        assert!(optimizer.clone().with_initial_step(0.0).is_err());
+        assert!(optimizer.clone().with_initial_step(1.5).is_err());
+        assert!(optimizer.clone().with_initial_step(f64::NAN).is_err());
+        assert!(optimizer.with_tolerance(0.0).is_ok());
+    }
+
+    #[test]
+    fn test_full_initial_step_stays_in_bounds() {
+        let optimizer = NelderMead::new(vec![(-1.0, 1.0), (0.0, 4.0)]).unwrap()
+            .with_initial_step(1.0).unwrap()
+            .with_max_evaluations(3).unwrap();
+
+        let mut points = Vec::new();
+        optimizer.minimize(|x: &[f64]| {
+            points.push(x.to_vec());
+            x[0] * x[0] + x[1]
+        }, &[0.2, 1.0]).unwrap();
+
+        // Neither full step fits, so each vertex stops at the farther bound
+        assert_eq!(points, vec![vec![0.2, 1.0], vec![-1.0, 1.0], vec![0.2, 4.0]]);
+    }
+}
@@ -0,0 +1,317 @@
+//! Phi Resonance Spectrum
+//!
+//! `phi_resonance` scores a value against a single base frequency. This
+//! module sweeps the base frequency instead. A value tuned at a reference
+//! frequency f_ref resonates at f_v = f_ref·φ^ln|v|, and against a base f the
+//! harmonic h contributes exp(-10·|f_v - h·f| / f). The curve peaks wherever
+//! some harmonic of the base lines up with f_v, and every peak records which
+//! harmonic caused it. With the standard harmonic set, the curve at f_ref
+//! equals `phi_resonance(value, f_ref)`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{Frequency, PHI};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::phi_harmonic::{harmonic_strength, resonant_frequency_of, standard_harmonics};
+
+/// Default minimum resonance for a local maximum to count as a peak
+const DEFAULT_THRESHOLD: f64 = 0.05;
+
+/// Ratios of the base frequency that a value can resonate with
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarmonicSet {
+    /// Harmonic ratios
+    ratios: Vec<f64>,
+}
+
+impl HarmonicSet {
+    /// Create a harmonic set from positive, finite ratios
+    pub fn new(ratios: Vec<f64>) -> QuantumResult<Self> {
+        if ratios.is_empty() {
+            return Err(QuantumError::OperationError {
+                message: "A harmonic set needs at least one ratio".to_string(),
+            });
+        }
+        if let Some(ratio) = ratios.iter().find(|r| !r.is_finite() || **r <= 0.0) {
+            return Err(QuantumError::OperationError {
+                message: format!("Harmonic ratios must be positive and finite, got {}", ratio),
+            });
+        }
+        Ok(Self { ratios })
+    }
+
+    /// The harmonics used by `phi_resonance`: ½, 1, φ and φ^φ
+    pub fn standard() -> Self {
+        Self { ratios: standard_harmonics::<f64>().to_vec() }
+    }
+
+    /// Integer harmonics 1, 2, ..., count
+    pub fn integer(count: usize) -> QuantumResult<Self> {
+        Self::new((1..=count).map(|k| k as f64).collect())
+    }
+
+    /// Phi powers φ^-count, ..., φ^0, ..., φ^count
+    pub fn phi_powers(count: u32) -> Self {
+        let count = count as i32;
+        Self { ratios: (-count..=count).map(|k| PHI.powi(k)).collect() }
+    }
+
+    /// Get the harmonic ratios
+    pub fn ratios(&self) -> &[f64] {
+        &self.ratios
+    }
+}
+
+impl Default for HarmonicSet {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Resonant frequency of a value tuned at a reference frequency
+pub fn resonant_frequency(value: f64, reference_frequency: f64) -> f64 {
+    resonant_frequency_of(value, reference_frequency)
+}
+
+/// Strength of each harmonic of a base frequency at a resonant frequency
+pub fn harmonic_strengths(resonant_frequency: f64, base_frequency: f64, harmonics: &HarmonicSet) -> Vec<f64> {
+    harmonics.ratios.iter()
+        .map(|h| harmonic_strength(resonant_frequency, base_frequency, *h))
+        .collect()
+}
+
+/// One base frequency of a resonance spectrum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectrumPoint {
+    /// Base frequency in Hz
+    pub frequency: f64,
+
+    /// Resonance, the strongest harmonic strength
+    pub resonance: f64,
+
+    /// Index of the strongest harmonic in the harmonic set
+    pub harmonic: usize,
+}
+
+/// A peak of a resonance spectrum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectrumPeak {
+    /// Index of the peak in the spectrum points
+    pub index: usize,
+
+    /// Base frequency of the peak in Hz
+    pub frequency: f64,
+
+    /// Resonance at the peak
+    pub resonance: f64,
+
+    /// Full width at half maximum in Hz, truncated at the sweep edges
+    pub width: f64,
+
+    /// Ratio of the harmonic that dominates the peak
+    pub harmonic: f64,
+
+    /// Index of that harmonic in the harmonic set
+    pub harmonic_index: usize,
+}
+
+/// Resonance curve of one value over a sweep of base frequencies
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResonanceSpectrum {
+    /// Resonant frequency of the analysed value
+    resonant_frequency: f64,
+
+    /// Curve samples, ascending in frequency
+    points: Vec<SpectrumPoint>,
+
+    /// Detected peaks, ascending in frequency
+    peaks: Vec<SpectrumPeak>,
+
+    /// Harmonics the curve was computed with
+    harmonics: HarmonicSet,
+}
+
+impl ResonanceSpectrum {
+    /// Get the resonant frequency of the analysed value
+    pub fn resonant_frequency(&self) -> f64 {
+        self.resonant_frequency
+    }
+
+    /// Get the curve samples
+    pub fn points(&self) -> &[SpectrumPoint] {
+        &self.points
+    }
+
+    /// Get the detected peaks
+    pub fn peaks(&self) -> &[SpectrumPeak] {
+        &self.peaks
+    }
+
+    /// Get the harmonic set
+    pub fn harmonics(&self) -> &HarmonicSet {
+        &self.harmonics
+    }
+
+    /// Get the curve as (frequency, resonance) pairs
+    pub fn curve(&self) -> Vec<(f64, f64)> {
+        self.points.iter().map(|p| (p.frequency, p.resonance)).collect()
+    }
+
+    /// Get the strongest peak
+    pub fn dominant_peak(&self) -> Option<&SpectrumPeak> {
+        self.peaks.iter().max_by(|a, b| a.resonance.total_cmp(&b.resonance))
+    }
+}
+
+/// Sweep of base frequencies for resonance analysis
+#[derive(Debug, Clone)]
+pub struct SpectrumSweep {
+    /// Base frequencies, ascending
+    frequencies: Vec<f64>,
+
+    /// Harmonics to test
+    harmonics: HarmonicSet,
+
+    /// Minimum resonance of a peak
+    threshold: f64,
+}
+
+impl SpectrumSweep {
+    /// Sweep `steps` evenly spaced frequencies from `start` to `end` inclusive
+    pub fn linear(start: f64, end: f64, steps: usize) -> QuantumResult<Self> {
+        Self::check_range(start, end, steps)?;
+        let step = (end - start) / (steps - 1) as f64;
+        Ok(Self::with_frequencies((0..steps).map(|i| start + step * i as f64).collect()))
+    }
+
+    /// Sweep `steps` geometrically spaced frequencies from `start` to `end`
+    ///
+    /// Harmonics are ratios, so peaks have a constant relative width and
+    /// geometric spacing resolves them equally well across the range.
+    pub fn logarithmic(start: f64, end: f64, steps: usize) -> QuantumResult<Self> {
+        Self::check_range(start, end, steps)?;
+        let ratio = (end / start).powf(1.0 / (steps - 1) as f64);
+        Ok(Self::with_frequencies((0..steps).map(|i| start * ratio.powi(i as i32)).collect()))
+    }
+
+    /// Sweep the sacred frequencies
+    pub fn sacred() -> Self {
+        Self::with_frequencies(Frequency::ALL.iter().map(Frequency::value).collect())
+    }
+
+    /// Use a different harmonic set
+    pub fn with_harmonics(mut self, harmonics: HarmonicSet) -> Self {
+        self.harmonics = harmonics;
+        self
+    }
+
+    /// Set the minimum resonance for a local maximum to count as a peak
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Get the base frequencies
+    pub fn frequencies(&self) -> &[f64] {
+        &self.frequencies
+    }
+
+    /// Get the harmonic set
+    pub fn harmonics(&self) -> &HarmonicSet {
+        &self.harmonics
+    }
+
+    /// Analyse a value tuned at a reference frequency
+    pub fn analyze(&self, value: f64, reference_frequency: f64) -> ResonanceSpectrum {
+        self.analyze_frequency(resonant_frequency(value, reference_frequency))
+    }
+
+    /// Analyse a resonant frequency directly
+    pub fn analyze_frequency(&self, resonant_frequency: f64) -> ResonanceSpectrum {
+        let points: Vec<SpectrumPoint> = self.frequencies.iter()
+            .map(|&frequency| {
+                let strengths = harmonic_strengths(resonant_frequency, frequency, &self.harmonics);
+                let (harmonic, resonance) = strengths.iter().copied().enumerate()
+                    .fold((0, 0.0), |best, (i, s)| if s > best.1 { (i, s) } else { best });
+                SpectrumPoint { frequency, resonance, harmonic }
+            })
+            .collect();
+
+        let peaks = self.find_peaks(&points);
+
+        ResonanceSpectrum {
+            resonant_frequency,
+            points,
+            peaks,
+            harmonics: self.harmonics.clone(),
+        }
+    }
+
+    /// Sweep explicit ascending frequencies with the default settings
+    fn with_frequencies(frequencies: Vec<f64>) -> Self {
+        Self {
+            frequencies,
+            harmonics: HarmonicSet::standard(),
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Validate the bounds of a generated sweep
+    fn check_range(start: f64, end: f64, steps: usize) -> QuantumResult<()> {
+        if !(start > 0.0 && end > start && end.is_finite()) || steps < 2 {
+            return Err(QuantumError::OperationError {
+                message: format!(
+                    "A sweep needs 0 < start < end and at least 2 steps, got {}..{} in {} steps",
+                    start, end, steps
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Local maxima at or above the threshold, with their half-maximum widths
+    ///
+    /// Sweep endpoints count when the curve falls away from them, so a peak
+    /// lying beyond the range shows up at the nearest edge.
+    fn find_peaks(&self, points: &[SpectrumPoint]) -> Vec<SpectrumPeak> {
+        let last = points.len().saturating_sub(1);
+        (0..points.len())
+            .filter(|&i| {
+                let r = points[i].resonance;
+                r >= self.threshold
+                    && (i == 0 || r > points[i - 1].resonance)
+                    && (i == last || r >= points[i + 1].resonance)
+            })
+            .map(|i| {
+                let point = points[i];
+                SpectrumPeak {
+                    index: i,
+                    frequency: point.frequency,
+                    resonance: point.resonance,
+                    width: half_maximum_width(points, i),
+                    harmonic: self.harmonics.ratios[point.harmonic],
+                    harmonic_index: point.harmonic,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Width of a peak between the interpolated half-maximum crossings
+fn half_maximum_width(points: &[SpectrumPoint], peak: usize) -> f64 {
+    let half = points[peak].resonance / 2.0;
+    let crossing = |inside: &SpectrumPoint, outside: &SpectrumPoint| {
+        let t = (inside.resonance - half) / (inside.resonance - outside.resonance);
+        inside.frequency + t * (outside.frequency - inside.frequency)
+    };
+
+    let left = (0..peak).rev()
+        .find(|&j| points[j].resonance <= half)
+        .map_or(points[0].frequency, |j| crossing(&points[j + 1], &points[j]));
+    let right = (peak + 1..points.len())
+        .find(|&j| points[j].resonance <= half)
+        .map_or(points[points.len() - 1].frequency, |j| crossing(&points[j - 1], &points[j]));
+
+    right - left
+}
@@ -0,0 +1,105 @@
+//! Unit tests for the spectrum module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{Frequency, PHI, PHI_PHI};
+    use crate::quantum_field::phi_harmonic::phi_resonance;
+    use crate::quantum_field::phi_harmonic::spectrum::{
+        harmonic_strengths, resonant_frequency, HarmonicSet, SpectrumSweep,
+    };
+
+    #[test]
+    fn test_standard_set_matches_phi_resonance() {
+        let harmonics = HarmonicSet::standard();
+        for value in [0.3, 1.0, PHI, 2.5, 7.0] {
+            let strengths = harmonic_strengths(resonant_frequency(value, 432.0), 432.0, &harmonics);
+            let strongest = strengths.iter().copied().fold(0.0, f64::max);
+            assert!((strongest - phi_resonance(value, 432.0)).abs() < 1e-12);
+        }
+
+        // The swept curve passes through phi_resonance at the reference
+        let spectrum = SpectrumSweep::linear(100.0, 1000.0, 901).unwrap().analyze(2.5, 432.0);
+        let at_reference = spectrum.points().iter().find(|p| p.frequency == 432.0).unwrap();
+        assert!((at_reference.resonance - phi_resonance(2.5, 432.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_peaks_and_dominant_harmonics() {
+        // ln e = 1, so e tuned at 432 Hz resonates at 432·φ
+        let value = std::f64::consts::E;
+        let f_v = resonant_frequency(value, 432.0);
+        assert!((f_v - 432.0 * PHI).abs() < 1e-9);
+
+        let spectrum = SpectrumSweep::linear(100.0, 1000.0, 9001).unwrap().analyze(value, 432.0);
+        let peaks = spectrum.peaks();
+        assert_eq!(peaks.len(), 3);
+
+        // Harmonic φ of 432 Hz and the fundamental of f_v itself
+        assert!((peaks[0].frequency - 432.0).abs() < 0.1);
+        assert_eq!(peaks[0].harmonic, PHI);
+        assert!((peaks[1].frequency - f_v).abs() < 0.1);
+        assert_eq!(peaks[1].harmonic, 1.0);
+        assert!(peaks[..2].iter().all(|p| p.resonance > 0.99));
+        assert_eq!(spectrum.points()[peaks[1].index].harmonic, peaks[1].harmonic_index);
+
+        // Harmonic ½ peaks at 2·f_v, beyond the sweep, so it shows at the edge
+        assert_eq!(peaks[2].frequency, 1000.0);
+        assert_eq!(peaks[2].harmonic, 0.5);
+
+        // Half maximum where |f_v/f - h| = ln 2 / 10
+        let c = 2f64.ln() / 10.0;
+        let expected = f_v / (1.0 - c) - f_v / (1.0 + c);
+        assert!((peaks[1].width - expected).abs() < 0.5);
+
+        let dominant = spectrum.dominant_peak().unwrap();
+        assert!(peaks.iter().all(|p| dominant.resonance >= p.resonance));
+        assert_eq!(spectrum.curve().len(), 9001);
+    }
+
+    #[test]
+    fn test_custom_harmonics() {
+        // 300 Hz against integer harmonics peaks at 300, 150 and 100 Hz
+        let sweep = SpectrumSweep::logarithmic(80.0, 400.0, 4001).unwrap()
+            .with_harmonics(HarmonicSet::integer(3).unwrap());
+        let spectrum = sweep.analyze_frequency(300.0);
+
+        let found: Vec<(f64, f64)> = spectrum.peaks().iter().map(|p| (p.frequency, p.harmonic)).collect();
+        assert_eq!(found.len(), 3);
+        for ((frequency, harmonic), (expected, ratio)) in found.iter().zip([(100.0, 3.0), (150.0, 2.0), (300.0, 1.0)]) {
+            assert!((frequency - expected).abs() / expected < 1e-3);
+            assert_eq!(*harmonic, ratio);
+        }
+
+        // A higher threshold drops weak peaks, and phi powers are symmetric
+        assert!(sweep.with_threshold(1.1).analyze_frequency(300.0).peaks().is_empty());
+        let powers = HarmonicSet::phi_powers(2);
+        assert_eq!(powers.ratios().len(), 5);
+        assert!((powers.ratios()[0] * powers.ratios()[4] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sacred_sweep() {
+        let sweep = SpectrumSweep::sacred();
+        let expected: Vec<f64> = Frequency::ALL.iter().map(Frequency::value).collect();
+        assert_eq!(sweep.frequencies(), expected.as_slice());
+
+        // A value resonating at 528·φ^φ peaks on the Love frequency
+        let spectrum = sweep.analyze_frequency(528.0 * PHI_PHI);
+        let dominant = spectrum.dominant_peak().unwrap();
+        assert_eq!(dominant.frequency, Frequency::Love.value());
+        assert_eq!(dominant.harmonic, PHI_PHI);
+        assert!(dominant.width > 0.0);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(HarmonicSet::new(vec![]).is_err());
+        assert!(HarmonicSet::new(vec![1.0, -2.0]).is_err());
+        assert!(HarmonicSet::new(vec![f64::NAN]).is_err());
+        assert!(SpectrumSweep::linear(0.0, 100.0, 10).is_err());
+        assert!(SpectrumSweep::linear(200.0, 100.0, 10).is_err());
+        assert!(SpectrumSweep::logarithmic(100.0, 200.0, 1).is_err());
+        assert_eq!(HarmonicSet::default(), HarmonicSet::standard());
+    }
+}
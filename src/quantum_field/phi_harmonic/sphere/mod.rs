@@ -0,0 +1,217 @@
+//! Fibonacci Sphere and 3D Phyllotaxis
+//!
+//! This module extends the planar phi spiral to three dimensions. The
+//! Fibonacci lattice spaces points evenly over a sphere, hemisphere or
+//! spherical cap by stepping the azimuth by the golden angle while the height
+//! advances in equal-area slices. Phyllotactic shells stack such lattices with
+//! a configurable divergence angle, scale and radial growth law. Point sets
+//! export to CSV and ASCII PLY.
+
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::constants::GOLDEN_ANGLE;
+use crate::error::{QuantumError, QuantumResult};
+
+/// A point in 3D space as (x, y, z)
+pub type Point3 = (f64, f64, f64);
+
+/// Evenly distributed points on a sphere centred at the origin
+pub fn fibonacci_sphere(count: usize, radius: f64) -> Vec<Point3> {
+    lattice(count, radius, -1.0, GOLDEN_ANGLE, 0.0)
+}
+
+/// Evenly distributed points on the upper (z ≥ 0) hemisphere
+pub fn fibonacci_hemisphere(count: usize, radius: f64) -> Vec<Point3> {
+    lattice(count, radius, 0.0, GOLDEN_ANGLE, 0.0)
+}
+
+/// Evenly distributed points on the cap within `polar_angle` of the +z axis
+pub fn fibonacci_cap(count: usize, radius: f64, polar_angle: f64) -> QuantumResult<Vec<Point3>> {
+    if polar_angle.is_nan() || polar_angle <= 0.0 || polar_angle > PI {
+        return Err(QuantumError::OperationError {
+            message: format!("Cap polar angle must be in (0, π], got {}", polar_angle),
+        });
+    }
+
+    Ok(lattice(count, radius, polar_angle.cos(), GOLDEN_ANGLE, 0.0))
+}
+
+/// Fibonacci lattice between height `min_z`·radius and the north pole
+///
+/// Each point sits at the centre of an equal-area band, so the lattice is
+/// uniform on any cap; `twist` rotates the whole lattice about z.
+fn lattice(count: usize, radius: f64, min_z: f64, divergence: f64, twist: f64) -> Vec<Point3> {
+    let span = 1.0 - min_z;
+
+    (0..count)
+        .map(|i| {
+            let z = 1.0 - span * (i as f64 + 0.5) / count as f64;
+            let ring = (1.0 - z * z).max(0.0).sqrt();
+            let (sin, cos) = (twist + i as f64 * divergence).sin_cos();
+            (radius * ring * cos, radius * ring * sin, radius * z)
+        })
+        .collect()
+}
+
+/// How the radius of phyllotactic points or shells grows with their index
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GrowthLaw {
+    /// Constant radius, giving a single sphere
+    Constant,
+
+    /// Radius ∝ √n, Vogel's model with constant area per point
+    Sqrt,
+
+    /// Radius ∝ n
+    Linear,
+
+    /// Radius ∝ rateⁿ, e.g. φ for logarithmic spirals
+    Exponential(f64),
+}
+
+impl GrowthLaw {
+    /// Get the relative radius for index n (1 for n = 1)
+    pub fn radius(&self, n: usize) -> f64 {
+        let n = n as f64;
+        match self {
+            GrowthLaw::Constant => 1.0,
+            GrowthLaw::Sqrt => n.sqrt(),
+            GrowthLaw::Linear => n,
+            GrowthLaw::Exponential(rate) => rate.powf(n - 1.0),
+        }
+    }
+}
+
+/// Builder for 3D phyllotactic point sets
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Phyllotaxis {
+    /// Azimuth step between successive points, in radians
+    divergence: f64,
+
+    /// Radius scale
+    scale: f64,
+
+    /// Radial growth law
+    growth: GrowthLaw,
+}
+
+impl Default for Phyllotaxis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Phyllotaxis {
+    /// Create a builder with the golden divergence angle, unit scale and √n growth
+    pub fn new() -> Self {
+        Self {
+            divergence: GOLDEN_ANGLE,
+            scale: 1.0,
+            growth: GrowthLaw::Sqrt,
+        }
+    }
+
+    /// Set the divergence angle in radians
+    pub fn with_divergence(mut self, divergence: f64) -> Self {
+        self.divergence = divergence;
+        self
+    }
+
+    /// Set the divergence angle in degrees (137.5° for most plants)
+    pub fn with_divergence_degrees(self, degrees: f64) -> Self {
+        self.with_divergence(degrees.to_radians())
+    }
+
+    /// Set the radius scale
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the radial growth law
+    pub fn with_growth(mut self, growth: GrowthLaw) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    /// Get the divergence angle in radians
+    pub fn divergence(&self) -> f64 {
+        self.divergence
+    }
+
+    /// Get the growth law
+    pub fn growth(&self) -> GrowthLaw {
+        self.growth
+    }
+
+    /// A single phyllotactic spiral of `count` points
+    ///
+    /// Point n (from 1) has azimuth n·divergence, an equal-area polar angle
+    /// from the +z axis, and distance scale·growth(n) from the origin.
+    pub fn points(&self, count: usize) -> Vec<Point3> {
+        lattice(count, 1.0, -1.0, self.divergence, self.divergence)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y, z))| {
+                let r = self.scale * self.growth.radius(i + 1);
+                (r * x, r * y, r * z)
+            })
+            .collect()
+    }
+
+    /// Concentric shells of `points_per_shell` points each
+    ///
+    /// Shell k (from 1) has radius scale·growth(k) and is twisted by
+    /// k·divergence so that neighbouring shells interleave.
+    pub fn shells(&self, points_per_shell: usize, shell_count: usize) -> Vec<Vec<Point3>> {
+        (1..=shell_count)
+            .map(|k| {
+                let radius = self.scale * self.growth.radius(k);
+                lattice(points_per_shell, radius, -1.0, self.divergence, k as f64 * self.divergence)
+            })
+            .collect()
+    }
+}
+
+/// Render points as CSV with an `x,y,z` header
+pub fn to_csv(points: &[Point3]) -> String {
+    let mut csv = String::from("x,y,z\n");
+    for (x, y, z) in points {
+        let _ = writeln!(csv, "{},{},{}", x, y, z);
+    }
+    csv
+}
+
+/// Render points as an ASCII PLY vertex cloud
+pub fn to_ply(points: &[Point3]) -> String {
+    let mut ply = String::new();
+    let _ = writeln!(ply, "ply\nformat ascii 1.0\ncomment phi-harmonic point set");
+    let _ = writeln!(ply, "element vertex {}", points.len());
+    let _ = writeln!(ply, "property double x\nproperty double y\nproperty double z\nend_header");
+    for (x, y, z) in points {
+        let _ = writeln!(ply, "{} {} {}", x, y, z);
+    }
+    ply
+}
+
+/// Write points to a CSV file
+pub fn save_csv(points: &[Point3], path: &Path) -> QuantumResult<()> {
+    write_file(path, to_csv(points))
+}
+
+/// Write points to an ASCII PLY file
+pub fn save_ply(points: &[Point3], path: &Path) -> QuantumResult<()> {
+    write_file(path, to_ply(points))
+}
+
+/// Write text to a file, mapping I/O errors
+fn write_file(path: &Path, contents: String) -> QuantumResult<()> {
+    std::fs::write(path, contents).map_err(|e| QuantumError::OperationError {
+        message: format!("Failed to write {}: {}", path.display(), e),
+    })
+}
@@ -0,0 +1,103 @@
+//! Unit tests for the sphere module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_field::phi_harmonic::sphere::{
+        fibonacci_cap, fibonacci_hemisphere, fibonacci_sphere, to_csv, to_ply, GrowthLaw, Phyllotaxis, Point3,
+    };
+    use std::f64::consts::PI;
+
+    fn norm((x, y, z): Point3) -> f64 {
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    fn distance(a: Point3, b: Point3) -> f64 {
+        norm((a.0 - b.0, a.1 - b.1, a.2 - b.2))
+    }
+
+    #[test]
+    fn test_fibonacci_sphere_is_even() {
+        let points = fibonacci_sphere(500, 2.0);
+        assert_eq!(points.len(), 500);
+        assert!(points.iter().all(|p| (norm(*p) - 2.0).abs() < 1e-12));
+
+        // Centroid near the origin and no clumping: nearest neighbours stay apart
+        let n = points.len() as f64;
+        let centroid = points.iter().fold((0.0, 0.0, 0.0), |c, p| (c.0 + p.0 / n, c.1 + p.1 / n, c.2 + p.2 / n));
+        assert!(norm(centroid) < 1e-2);
+
+        let spacing = (4.0 * PI * 4.0 / n).sqrt();
+        for (i, a) in points.iter().enumerate() {
+            let nearest = points.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, b)| distance(*a, *b))
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest > 0.5 * spacing);
+        }
+    }
+
+    #[test]
+    fn test_hemisphere_and_cap_stay_in_range() {
+        let hemisphere = fibonacci_hemisphere(200, 1.0);
+        assert!(hemisphere.iter().all(|p| p.2 >= 0.0));
+
+        // Points sit at equal-area heights, so half of them are above the mean height
+        let above = hemisphere.iter().filter(|p| p.2 > 0.5).count();
+        assert_eq!(above, 100);
+
+        let cap = fibonacci_cap(100, 1.0, PI / 6.0).unwrap();
+        let limit = (PI / 6.0).cos();
+        assert!(cap.iter().all(|p| p.2 >= limit && (norm(*p) - 1.0).abs() < 1e-12));
+
+        assert!(fibonacci_cap(10, 1.0, 0.0).is_err());
+        assert!(fibonacci_cap(10, 1.0, 4.0).is_err());
+    }
+
+    #[test]
+    fn test_phyllotaxis_growth_laws() {
+        assert_eq!(GrowthLaw::Constant.radius(7), 1.0);
+        assert_eq!(GrowthLaw::Sqrt.radius(9), 3.0);
+        assert_eq!(GrowthLaw::Linear.radius(4), 4.0);
+        assert!((GrowthLaw::Exponential(2.0).radius(3) - 4.0).abs() < 1e-12);
+
+        let points = Phyllotaxis::new().with_scale(0.5).points(50);
+        for (i, p) in points.iter().enumerate() {
+            assert!((norm(*p) - 0.5 * ((i + 1) as f64).sqrt()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_phyllotaxis_divergence_and_shells() {
+        let builder = Phyllotaxis::new().with_divergence_degrees(137.5).with_growth(GrowthLaw::Linear);
+        assert!((builder.divergence() - 137.5f64.to_radians()).abs() < 1e-15);
+
+        // Successive azimuths differ by the divergence angle
+        let points = builder.points(10);
+        let azimuth = |p: &Point3| p.1.atan2(p.0);
+        let step = (azimuth(&points[3]) - azimuth(&points[2])).rem_euclid(2.0 * PI);
+        assert!((step - 137.5f64.to_radians()).abs() < 1e-9);
+
+        let shells = builder.with_scale(2.0).shells(30, 4);
+        assert_eq!(shells.len(), 4);
+        for (k, shell) in shells.iter().enumerate() {
+            assert_eq!(shell.len(), 30);
+            assert!(shell.iter().all(|p| (norm(*p) - 2.0 * (k + 1) as f64).abs() < 1e-9));
+        }
+        assert!((azimuth(&shells[1][0]) - azimuth(&shells[0][0])).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_csv_and_ply_export() {
+        let points = vec![(0.0, 1.0, 2.0), (-1.5, 0.25, 3.0)];
+
+        let csv = to_csv(&points);
+        assert_eq!(csv, "x,y,z\n0,1,2\n-1.5,0.25,3\n");
+
+        let ply = to_ply(&points);
+        assert!(ply.starts_with("ply\nformat ascii 1.0\n"));
+        assert!(ply.contains("element vertex 2\n"));
+        assert!(ply.ends_with("end_header\n0 1 2\n-1.5 0.25 3\n"));
+    }
+}
@@ -1,14 +1,16 @@
 //! Unit tests for the phi-harmonic module
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::quantum_field::phi_harmonic::{
         phi_scale, lambda_scale, phi_sequence, phi_ratio, phi_optimize,
         phi_spiral_point, phi_spiral, phi_resonance, phi_grid, phi_compress,
-        phi_harmonic_optimize, PhiHarmonicValues
+        phi_harmonic_optimize, phi_sequence_of, phi_grid_of, PhiFloat, PhiHarmonicValues
     };
-    use crate::constants::{PHI, LAMBDA};
-    use std::f64::consts::PI;
+    use crate::quantum_field::phi_harmonic::compression::CompressionMode;
+    use crate::quantum_field::phi_harmonic::wavelet::ThresholdMode;
+    use crate::constants::{PHI, LAMBDA, PHI_PHI};
 
     #[test]
     fn test_phi_scale() {
@@ -64,7 +66,7 @@ mod tests {
 
     #[test]
     fn test_phi_optimize() {
-        let value = 2.0;
+        let value: f64 = 2.0;
         let target = 5.0;
         let optimized = phi_optimize(value, target, 10);
         
@@ -115,6 +117,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_phi_grid() {
         let grid = phi_grid(3, 2);
         
@@ -179,4 +182,158 @@ mod tests {
         // Compression should reduce size
         assert!(compressed.values().len() < values.len());
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_phi_harmonic_values_archive() {
+        let values = vec![1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0];
+        let harmonic = PhiHarmonicValues::new(values.clone(), 432.0);
+        
+        // Encoding keeps the container intact and restores exactly
+        let archive = harmonic.encode(0.5, CompressionMode::Lossless).unwrap();
+        assert_eq!(harmonic.values(), &values);
+        
+        let restored = PhiHarmonicValues::from_compressed(&archive, 432.0).unwrap();
+        assert_eq!(restored.values(), &values);
+        assert_eq!(restored.phi_resonance(), harmonic.phi_resonance());
+    }
+    
+    #[test]
+    fn test_phi_harmonic_values_denoise() {
+        let clean: Vec<f64> = (0..200).map(|i| (i as f64 * 0.03).sin()).collect();
+        let noisy: Vec<f64> = clean.iter().enumerate()
+            .map(|(i, v)| v + 0.05 * (i as f64 * PHI * 1000.0).sin())
+            .collect();
+        
+        let mut harmonic = PhiHarmonicValues::new(noisy.clone(), 432.0);
+        harmonic.denoise(4, ThresholdMode::Soft).unwrap();
+        
+        let error = |values: &[f64]| values.iter().zip(&clean).map(|(a, b)| (a - b).powi(2)).sum::<f64>();
+        assert_eq!(harmonic.values().len(), noisy.len());
+        assert!(error(harmonic.values()) < error(&noisy) / 2.0);
+    }
+
+    #[test]
+    fn test_phi_harmonic_values_incremental() {
+        let mean = |values: &[f64]| values.iter().map(|v| phi_resonance(*v, 432.0)).sum::<f64>() / values.len() as f64;
+
+        // Empty containers have a well-defined resonance
+        let mut harmonic = PhiHarmonicValues::new(Vec::new(), 432.0);
+        assert!(harmonic.is_empty());
+        assert_eq!(harmonic.phi_resonance(), 0.0);
+        assert_eq!(harmonic.pop(), None);
+
+        harmonic.extend([1.0, PHI, 3.0, 5.0]);
+        assert_eq!(harmonic.len(), 4);
+        assert!((harmonic.phi_resonance() - mean(&[1.0, PHI, 3.0, 5.0])).abs() < 1e-12);
+
+        assert_eq!(harmonic.replace(2, 8.0), Some(3.0));
+        assert!((harmonic.phi_resonance() - mean(&[1.0, PHI, 8.0, 5.0])).abs() < 1e-12);
+
+        assert_eq!(harmonic.remove(0), Some(1.0));
+        assert_eq!(harmonic.remove(7), None);
+        assert_eq!(harmonic.pop(), Some(5.0));
+        assert_eq!(harmonic.values(), &[PHI, 8.0]);
+        assert!((harmonic.phi_resonance() - mean(&[PHI, 8.0])).abs() < 1e-12);
+
+        harmonic.pop();
+        harmonic.pop();
+        assert_eq!(harmonic.phi_resonance(), 0.0);
+    }
+
+    #[test]
+    fn test_phi_harmonic_values_window() {
+        let mut harmonic = PhiHarmonicValues::new(vec![1.0, 2.0, 3.0, 5.0], 432.0).with_window(3);
+        assert_eq!(harmonic.values(), &[2.0, 3.0, 5.0]);
+        assert_eq!(harmonic.window(), Some(3));
+
+        // A long stream keeps only the newest values and an exact resonance
+        for i in 0..100 {
+            let evicted = harmonic.push(i as f64);
+            assert!(evicted.is_some());
+        }
+        assert_eq!(harmonic.values(), &[97.0, 98.0, 99.0]);
+        let expected = PhiHarmonicValues::new(vec![97.0, 98.0, 99.0], 432.0);
+        assert!((harmonic.phi_resonance() - expected.phi_resonance()).abs() < 1e-12);
+
+        assert_eq!(harmonic.pop_front(), Some(97.0));
+        assert_eq!(harmonic.get(0), Some(&98.0));
+
+        // Millions of f32 updates through a small window do not drift
+        let mut stream = PhiHarmonicValues::new(Vec::new(), 432.0f32).with_window(64);
+        let sample = |i: u32| (i % 977) as f32 * 0.37 + 0.1;
+        for i in 0..2_000_000 {
+            stream.push(sample(i));
+        }
+        let newest: Vec<f32> = (2_000_000 - 64..2_000_000).map(sample).collect();
+        assert_eq!(stream.values(), newest.as_slice());
+        let expected = PhiHarmonicValues::new(newest, 432.0f32).phi_resonance();
+        assert!(((stream.phi_resonance() - expected) / expected).abs() < 1e-5);
+
+        let mut closed = PhiHarmonicValues::new(Vec::new(), 432.0).with_window(0);
+        assert_eq!(closed.push(1.0), Some(1.0));
+        assert!(closed.is_empty());
+    }
+
+    #[test]
+    fn test_phi_harmonic_values_iterators() {
+        let harmonic: PhiHarmonicValues = phi_sequence(6).into_iter().collect();
+        assert_eq!(harmonic.base_frequency(), 432.0);
+        assert_eq!(harmonic.len(), 6);
+
+        let retuned = harmonic.clone().with_base_frequency(528.0);
+        assert_eq!(retuned.phi_resonance(), PhiHarmonicValues::new(phi_sequence(6), 528.0).phi_resonance());
+
+        let borrowed: Vec<f64> = (&harmonic).into_iter().copied().collect();
+        assert_eq!(borrowed, harmonic.iter().copied().collect::<Vec<_>>());
+
+        let mut windowed = PhiHarmonicValues::default().with_window(4);
+        windowed.extend(harmonic.values());
+        let owned: Vec<f64> = windowed.into_iter().collect();
+        assert_eq!(owned, &harmonic.values()[2..]);
+    }
+
+    #[test]
+    fn test_generic_constants() {
+        assert_eq!(<f64 as PhiFloat>::PHI, PHI);
+        assert_eq!(<f64 as PhiFloat>::LAMBDA, LAMBDA);
+        assert_eq!(<f64 as PhiFloat>::PHI_PHI, PHI_PHI);
+        assert_eq!(<f32 as PhiFloat>::PHI, PHI as f32);
+        assert!((<f32 as PhiFloat>::PHI * <f32 as PhiFloat>::LAMBDA - 1.0).abs() < 1e-6);
+        assert_eq!(<f32 as PhiFloat>::PHI_PHI, PHI_PHI as f32);
+    }
+    
+    #[test]
+    fn test_f32_matches_f64() {
+        let sequence32 = phi_sequence_of::<f32>(20);
+        let sequence64 = phi_sequence(20);
+        assert_eq!(phi_sequence_of::<f64>(20), sequence64);
+        for (a, b) in sequence32.iter().zip(&sequence64) {
+            assert!(((*a as f64 - b) / b).abs() < 1e-6);
+        }
+        
+        assert!((phi_scale(2.0f32, 0.5) - 2.0 * PHI as f32 * 0.5).abs() < 1e-6);
+        assert!((phi_optimize(2.0f32, 5.0, 10) as f64 - phi_optimize(2.0, 5.0, 10)).abs() < 1e-5);
+        assert!((phi_resonance(PHI as f32, 432.0f32) as f64 - phi_resonance(PHI, 432.0)).abs() < 1e-4);
+        
+        let (x32, y32) = phi_spiral_point(7, 1.5f32);
+        let (x64, y64) = phi_spiral_point(7, 1.5f64);
+        assert!((x32 as f64 - x64).abs() < 1e-4 && (y32 as f64 - y64).abs() < 1e-4);
+        
+        let grid = phi_grid_of::<f32>(4, 3);
+        let reference = phi_grid(4, 3);
+        for (row32, row64) in grid.iter().zip(&reference) {
+            for (a, b) in row32.iter().zip(row64) {
+                assert!((*a as f64 - b).abs() < 1e-6);
+            }
+        }
+        
+        let values: Vec<f32> = (1..=13).map(|v| v as f32).collect();
+        let wide: Vec<f64> = (1..=13).map(|v| v as f64).collect();
+        let compressed = phi_compress(&values, 0.5f32);
+        assert_eq!(compressed.len(), phi_compress(&wide, 0.5).len());
+        
+        let mut harmonic = PhiHarmonicValues::new(values, 432.0f32);
+        harmonic.optimize(6.0, 5);
+        assert!(harmonic.phi_resonance() > 0.0);
+    }
+}
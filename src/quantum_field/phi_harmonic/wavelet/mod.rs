@@ -0,0 +1,221 @@
+//! Phi Multiresolution Wavelet Transform
+//!
+//! This module implements a lifting wavelet whose scales follow the Fibonacci
+//! lattice. Each level splits the signal along the Fibonacci word: sample i is
+//! coarse when ⌊(i+2)λ⌋ - ⌊(i+1)λ⌋ = 1, which keeps a λ fraction of the
+//! samples, so successive levels shrink by φ rather than 2. Detail samples
+//! never touch each other, so each one is predicted from its coarse
+//! neighbours and the coarse samples are then updated with the details.
+//! Lifting steps are inverted exactly, giving perfect reconstruction.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::LAMBDA;
+use crate::error::{QuantumError, QuantumResult};
+
+/// How detail coefficients are shrunk by thresholding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdMode {
+    /// Zero coefficients below the threshold, keep the rest
+    Hard,
+
+    /// Zero coefficients below the threshold, shrink the rest toward zero
+    Soft,
+}
+
+/// Multiresolution decomposition of a signal
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveletDecomposition {
+    /// Coarsest approximation
+    approximation: Vec<f64>,
+
+    /// Detail coefficients per level, finest first
+    details: Vec<Vec<f64>>,
+
+    /// Signal length entering each level, finest first
+    lengths: Vec<usize>,
+}
+
+impl WaveletDecomposition {
+    /// Get the number of levels
+    pub fn levels(&self) -> usize {
+        self.details.len()
+    }
+
+    /// Get the length of the original signal
+    pub fn original_len(&self) -> usize {
+        self.lengths.first().copied().unwrap_or(self.approximation.len())
+    }
+
+    /// Get the coarsest approximation
+    pub fn approximation(&self) -> &[f64] {
+        &self.approximation
+    }
+
+    /// Get the detail coefficients of a level (0 is the finest)
+    pub fn detail(&self, level: usize) -> Option<&[f64]> {
+        self.details.get(level).map(Vec::as_slice)
+    }
+
+    /// Get the detail coefficients of a level mutably
+    pub fn detail_mut(&mut self, level: usize) -> Option<&mut [f64]> {
+        self.details.get_mut(level).map(Vec::as_mut_slice)
+    }
+
+    /// Get the energy (sum of squares) of each detail level, finest first
+    pub fn detail_energies(&self) -> Vec<f64> {
+        self.details.iter().map(|d| d.iter().map(|c| c * c).sum()).collect()
+    }
+
+    /// Threshold all detail coefficients
+    pub fn threshold(&mut self, threshold: f64, mode: ThresholdMode) {
+        for coefficient in self.details.iter_mut().flatten() {
+            *coefficient = shrink(*coefficient, threshold, mode);
+        }
+    }
+
+    /// Universal threshold σ·√(2 ln n) for denoising
+    ///
+    /// The noise level σ is estimated from the median absolute finest-level
+    /// detail divided by 0.6745, which is robust to the signal itself.
+    pub fn universal_threshold(&self) -> f64 {
+        let finest = match self.details.first() {
+            Some(finest) if !finest.is_empty() => finest,
+            _ => return 0.0,
+        };
+
+        let mut magnitudes: Vec<f64> = finest.iter().map(|c| c.abs()).collect();
+        magnitudes.sort_by(f64::total_cmp);
+        let sigma = magnitudes[magnitudes.len() / 2] / 0.6745;
+        sigma * (2.0 * (self.original_len().max(2) as f64).ln()).sqrt()
+    }
+
+    /// Reconstruct the signal
+    pub fn inverse(&self) -> Vec<f64> {
+        let mut signal = self.approximation.clone();
+        for (detail, length) in self.details.iter().zip(&self.lengths).rev() {
+            signal = merge(&signal, detail, *length);
+        }
+        signal
+    }
+}
+
+/// Whether sample i of a level is coarse in the Fibonacci-word split
+fn is_coarse(i: usize) -> bool {
+    ((i + 2) as f64 * LAMBDA).floor() - ((i + 1) as f64 * LAMBDA).floor() == 1.0
+}
+
+/// Number of coarse samples a level of this length keeps
+fn coarse_len(length: usize) -> usize {
+    (0..length).filter(|i| is_coarse(*i)).count()
+}
+
+/// Largest number of levels for a signal length, stopping below two samples
+pub fn max_levels(length: usize) -> usize {
+    let mut levels = 0;
+    let mut length = length;
+    while length >= 2 {
+        length = coarse_len(length);
+        levels += 1;
+    }
+    levels
+}
+
+/// Forward transform with up to `levels` levels
+pub fn forward(signal: &[f64], levels: usize) -> QuantumResult<WaveletDecomposition> {
+    let available = max_levels(signal.len());
+    if levels > available {
+        return Err(QuantumError::OperationError {
+            message: format!("A signal of length {} supports at most {} levels, got {}", signal.len(), available, levels),
+        });
+    }
+
+    let mut approximation = signal.to_vec();
+    let mut details = Vec::with_capacity(levels);
+    let mut lengths = Vec::with_capacity(levels);
+
+    for _ in 0..levels {
+        lengths.push(approximation.len());
+        let (coarse, detail) = split(&approximation);
+        approximation = coarse;
+        details.push(detail);
+    }
+
+    Ok(WaveletDecomposition { approximation, details, lengths })
+}
+
+/// Denoise a signal by soft or hard thresholding at the universal threshold
+pub fn denoise(signal: &[f64], levels: usize, mode: ThresholdMode) -> QuantumResult<Vec<f64>> {
+    let mut decomposition = forward(signal, levels)?;
+    let threshold = decomposition.universal_threshold();
+    decomposition.threshold(threshold, mode);
+    Ok(decomposition.inverse())
+}
+
+/// One analysis step: predict details from coarse neighbours, then update
+fn split(signal: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut x = signal.to_vec();
+    let n = x.len();
+
+    // Predict: each detail minus the mean of its (always coarse) neighbours
+    for i in (0..n).filter(|i| !is_coarse(*i)) {
+        x[i] -= neighbour_mean(&x, i);
+    }
+
+    // Update: each coarse sample plus a quarter of its adjacent details
+    for j in (0..n).filter(|j| is_coarse(*j)) {
+        x[j] += adjacent_detail_sum(&x, j) / 4.0;
+    }
+
+    let coarse = (0..n).filter(|i| is_coarse(*i)).map(|i| x[i]).collect();
+    let detail = (0..n).filter(|i| !is_coarse(*i)).map(|i| x[i]).collect();
+    (coarse, detail)
+}
+
+/// One synthesis step, undoing the lifting steps of [`split`] in reverse
+fn merge(coarse: &[f64], detail: &[f64], length: usize) -> Vec<f64> {
+    let mut x = vec![0.0; length];
+    let (mut c, mut d) = (coarse.iter(), detail.iter());
+    for (i, slot) in x.iter_mut().enumerate() {
+        let source = if is_coarse(i) { c.next() } else { d.next() };
+        *slot = source.copied().unwrap_or(0.0);
+    }
+
+    for j in (0..length).filter(|j| is_coarse(*j)) {
+        x[j] -= adjacent_detail_sum(&x, j) / 4.0;
+    }
+    for i in (0..length).filter(|i| !is_coarse(*i)) {
+        x[i] += neighbour_mean(&x, i);
+    }
+    x
+}
+
+/// Mean of the coarse neighbours of detail sample i
+fn neighbour_mean(x: &[f64], i: usize) -> f64 {
+    let left = i.checked_sub(1).map(|k| x[k]);
+    let right = x.get(i + 1).copied();
+    match (left, right) {
+        (Some(a), Some(b)) => (a + b) / 2.0,
+        (Some(a), None) | (None, Some(a)) => a,
+        (None, None) => 0.0,
+    }
+}
+
+/// Sum of the detail samples adjacent to coarse sample j
+fn adjacent_detail_sum(x: &[f64], j: usize) -> f64 {
+    let left = j.checked_sub(1).filter(|k| !is_coarse(*k)).map_or(0.0, |k| x[k]);
+    let right = Some(j + 1).filter(|k| *k < x.len() && !is_coarse(*k)).map_or(0.0, |k| x[k]);
+    left + right
+}
+
+/// Apply a threshold to one coefficient
+fn shrink(coefficient: f64, threshold: f64, mode: ThresholdMode) -> f64 {
+    if coefficient.abs() <= threshold {
+        return 0.0;
+    }
+    match mode {
+        ThresholdMode::Hard => coefficient,
+        ThresholdMode::Soft => coefficient.signum() * (coefficient.abs() - threshold),
+    }
+}
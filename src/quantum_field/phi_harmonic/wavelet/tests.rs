@@ -0,0 +1,90 @@
+//! Unit tests for the wavelet module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::LAMBDA;
+    use crate::quantum_field::phi_harmonic::wavelet::{denoise, forward, max_levels, ThresholdMode};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_perfect_reconstruction() {
+        let mut rng = StdRng::seed_from_u64(21);
+        for length in [1usize, 2, 3, 13, 89, 100, 377, 1000] {
+            let signal: Vec<f64> = (0..length).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            for levels in 0..=max_levels(length) {
+                let decomposition = forward(&signal, levels).unwrap();
+                let restored = decomposition.inverse();
+
+                assert_eq!(restored.len(), length);
+                assert!(restored.iter().zip(&signal).all(|(a, b)| (a - b).abs() < 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn test_levels_shrink_by_phi() {
+        let decomposition = forward(&vec![1.0; 987], 5).unwrap();
+        assert_eq!(decomposition.levels(), 5);
+        assert_eq!(decomposition.original_len(), 987);
+
+        // Consecutive Fibonacci lengths split into the two previous ones
+        assert_eq!(decomposition.detail(0).unwrap().len(), 377);
+        assert_eq!(decomposition.detail(1).unwrap().len(), 233);
+        assert_eq!(decomposition.approximation().len(), 89);
+
+        let lengths: Vec<usize> = (0..5).map(|l| decomposition.detail(l).unwrap().len()).collect();
+        for pair in lengths.windows(2) {
+            assert!(((pair[1] as f64 / pair[0] as f64) - LAMBDA).abs() < 0.01);
+        }
+        assert!(decomposition.detail(5).is_none());
+        assert!(forward(&[1.0; 10], max_levels(10) + 1).is_err());
+    }
+
+    #[test]
+    fn test_smooth_signals_have_small_details() {
+        // Linear trends are predicted exactly away from the boundary
+        let ramp: Vec<f64> = (0..200).map(|i| 0.5 * i as f64 + 3.0).collect();
+        let decomposition = forward(&ramp, 1).unwrap();
+        let detail = decomposition.detail(0).unwrap();
+        assert!(detail[..detail.len() - 1].iter().all(|d| d.abs() < 1e-12));
+
+        // A constant signal keeps its value in the approximation
+        let flat = forward(&[2.5; 144], 4).unwrap();
+        assert!(flat.approximation().iter().all(|a| (a - 2.5).abs() < 1e-12));
+        assert!(flat.detail_energies().iter().all(|e| *e < 1e-20));
+    }
+
+    #[test]
+    fn test_thresholding_and_editing_coefficients() {
+        let signal: Vec<f64> = (0..100).map(|i| (i as f64 * 0.7).sin()).collect();
+        let mut decomposition = forward(&signal, 3).unwrap();
+
+        decomposition.threshold(f64::INFINITY, ThresholdMode::Hard);
+        assert!(decomposition.detail_energies().iter().all(|e| *e == 0.0));
+
+        let mut decomposition = forward(&signal, 3).unwrap();
+        let before = decomposition.detail(0).unwrap().to_vec();
+        decomposition.threshold(0.1, ThresholdMode::Soft);
+        for (after, before) in decomposition.detail(0).unwrap().iter().zip(&before) {
+            assert!(after.abs() <= (before.abs() - 0.1).max(0.0) + 1e-15);
+        }
+
+        decomposition.detail_mut(2).unwrap().fill(0.0);
+        assert_eq!(decomposition.detail_energies()[2], 0.0);
+    }
+
+    #[test]
+    fn test_denoising_reduces_error() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let clean: Vec<f64> = (0..610).map(|i| (i as f64 * 0.02).sin() + 0.5 * (i as f64 * 0.005).cos()).collect();
+        let noisy: Vec<f64> = clean.iter().map(|v| v + rng.gen_range(-0.1..0.1)).collect();
+
+        let error = |values: &[f64]| values.iter().zip(&clean).map(|(a, b)| (a - b).powi(2)).sum::<f64>();
+        for mode in [ThresholdMode::Soft, ThresholdMode::Hard] {
+            let denoised = denoise(&noisy, 5, mode).unwrap();
+            assert!(error(&denoised) < error(&noisy) / 2.0);
+        }
+    }
+}
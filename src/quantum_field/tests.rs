@@ -1,6 +1,7 @@
 //! Unit tests for the quantum field module
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::quantum_field::Field;
     use crate::constants::{ConsciousnessState, OPTIMAL_COHERENCE, Frequency};
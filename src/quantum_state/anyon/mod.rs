@@ -0,0 +1,394 @@
+//! Fibonacci Anyons
+//!
+//! This module provides the F (fusion basis change) and R (exchange)
+//! matrices of Fibonacci anyons, braids of three anyons encoding one qubit,
+//! and a Solovay-Kitaev compiler approximating arbitrary single-qubit
+//! unitaries by braid words.
+//!
+//! The qubit lives in the two-dimensional fusion space of three τ anyons.
+//! Exchanging anyons 1 and 2 acts as σ₁ = R; exchanging anyons 2 and 3 acts
+//! as σ₂ = F·R·F. Braid words are read left to right in time order.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashSet;
+use std::f64::consts::PI;
+
+use num::complex::Complex64;
+
+use crate::constants::LAMBDA;
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::gates::{c, dagger2, is_unitary2, matmul2, Gate, Matrix2};
+
+/// Grid used to deduplicate matrices while building the base net
+const NET_RESOLUTION: f64 = 1e-9;
+
+/// Fibonacci F matrix [[λ, √λ], [√λ, -λ]]
+///
+/// F is real, symmetric and its own inverse.
+pub fn f_matrix() -> Matrix2 {
+    let root = LAMBDA.sqrt();
+    [[c(LAMBDA, 0.0), c(root, 0.0)], [c(root, 0.0), c(-LAMBDA, 0.0)]]
+}
+
+/// Fibonacci R matrix diag(e^{-4πi/5}, e^{3πi/5})
+pub fn r_matrix() -> Matrix2 {
+    [
+        [Complex64::from_polar(1.0, -4.0 * PI / 5.0), c(0.0, 0.0)],
+        [c(0.0, 0.0), Complex64::from_polar(1.0, 3.0 * PI / 5.0)],
+    ]
+}
+
+/// Elementary exchanges of three anyons and their inverses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BraidGenerator {
+    /// Counter-clockwise exchange of anyons 1 and 2
+    Sigma1,
+
+    /// Clockwise exchange of anyons 1 and 2
+    Sigma1Inverse,
+
+    /// Counter-clockwise exchange of anyons 2 and 3
+    Sigma2,
+
+    /// Clockwise exchange of anyons 2 and 3
+    Sigma2Inverse,
+}
+
+impl BraidGenerator {
+    /// All generators
+    pub const ALL: [BraidGenerator; 4] = [
+        BraidGenerator::Sigma1,
+        BraidGenerator::Sigma1Inverse,
+        BraidGenerator::Sigma2,
+        BraidGenerator::Sigma2Inverse,
+    ];
+
+    /// Get the inverse generator
+    pub fn inverse(&self) -> BraidGenerator {
+        match self {
+            BraidGenerator::Sigma1 => BraidGenerator::Sigma1Inverse,
+            BraidGenerator::Sigma1Inverse => BraidGenerator::Sigma1,
+            BraidGenerator::Sigma2 => BraidGenerator::Sigma2Inverse,
+            BraidGenerator::Sigma2Inverse => BraidGenerator::Sigma2,
+        }
+    }
+
+    /// Get the matrix acting on the fusion space
+    pub fn matrix(&self) -> Matrix2 {
+        let r = r_matrix();
+        let f = f_matrix();
+        match self {
+            BraidGenerator::Sigma1 => r,
+            BraidGenerator::Sigma1Inverse => dagger2(&r),
+            BraidGenerator::Sigma2 => matmul2(&matmul2(&f, &r), &f),
+            BraidGenerator::Sigma2Inverse => matmul2(&matmul2(&f, &dagger2(&r)), &f),
+        }
+    }
+}
+
+/// Braid word over three anyons
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Braid {
+    /// Generators in time order
+    word: Vec<BraidGenerator>,
+}
+
+impl Braid {
+    /// Create the empty braid
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a braid from generators in time order, cancelling adjacent inverses
+    pub fn from_word(word: &[BraidGenerator]) -> Self {
+        let mut braid = Self::new();
+        for generator in word {
+            braid.push(*generator);
+        }
+        braid
+    }
+
+    /// Get the generators in time order
+    pub fn word(&self) -> &[BraidGenerator] {
+        &self.word
+    }
+
+    /// Get the number of exchanges
+    pub fn len(&self) -> usize {
+        self.word.len()
+    }
+
+    /// Check if the braid is trivial
+    pub fn is_empty(&self) -> bool {
+        self.word.is_empty()
+    }
+
+    /// Append a generator, cancelling it against an inverse at the end
+    pub fn push(&mut self, generator: BraidGenerator) {
+        if self.word.last() == Some(&generator.inverse()) {
+            self.word.pop();
+        } else {
+            self.word.push(generator);
+        }
+    }
+
+    /// Braid performing `self` and then `next`
+    pub fn then(&self, next: &Braid) -> Braid {
+        let mut braid = self.clone();
+        for generator in &next.word {
+            braid.push(*generator);
+        }
+        braid
+    }
+
+    /// Inverse braid
+    pub fn inverse(&self) -> Braid {
+        Braid {
+            word: self.word.iter().rev().map(|g| g.inverse()).collect(),
+        }
+    }
+
+    /// Unitary implemented by the braid
+    pub fn matrix(&self) -> Matrix2 {
+        self.word.iter().fold(identity2(), |acc, g| matmul2(&g.matrix(), &acc))
+    }
+
+    /// Braid as a single gate on a qubit
+    pub fn to_gate(&self, qubit: usize) -> Gate {
+        Gate::Unitary(qubit, self.matrix())
+    }
+
+    /// Braid as one gate per exchange
+    pub fn to_gates(&self, qubit: usize) -> Vec<Gate> {
+        self.word.iter().map(|g| Gate::Unitary(qubit, g.matrix())).collect()
+    }
+}
+
+/// Distance between unitaries ignoring global phase: min_φ ‖U - e^{iφ}V‖_F
+pub fn phase_distance(u: &Matrix2, v: &Matrix2) -> f64 {
+    let overlap = matmul2(&dagger2(u), v);
+    let trace = overlap[0][0] + overlap[1][1];
+
+    // Align V's phase to U's and measure the difference directly, avoiding cancellation
+    let phase = if trace.norm() > 0.0 { trace.conj() / trace.norm() } else { c(1.0, 0.0) };
+    let mut total = 0.0;
+    for i in 0..2 {
+        for j in 0..2 {
+            total += (u[i][j] - v[i][j] * phase).norm_sqr();
+        }
+    }
+    total.sqrt()
+}
+
+/// Approximation of a unitary by a braid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Approximation {
+    /// Braid word
+    pub braid: Braid,
+
+    /// Phase-invariant distance from the target
+    pub error: f64,
+}
+
+/// Solovay-Kitaev compiler over Fibonacci braids
+#[derive(Debug, Clone)]
+pub struct SolovayKitaev {
+    /// Base net of distinct braids up to the base length
+    net: Vec<(Braid, Matrix2)>,
+}
+
+impl SolovayKitaev {
+    /// Build the base net from all braids of up to `base_length` exchanges
+    ///
+    /// Braids equal up to global phase are stored once, keeping the shortest.
+    pub fn new(base_length: usize) -> Self {
+        let mut seen = HashSet::new();
+        seen.insert(net_key(&identity2()));
+
+        let mut net = vec![(Braid::new(), identity2())];
+        let mut frontier = vec![(Braid::new(), identity2())];
+
+        for _ in 0..base_length {
+            let mut next = Vec::new();
+
+            for (braid, matrix) in &frontier {
+                for generator in BraidGenerator::ALL {
+                    if braid.word.last() == Some(&generator.inverse()) {
+                        continue;
+                    }
+
+                    let product = matmul2(&generator.matrix(), matrix);
+                    if seen.insert(net_key(&product)) {
+                        let mut extended = braid.clone();
+                        extended.word.push(generator);
+                        next.push((extended, product));
+                    }
+                }
+            }
+
+            net.extend(next.iter().cloned());
+            frontier = next;
+        }
+
+        Self { net }
+    }
+
+    /// Get the number of distinct braids in the base net
+    pub fn net_size(&self) -> usize {
+        self.net.len()
+    }
+
+    /// Closest braid in the base net
+    pub fn basic_approximation(&self, target: &Matrix2) -> Approximation {
+        let (braid, matrix) = self.net.iter()
+            .min_by(|a, b| phase_distance(&a.1, target).total_cmp(&phase_distance(&b.1, target)))
+            .cloned()
+            .unwrap_or_default();
+
+        Approximation { error: phase_distance(&matrix, target), braid }
+    }
+
+    /// Approximate a single-qubit unitary with `depth` levels of Solovay-Kitaev refinement
+    pub fn compile(&self, target: &Matrix2, depth: usize) -> QuantumResult<Approximation> {
+        if !is_unitary2(target, 1e-9) {
+            return Err(QuantumError::OperationError {
+                message: "Solovay-Kitaev compilation requires a unitary target".to_string(),
+            });
+        }
+
+        let target = to_su2(target);
+        let braid = self.refine(&target, depth);
+        Ok(Approximation { error: phase_distance(&braid.matrix(), &target), braid })
+    }
+
+    /// Recursive Solovay-Kitaev step
+    fn refine(&self, target: &Matrix2, depth: usize) -> Braid {
+        if depth == 0 {
+            return self.basic_approximation(target).braid;
+        }
+
+        let previous = self.refine(target, depth - 1);
+        let residual = to_su2(&matmul2(target, &dagger2(&previous.matrix())));
+        let (v, w) = balanced_commutator(&residual);
+
+        let v_braid = self.refine(&v, depth - 1);
+        let w_braid = self.refine(&w, depth - 1);
+
+        // target ≈ V W V† W† · previous, applied right to left
+        let candidate = previous
+            .then(&w_braid.inverse())
+            .then(&v_braid.inverse())
+            .then(&w_braid)
+            .then(&v_braid);
+
+        // Keep the earlier approximation if refinement did not help
+        if phase_distance(&candidate.matrix(), target) < phase_distance(&previous.matrix(), target) {
+            candidate
+        } else {
+            previous
+        }
+    }
+}
+
+/// 2×2 identity
+fn identity2() -> Matrix2 {
+    [[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(1.0, 0.0)]]
+}
+
+/// Hash key of a matrix up to global phase
+fn net_key(m: &Matrix2) -> [i64; 8] {
+    // Fix the phase so the largest first-column entry is real and positive
+    let pivot = if m[0][0].norm() >= m[1][0].norm() { m[0][0] } else { m[1][0] };
+    let phase = pivot.conj() / pivot.norm();
+
+    let mut key = [0i64; 8];
+    for (k, v) in [m[0][0], m[0][1], m[1][0], m[1][1]].iter().enumerate() {
+        let z = v * phase;
+        key[2 * k] = (z.re / NET_RESOLUTION).round() as i64;
+        key[2 * k + 1] = (z.im / NET_RESOLUTION).round() as i64;
+    }
+    key
+}
+
+/// Rescale a unitary to unit determinant, choosing the branch with Re(tr) ≥ 0
+fn to_su2(m: &Matrix2) -> Matrix2 {
+    let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+    let mut scale = det.sqrt().inv();
+    if ((m[0][0] + m[1][1]) * scale).re < 0.0 {
+        scale = -scale;
+    }
+    m.map(|row| row.map(|v| v * scale))
+}
+
+/// Rotation axis and angle of an SU(2) matrix cos(θ/2)·I - i·sin(θ/2)·n·σ
+fn axis_angle(m: &Matrix2) -> ([f64; 3], f64) {
+    let cos_half = ((m[0][0] + m[1][1]).re / 2.0).clamp(-1.0, 1.0);
+    let axis = [
+        -(m[0][1] + m[1][0]).im / 2.0,
+        (m[1][0] - m[0][1]).re / 2.0,
+        -(m[0][0] - m[1][1]).im / 2.0,
+    ];
+
+    let sin_half = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+    let angle = 2.0 * sin_half.atan2(cos_half);
+
+    if sin_half < 1e-15 {
+        ([0.0, 0.0, 1.0], angle)
+    } else {
+        (axis.map(|a| a / sin_half), angle)
+    }
+}
+
+/// SU(2) rotation about a unit axis
+fn rotation(axis: [f64; 3], angle: f64) -> Matrix2 {
+    let (s, co) = (angle / 2.0).sin_cos();
+    let [x, y, z] = axis;
+    [
+        [c(co, -s * z), c(-s * y, -s * x)],
+        [c(s * y, -s * x), c(co, s * z)],
+    ]
+}
+
+/// Balanced group commutator: V, W with V W V† W† = U and ‖V - I‖, ‖W - I‖ ~ √‖U - I‖
+fn balanced_commutator(u: &Matrix2) -> (Matrix2, Matrix2) {
+    let (target_axis, theta) = axis_angle(u);
+
+    // Rotations by φ about X and Y have a commutator rotating by θ
+    let phi = 2.0 * ((1.0 - (theta / 2.0).cos()) / 2.0).powf(0.25).asin();
+    let v = rotation([1.0, 0.0, 0.0], phi);
+    let w = rotation([0.0, 1.0, 0.0], phi);
+    let commutator = matmul2(&matmul2(&v, &w), &matmul2(&dagger2(&v), &dagger2(&w)));
+
+    // Conjugate by the rotation carrying the commutator's axis onto the target's
+    let (axis, _) = axis_angle(&commutator);
+    let cross = [
+        axis[1] * target_axis[2] - axis[2] * target_axis[1],
+        axis[2] * target_axis[0] - axis[0] * target_axis[2],
+        axis[0] * target_axis[1] - axis[1] * target_axis[0],
+    ];
+    let dot: f64 = (0..3).map(|k| axis[k] * target_axis[k]).sum();
+    let cross_norm = cross.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    let s = if cross_norm < 1e-15 {
+        if dot > 0.0 {
+            identity2()
+        } else {
+            // Antiparallel: rotate by π about any perpendicular axis
+            let perpendicular = if axis[0].abs() < 0.9 { [1.0, 0.0, 0.0] } else { [0.0, 1.0, 0.0] };
+            let p = [
+                axis[1] * perpendicular[2] - axis[2] * perpendicular[1],
+                axis[2] * perpendicular[0] - axis[0] * perpendicular[2],
+                axis[0] * perpendicular[1] - axis[1] * perpendicular[0],
+            ];
+            let norm = p.iter().map(|v| v * v).sum::<f64>().sqrt();
+            rotation(p.map(|v| v / norm), PI)
+        }
+    } else {
+        rotation(cross.map(|v| v / cross_norm), cross_norm.atan2(dot))
+    };
+
+    let conjugate = |m: &Matrix2| matmul2(&matmul2(&s, m), &dagger2(&s));
+    (conjugate(&v), conjugate(&w))
+}
@@ -0,0 +1,127 @@
+//! Unit tests for the anyon module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{LAMBDA, PHI};
+    use crate::quantum_state::anyon::{
+        balanced_commutator, f_matrix, phase_distance, r_matrix, Braid, BraidGenerator, SolovayKitaev,
+    };
+    use crate::quantum_state::gates::{dagger2, is_unitary2, matmul2, Gate, Matrix2};
+    use crate::quantum_state::state_vector::StateVector;
+
+    fn product(word: &[BraidGenerator]) -> Matrix2 {
+        Braid::from_word(word).matrix()
+    }
+
+    #[test]
+    fn test_f_and_r_matrices() {
+        let f = f_matrix();
+        let r = r_matrix();
+        assert!(is_unitary2(&f, 1e-12));
+        assert!(is_unitary2(&r, 1e-12));
+
+        // F is an involution built from the quantum dimension φ
+        let ff = matmul2(&f, &f);
+        assert!((ff[0][0].re - 1.0).abs() < 1e-12 && ff[0][1].norm() < 1e-12);
+        assert!((f[0][0].re - 1.0 / PHI).abs() < 1e-12);
+        assert!((f[0][1].re - LAMBDA.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_braid_relation_holds() {
+        use BraidGenerator::{Sigma1, Sigma2};
+
+        // σ₁σ₂σ₁ = σ₂σ₁σ₂
+        let left = product(&[Sigma1, Sigma2, Sigma1]);
+        let right = product(&[Sigma2, Sigma1, Sigma2]);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((left[i][j] - right[i][j]).norm() < 1e-12);
+            }
+        }
+
+        // σ₁¹⁰ is the identity
+        let tenth = product(&[Sigma1; 10]);
+        assert!(phase_distance(&tenth, &Braid::new().matrix()) < 1e-12);
+        assert!((tenth[0][0].re - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_braid_words() {
+        use BraidGenerator::*;
+
+        let braid = Braid::from_word(&[Sigma1, Sigma2, Sigma2Inverse, Sigma1]);
+        assert_eq!(braid.word(), &[Sigma1, Sigma1]);
+        assert!(braid.then(&braid.inverse()).is_empty());
+
+        let inverse = braid.inverse().matrix();
+        let identity = matmul2(&inverse, &braid.matrix());
+        assert!(phase_distance(&identity, &Braid::new().matrix()) < 1e-12);
+        assert!(phase_distance(&dagger2(&braid.matrix()), &inverse) < 1e-12);
+    }
+
+    #[test]
+    fn test_braid_gates_simulate() {
+        use BraidGenerator::*;
+
+        let braid = Braid::from_word(&[Sigma1, Sigma2, Sigma1Inverse, Sigma2, Sigma2]);
+        let mut single = StateVector::new(2).unwrap();
+        single.apply_all(&[Gate::H(1), braid.to_gate(1)]).unwrap();
+
+        let mut stepwise = StateVector::new(2).unwrap();
+        stepwise.apply(&Gate::H(1)).unwrap();
+        stepwise.apply_all(&braid.to_gates(1)).unwrap();
+
+        assert!((single.fidelity(&stepwise).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_base_net_removes_duplicates() {
+        let compiler = SolovayKitaev::new(6);
+        let words: usize = 1 + (1..=6).map(|k| 4 * 3usize.pow(k - 1)).sum::<usize>();
+        assert!(compiler.net_size() < words);
+
+        // The net contains σ₁ itself at distance zero
+        let approximation = compiler.basic_approximation(&r_matrix());
+        assert_eq!(approximation.braid.len(), 1);
+        assert!(approximation.error < 1e-9);
+    }
+
+    #[test]
+    fn test_balanced_commutator_reproduces_target() {
+        for target in [Gate::Rz(0, 0.2), Gate::Rx(0, -0.05), Gate::Ry(0, 0.4)] {
+            let m = target.matrix().unwrap();
+            let (v, w) = balanced_commutator(&m);
+            let commutator = matmul2(&matmul2(&v, &w), &matmul2(&dagger2(&v), &dagger2(&w)));
+            assert!(phase_distance(&commutator, &m) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_solovay_kitaev_refines_approximation() {
+        let compiler = SolovayKitaev::new(10);
+        let targets = [
+            Gate::H(0).matrix().unwrap(),
+            Gate::T(0).matrix().unwrap(),
+            Gate::Ry(0, 1.234).matrix().unwrap(),
+        ];
+
+        for target in targets {
+            let basic = compiler.compile(&target, 0).unwrap();
+            let refined = compiler.compile(&target, 3).unwrap();
+
+            assert!(refined.error < basic.error / 10.0);
+            assert!(refined.error < 0.005);
+            assert!((phase_distance(&refined.braid.matrix(), &target) - refined.error).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_compile_rejects_non_unitary() {
+        let compiler = SolovayKitaev::new(2);
+        let mut m = Gate::H(0).matrix().unwrap();
+        m[0][0] *= 2.0;
+        assert!(compiler.compile(&m, 1).is_err());
+    }
+}
@@ -0,0 +1,557 @@
+//! Bloch Sphere
+//!
+//! This module represents single-qubit states as Bloch vectors, interpolates
+//! between them along geodesics, and renders the sphere with points and
+//! trajectories as SVG. An optional overlay colors each point by the
+//! consciousness state (or its frequency) mapped from the point's latitude,
+//! which makes state transitions easy to follow visually.
+
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use num::complex::Complex64;
+
+use crate::constants::{ConsciousnessState, Frequency, GOLDEN_ANGLE};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::density::DensityMatrix;
+use crate::quantum_state::gates::{c, Gate};
+use crate::quantum_state::matrix::ComplexMatrix;
+use crate::quantum_state::state_vector::StateVector;
+
+/// Consciousness states ordered by dimension, from the |0⟩ pole to the |1⟩ pole
+fn states_by_dimension() -> [ConsciousnessState; 7] {
+    let mut states = ConsciousnessState::ALL;
+    states.sort_by_key(|state| state.dimension());
+    states
+}
+
+/// Point in (or on) the Bloch ball
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlochVector {
+    /// ⟨X⟩ component
+    pub x: f64,
+
+    /// ⟨Y⟩ component
+    pub y: f64,
+
+    /// ⟨Z⟩ component
+    pub z: f64,
+}
+
+impl BlochVector {
+    /// Create a Bloch vector from components
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Create a unit Bloch vector from polar angle θ and azimuth φ
+    pub fn from_angles(theta: f64, phi: f64) -> Self {
+        Self {
+            x: theta.sin() * phi.cos(),
+            y: theta.sin() * phi.sin(),
+            z: theta.cos(),
+        }
+    }
+
+    /// Bloch vector of a single-qubit pure state
+    pub fn from_state(state: &StateVector) -> QuantumResult<Self> {
+        check_single_qubit(state.num_qubits())?;
+        let a = state.amplitudes();
+
+        // ⟨X⟩ + i⟨Y⟩ = 2·conj(a0)·a1
+        let coherence = a[0].conj() * a[1] * 2.0;
+        Ok(Self {
+            x: coherence.re,
+            y: coherence.im,
+            z: a[0].norm_sqr() - a[1].norm_sqr(),
+        })
+    }
+
+    /// Bloch vector of a single-qubit density matrix (inside the ball when mixed)
+    pub fn from_density(rho: &DensityMatrix) -> QuantumResult<Self> {
+        check_single_qubit(rho.num_qubits())?;
+        let off_diagonal = rho.element(1, 0) * 2.0;
+
+        Ok(Self {
+            x: off_diagonal.re,
+            y: off_diagonal.im,
+            z: rho.probability(0) - rho.probability(1),
+        })
+    }
+
+    /// Point representing a consciousness state
+    ///
+    /// Latitude follows the state's dimension from the |0⟩ pole (Observe)
+    /// to the |1⟩ pole (Amplify); successive states are spread in azimuth by
+    /// the golden angle.
+    pub fn for_state(state: ConsciousnessState) -> Self {
+        let states = states_by_dimension();
+        let index = states.iter().position(|s| *s == state).unwrap_or(0);
+        let theta = PI * index as f64 / (states.len() - 1) as f64;
+        Self::from_angles(theta, GOLDEN_ANGLE * index as f64)
+    }
+
+    /// Length (1 for pure states, 0 for the maximally mixed state)
+    pub fn length(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Polar angle θ from the |0⟩ pole
+    pub fn theta(&self) -> f64 {
+        (self.x * self.x + self.y * self.y).sqrt().atan2(self.z)
+    }
+
+    /// Azimuth φ from the +X axis
+    pub fn phi(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Unit vector in the same direction (+Z for the origin)
+    pub fn normalized(&self) -> Self {
+        let length = self.length();
+        if length < 1e-15 {
+            Self::new(0.0, 0.0, 1.0)
+        } else {
+            Self::new(self.x / length, self.y / length, self.z / length)
+        }
+    }
+
+    /// Angle between the directions of two Bloch vectors
+    pub fn angle_to(&self, other: &BlochVector) -> f64 {
+        let a = self.normalized();
+        let b = other.normalized();
+        let cross = a.cross(&b);
+        cross.length().atan2(a.dot(&b))
+    }
+
+    /// Dot product
+    pub fn dot(&self, other: &BlochVector) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product
+    pub fn cross(&self, other: &BlochVector) -> BlochVector {
+        BlochVector::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Geodesic interpolation at fraction `t` from `self` to `other`
+    ///
+    /// Directions follow the great circle; lengths interpolate linearly, so
+    /// mixed states move through the ball. Antipodal points are joined
+    /// through the meridian containing the +X direction (or +Y at the poles).
+    pub fn slerp(&self, other: &BlochVector, t: f64) -> BlochVector {
+        let a = self.normalized();
+        let b = other.normalized();
+        let angle = self.angle_to(other);
+        let length = self.length() + (other.length() - self.length()) * t;
+
+        if angle < 1e-12 {
+            return a.scaled(length);
+        }
+
+        // Unit vector perpendicular to `a` in the plane of rotation
+        let perpendicular = rotation_axis(&a, &b).cross(&a);
+
+        let (s, co) = (angle * t).sin_cos();
+        BlochVector::new(
+            a.x * co + perpendicular.x * s,
+            a.y * co + perpendicular.y * s,
+            a.z * co + perpendicular.z * s,
+        ).scaled(length)
+    }
+
+    /// Points along the geodesic from `self` to `other`, both ends included
+    pub fn geodesic(&self, other: &BlochVector, steps: usize) -> Vec<BlochVector> {
+        let steps = steps.max(1);
+        (0..=steps).map(|k| self.slerp(other, k as f64 / steps as f64)).collect()
+    }
+
+    /// Single-qubit gate rotating this direction onto `other` along the geodesic
+    pub fn rotation_to(&self, other: &BlochVector, qubit: usize) -> Gate {
+        let angle = self.angle_to(other);
+        let n = rotation_axis(&self.normalized(), &other.normalized());
+
+        // exp(-i·α/2·n·σ) = cos(α/2)·I - i·sin(α/2)·n·σ
+        let (s, co) = (angle / 2.0).sin_cos();
+        Gate::Unitary(qubit, [
+            [c(co, -s * n.z), c(-s * n.y, -s * n.x)],
+            [c(s * n.y, -s * n.x), c(co, s * n.z)],
+        ])
+    }
+
+    /// Pure state pointing in this vector's direction
+    pub fn to_state(&self) -> QuantumResult<StateVector> {
+        let direction = self.normalized();
+        let theta = direction.theta();
+        let amplitudes = vec![
+            c((theta / 2.0).cos(), 0.0),
+            Complex64::from_polar((theta / 2.0).sin(), direction.phi()),
+        ];
+        StateVector::from_amplitudes(amplitudes)
+    }
+
+    /// Density matrix (I + r·σ)/2 of this vector
+    pub fn to_density(&self) -> QuantumResult<DensityMatrix> {
+        let matrix = ComplexMatrix::from_rows(&[
+            vec![c((1.0 + self.z) / 2.0, 0.0), c(self.x / 2.0, -self.y / 2.0)],
+            vec![c(self.x / 2.0, self.y / 2.0), c((1.0 - self.z) / 2.0, 0.0)],
+        ])?;
+        DensityMatrix::from_matrix(matrix)
+    }
+
+    /// Consciousness state mapped from the vector's latitude
+    pub fn consciousness_state(&self) -> ConsciousnessState {
+        let states = states_by_dimension();
+        let fraction = self.theta() / PI;
+        let index = (fraction * (states.len() - 1) as f64).round() as usize;
+        states[index.min(states.len() - 1)]
+    }
+
+    /// Frequency of the mapped consciousness state
+    pub fn frequency(&self) -> Frequency {
+        self.consciousness_state().frequency()
+    }
+
+    /// Multiply every component by a factor
+    fn scaled(&self, factor: f64) -> BlochVector {
+        BlochVector::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+/// Geodesic interpolation between two single-qubit pure states
+pub fn interpolate_states(from: &StateVector, to: &StateVector, t: f64) -> QuantumResult<StateVector> {
+    let a = BlochVector::from_state(from)?;
+    let b = BlochVector::from_state(to)?;
+    a.slerp(&b, t).to_state()
+}
+
+/// Color scheme for points and trajectories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    /// Single color for everything
+    None,
+
+    /// Color by mapped consciousness state
+    State,
+
+    /// Color by the mapped state's frequency (hue from 432 Hz to 768 Hz)
+    Frequency,
+}
+
+/// SVG renderer for the Bloch sphere
+#[derive(Debug, Clone)]
+pub struct BlochSphereSvg {
+    /// Image width and height in pixels
+    size: f64,
+
+    /// Viewing elevation above the equator (radians)
+    elevation: f64,
+
+    /// Viewing azimuth around the Z axis (radians)
+    azimuth: f64,
+
+    /// Point coloring
+    overlay: Overlay,
+
+    /// Individual points with optional labels
+    points: Vec<(BlochVector, Option<String>)>,
+
+    /// Trajectories drawn as connected paths
+    trajectories: Vec<Vec<BlochVector>>,
+}
+
+impl BlochSphereSvg {
+    /// Create a renderer with a square canvas
+    pub fn new(size: f64) -> Self {
+        Self {
+            size,
+            elevation: PI / 9.0,
+            azimuth: PI / 6.0,
+            overlay: Overlay::None,
+            points: Vec::new(),
+            trajectories: Vec::new(),
+        }
+    }
+
+    /// Set the viewing angles (radians)
+    pub fn with_view(mut self, elevation: f64, azimuth: f64) -> Self {
+        self.elevation = elevation;
+        self.azimuth = azimuth;
+        self
+    }
+
+    /// Set the color overlay
+    pub fn with_overlay(mut self, overlay: Overlay) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Add a point
+    pub fn add_point(&mut self, point: BlochVector, label: Option<&str>) -> &mut Self {
+        self.points.push((point, label.map(str::to_string)));
+        self
+    }
+
+    /// Add a trajectory
+    pub fn add_trajectory(&mut self, trajectory: &[BlochVector]) -> &mut Self {
+        self.trajectories.push(trajectory.to_vec());
+        self
+    }
+
+    /// Get the number of points
+    pub fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Get the number of trajectories
+    pub fn trajectory_count(&self) -> usize {
+        self.trajectories.len()
+    }
+
+    /// Render the sphere as an SVG document
+    pub fn render(&self) -> String {
+        let center = self.size / 2.0;
+        let radius = self.size * 0.4;
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+            self.size
+        );
+        let _ = writeln!(svg, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+        let _ = writeln!(
+            svg,
+            r##"<circle class="sphere" cx="{:.2}" cy="{:.2}" r="{:.2}" fill="#f4f6fb" stroke="#555555" stroke-width="1.5"/>"##,
+            center, center, radius
+        );
+
+        // Equator and prime meridian, dashed where they pass behind the sphere
+        let equator: Vec<BlochVector> = (0..=72).map(|k| BlochVector::from_angles(PI / 2.0, k as f64 * PI / 36.0)).collect();
+        let meridian: Vec<BlochVector> = (0..=72).map(|k| BlochVector::from_angles(k as f64 * PI / 36.0, 0.0)).collect();
+        for circle in [&equator, &meridian] {
+            for (front, segment) in self.split_by_depth(circle) {
+                let dash = if front { "" } else { r#" stroke-dasharray="4 4""# };
+                let _ = writeln!(
+                    svg,
+                    r##"<polyline class="grid" points="{}" fill="none" stroke="#999999" stroke-width="1"{}/>"##,
+                    self.path(&segment, center, radius),
+                    dash
+                );
+            }
+        }
+
+        // Axes with basis-state labels
+        for (end, label) in [
+            (BlochVector::new(0.0, 0.0, 1.0), "|0⟩"),
+            (BlochVector::new(0.0, 0.0, -1.0), "|1⟩"),
+            (BlochVector::new(1.0, 0.0, 0.0), "x"),
+            (BlochVector::new(0.0, 1.0, 0.0), "y"),
+        ] {
+            let (x, y, _) = self.project(&end, center, radius);
+            let _ = writeln!(
+                svg,
+                r##"<line class="axis" x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="#777777" stroke-width="1"/>"##,
+                center, center, x, y
+            );
+            let (lx, ly, _) = self.project(&end.scaled(1.12), center, radius);
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.2}" y="{:.2}" font-family="sans-serif" font-size="{:.1}" text-anchor="middle">{}</text>"#,
+                lx, ly + 4.0, self.size * 0.035, label
+            );
+        }
+
+        for trajectory in &self.trajectories {
+            for pair in trajectory.windows(2) {
+                let (x1, y1, d1) = self.project(&pair[0], center, radius);
+                let (x2, y2, d2) = self.project(&pair[1], center, radius);
+                let opacity = if d1 + d2 >= 0.0 { 1.0 } else { 0.4 };
+                let _ = writeln!(
+                    svg,
+                    r#"<line class="trajectory" x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="2" stroke-opacity="{}"/>"#,
+                    x1, y1, x2, y2, self.color(&pair[0]), opacity
+                );
+            }
+        }
+
+        for (point, label) in &self.points {
+            let (x, y, depth) = self.project(point, center, radius);
+            let opacity = if depth >= 0.0 { 1.0 } else { 0.5 };
+            let _ = writeln!(
+                svg,
+                r##"<circle class="point" cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}" fill-opacity="{}" stroke="#222222" stroke-width="0.5"/>"##,
+                x, y, self.size * 0.012, self.color(point), opacity
+            );
+            if let Some(text) = label {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{:.2}" y="{:.2}" font-family="sans-serif" font-size="{:.1}">{}</text>"#,
+                    x + self.size * 0.018, y - self.size * 0.012, self.size * 0.03, escape(text)
+                );
+            }
+        }
+
+        if self.overlay != Overlay::None {
+            self.legend(&mut svg);
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Write the SVG document to a file
+    pub fn save(&self, path: &std::path::Path) -> QuantumResult<()> {
+        std::fs::write(path, self.render()).map_err(|e| QuantumError::OperationError {
+            message: format!("Failed to write {}: {}", path.display(), e),
+        })
+    }
+
+    /// Orthographic projection: (screen x, screen y, depth toward the viewer)
+    fn project(&self, v: &BlochVector, center: f64, radius: f64) -> (f64, f64, f64) {
+        let (sa, ca) = self.azimuth.sin_cos();
+        let (se, ce) = self.elevation.sin_cos();
+
+        // Rotate about Z so the viewer looks along the rotated X axis, then tilt
+        let right = -v.x * sa + v.y * ca;
+        let toward = v.x * ca + v.y * sa;
+        let up = v.z * ce - toward * se;
+        let depth = toward * ce + v.z * se;
+
+        (center + radius * right, center - radius * up, depth)
+    }
+
+    /// Split a closed curve into runs in front of and behind the sphere
+    fn split_by_depth(&self, curve: &[BlochVector]) -> Vec<(bool, Vec<BlochVector>)> {
+        let mut runs: Vec<(bool, Vec<BlochVector>)> = Vec::new();
+
+        for point in curve {
+            let front = self.project(point, 0.0, 1.0).2 >= 0.0;
+            match runs.last_mut() {
+                Some((side, run)) if *side == front => run.push(*point),
+                Some((_, run)) => {
+                    // Share the boundary point so runs join up
+                    let last = run[run.len() - 1];
+                    runs.push((front, vec![last, *point]));
+                }
+                None => runs.push((front, vec![*point])),
+            }
+        }
+
+        runs
+    }
+
+    /// SVG points attribute for a path
+    fn path(&self, points: &[BlochVector], center: f64, radius: f64) -> String {
+        points.iter()
+            .map(|p| {
+                let (x, y, _) = self.project(p, center, radius);
+                format!("{:.2},{:.2}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Fill color for a point under the current overlay
+    fn color(&self, point: &BlochVector) -> String {
+        match self.overlay {
+            Overlay::None => "#3366cc".to_string(),
+            Overlay::State => state_color(point.consciousness_state()).to_string(),
+            Overlay::Frequency => frequency_color(point.frequency()),
+        }
+    }
+
+    /// Draw a legend for the active overlay
+    fn legend(&self, svg: &mut String) {
+        let font = self.size * 0.028;
+        let x = self.size * 0.02;
+
+        for (row, state) in states_by_dimension().iter().enumerate() {
+            let y = self.size * 0.04 + row as f64 * font * 1.4;
+            let (color, text) = match self.overlay {
+                Overlay::Frequency => (frequency_color(state.frequency()), format!("{} Hz", state.frequency().value())),
+                _ => (state_color(*state).to_string(), format!("{:?}", state)),
+            };
+
+            let _ = writeln!(
+                svg,
+                r##"<rect class="legend" x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}"/>"##,
+                x, y - font * 0.8, font, font, color
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.2}" y="{:.2}" font-family="sans-serif" font-size="{:.1}">{}</text>"#,
+                x + font * 1.4, y, font, text
+            );
+        }
+    }
+}
+
+/// Fixed palette for consciousness states
+pub fn state_color(state: ConsciousnessState) -> &'static str {
+    match state {
+        ConsciousnessState::Observe => "#4e79a7",
+        ConsciousnessState::Create => "#59a14f",
+        ConsciousnessState::Integrate => "#edc948",
+        ConsciousnessState::Transcend => "#b07aa1",
+        ConsciousnessState::Harmonize => "#76b7b2",
+        ConsciousnessState::Cascade => "#f28e2b",
+        ConsciousnessState::Amplify => "#e15759",
+    }
+}
+
+/// Hue running from red at 432 Hz to violet at 768 Hz
+pub fn frequency_color(frequency: Frequency) -> String {
+    let low = Frequency::Unity.value();
+    let high = Frequency::Oneness.value();
+    let hue = 270.0 * (frequency.value() - low) / (high - low);
+
+    // HSL with full saturation and 50% lightness
+    let sector = hue / 60.0;
+    let x = 1.0 - (sector % 2.0 - 1.0).abs();
+    let (r, g, b) = match sector as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        _ => (x, 0.0, 1.0),
+    };
+
+    format!("#{:02x}{:02x}{:02x}", (r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+}
+
+/// Unit axis rotating unit vector `a` toward `b`
+///
+/// Antipodal points use the axis perpendicular to `a` and +X (or +Y near
+/// the X axis).
+fn rotation_axis(a: &BlochVector, b: &BlochVector) -> BlochVector {
+    let axis = a.cross(b);
+    if axis.length() >= 1e-12 {
+        return axis.normalized();
+    }
+
+    let reference = if a.x.abs() < 0.9 { BlochVector::new(1.0, 0.0, 0.0) } else { BlochVector::new(0.0, 1.0, 0.0) };
+    a.cross(&reference).normalized()
+}
+
+/// Escape text for XML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Ensure a register has exactly one qubit
+fn check_single_qubit(num_qubits: usize) -> QuantumResult<()> {
+    if num_qubits != 1 {
+        return Err(QuantumError::OperationError {
+            message: format!("Bloch vectors describe single qubits, got {} qubits", num_qubits),
+        });
+    }
+    Ok(())
+}
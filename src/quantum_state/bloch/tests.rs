@@ -0,0 +1,172 @@
+//! Unit tests for the bloch module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{ConsciousnessState, Frequency};
+    use crate::quantum_state::bloch::{
+        frequency_color, interpolate_states, state_color, BlochSphereSvg, BlochVector, Overlay,
+    };
+    use crate::quantum_state::channels::Channel;
+    use crate::quantum_state::density::DensityMatrix;
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::state_vector::StateVector;
+    use std::f64::consts::PI;
+
+    fn close(a: &BlochVector, b: &BlochVector) -> bool {
+        (a.x - b.x).abs() < 1e-12 && (a.y - b.y).abs() < 1e-12 && (a.z - b.z).abs() < 1e-12
+    }
+
+    fn prepared(gates: &[Gate]) -> StateVector {
+        let mut state = StateVector::new(1).unwrap();
+        state.apply_all(gates).unwrap();
+        state
+    }
+
+    #[test]
+    fn test_basis_states_map_to_axes() {
+        let zero = BlochVector::from_state(&prepared(&[])).unwrap();
+        let one = BlochVector::from_state(&prepared(&[Gate::X(0)])).unwrap();
+        let plus = BlochVector::from_state(&prepared(&[Gate::H(0)])).unwrap();
+        let plus_i = BlochVector::from_state(&prepared(&[Gate::H(0), Gate::S(0)])).unwrap();
+
+        assert!(close(&zero, &BlochVector::new(0.0, 0.0, 1.0)));
+        assert!(close(&one, &BlochVector::new(0.0, 0.0, -1.0)));
+        assert!(close(&plus, &BlochVector::new(1.0, 0.0, 0.0)));
+        assert!(close(&plus_i, &BlochVector::new(0.0, 1.0, 0.0)));
+
+        assert!(BlochVector::from_state(&StateVector::new(2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let state = prepared(&[Gate::Ry(0, 1.1), Gate::Rz(0, -0.7)]);
+        let vector = BlochVector::from_state(&state).unwrap();
+        assert!((vector.length() - 1.0).abs() < 1e-12);
+
+        let rebuilt = vector.to_state().unwrap();
+        assert!((rebuilt.fidelity(&state).unwrap() - 1.0).abs() < 1e-12);
+
+        let angles = BlochVector::from_angles(vector.theta(), vector.phi());
+        assert!(close(&angles, &vector));
+    }
+
+    #[test]
+    fn test_mixed_states_lie_inside_ball() {
+        let mut rho = DensityMatrix::from_state_vector(&prepared(&[Gate::H(0)])).unwrap();
+        rho.apply_channel(&Channel::depolarizing(0.5).unwrap(), &[0]).unwrap();
+
+        let vector = BlochVector::from_density(&rho).unwrap();
+        assert!((vector.length() - 0.5).abs() < 1e-12);
+
+        let rebuilt = vector.to_density().unwrap();
+        assert!((rebuilt.purity() - rho.purity()).abs() < 1e-12);
+        assert!(BlochVector::from_density(&DensityMatrix::maximally_mixed(1).unwrap()).unwrap().length() < 1e-12);
+    }
+
+    #[test]
+    fn test_geodesic_interpolation() {
+        let zero = BlochVector::new(0.0, 0.0, 1.0);
+        let plus = BlochVector::new(1.0, 0.0, 0.0);
+
+        let middle = zero.slerp(&plus, 0.5);
+        let expected = BlochVector::from_angles(PI / 4.0, 0.0);
+        assert!(close(&middle, &expected));
+
+        let path = zero.geodesic(&plus, 8);
+        assert_eq!(path.len(), 9);
+        assert!(close(&path[0], &zero) && close(&path[8], &plus));
+        for pair in path.windows(2) {
+            assert!((pair[0].angle_to(&pair[1]) - PI / 16.0).abs() < 1e-12);
+            assert!((pair[1].length() - 1.0).abs() < 1e-12);
+        }
+
+        // Antipodal points still follow a great circle
+        let one = BlochVector::new(0.0, 0.0, -1.0);
+        let equator = zero.slerp(&one, 0.5);
+        assert!(equator.z.abs() < 1e-12 && (equator.length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotation_to_moves_states() {
+        let targets = [
+            (BlochVector::new(0.0, 0.0, 1.0), BlochVector::new(0.0, 1.0, 0.0)),
+            (BlochVector::from_angles(0.3, 1.2), BlochVector::from_angles(2.5, -0.4)),
+            (BlochVector::new(0.0, 0.0, 1.0), BlochVector::new(0.0, 0.0, -1.0)),
+        ];
+
+        for (from, to) in targets {
+            let mut state = from.to_state().unwrap();
+            state.apply(&from.rotation_to(&to, 0)).unwrap();
+            assert!(close(&BlochVector::from_state(&state).unwrap(), &to));
+        }
+    }
+
+    #[test]
+    fn test_interpolate_states() {
+        let from = prepared(&[]);
+        let to = prepared(&[Gate::H(0)]);
+        let halfway = interpolate_states(&from, &to, 0.5).unwrap();
+
+        // Ry(π/4)|0⟩ lies halfway between |0⟩ and |+⟩
+        let expected = prepared(&[Gate::Ry(0, PI / 4.0)]);
+        assert!((halfway.fidelity(&expected).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_consciousness_mapping() {
+        assert_eq!(BlochVector::new(0.0, 0.0, 1.0).consciousness_state(), ConsciousnessState::Observe);
+        assert_eq!(BlochVector::new(0.0, 0.0, -1.0).consciousness_state(), ConsciousnessState::Amplify);
+        assert_eq!(BlochVector::new(1.0, 0.0, 0.0).consciousness_state(), ConsciousnessState::Transcend);
+        assert_eq!(BlochVector::new(0.0, 0.0, 1.0).frequency(), Frequency::Unity);
+
+        for state in [
+            ConsciousnessState::Observe,
+            ConsciousnessState::Create,
+            ConsciousnessState::Integrate,
+            ConsciousnessState::Transcend,
+            ConsciousnessState::Harmonize,
+            ConsciousnessState::Cascade,
+            ConsciousnessState::Amplify,
+        ] {
+            let point = BlochVector::for_state(state);
+            assert_eq!(point.consciousness_state(), state);
+            assert!((point.length() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_svg_rendering() {
+        let zero = BlochVector::new(0.0, 0.0, 1.0);
+        let one = BlochVector::new(0.0, 0.0, -1.0);
+
+        let mut sphere = BlochSphereSvg::new(400.0);
+        sphere.add_point(zero, Some("start <0>")).add_point(one, None);
+        sphere.add_trajectory(&zero.geodesic(&BlochVector::new(1.0, 0.0, 0.0), 10));
+        assert_eq!((sphere.point_count(), sphere.trajectory_count()), (2, 1));
+
+        let svg = sphere.render();
+        assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches(r#"class="point""#).count(), 2);
+        assert_eq!(svg.matches(r#"class="trajectory""#).count(), 10);
+        assert!(svg.contains("start &lt;0&gt;"));
+        assert!(svg.contains("|0⟩") && svg.contains("|1⟩"));
+        assert!(!svg.contains(r#"class="legend""#));
+    }
+
+    #[test]
+    fn test_svg_overlays() {
+        let mut sphere = BlochSphereSvg::new(300.0).with_overlay(Overlay::State);
+        sphere.add_point(BlochVector::for_state(ConsciousnessState::Cascade), None);
+        let svg = sphere.render();
+        assert!(svg.contains(state_color(ConsciousnessState::Cascade)));
+        assert_eq!(svg.matches(r#"class="legend""#).count(), 7);
+
+        let mut sphere = BlochSphereSvg::new(300.0).with_overlay(Overlay::Frequency);
+        sphere.add_point(BlochVector::new(0.0, 0.0, 1.0), None);
+        assert!(sphere.render().contains(&frequency_color(Frequency::Unity)));
+
+        assert_eq!(frequency_color(Frequency::Unity), "#ff0000");
+        assert_ne!(frequency_color(Frequency::Unity), frequency_color(Frequency::Oneness));
+    }
+}
@@ -0,0 +1,215 @@
+//! Quantum Noise Channels
+//!
+//! This module provides completely positive trace-preserving channels in
+//! Kraus form: the standard single-qubit noise models (amplitude damping,
+//! phase damping, depolarizing, bit and phase flips) and validated custom
+//! Kraus sets acting on any number of qubits.
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::gates::c;
+use crate::quantum_state::matrix::ComplexMatrix;
+
+/// Tolerance for the Kraus completeness relation Σ K†K = I
+const COMPLETENESS_TOLERANCE: f64 = 1e-9;
+
+/// Quantum channel described by a set of Kraus operators
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channel {
+    /// Number of qubits the channel acts on
+    num_qubits: usize,
+
+    /// Kraus operators, each of dimension 2^num_qubits
+    kraus: Vec<ComplexMatrix>,
+}
+
+impl Channel {
+    /// Create a channel from custom Kraus operators
+    ///
+    /// The operators must share a dimension 2^k and satisfy Σ K†K = I.
+    pub fn from_kraus(kraus: Vec<ComplexMatrix>) -> QuantumResult<Self> {
+        let dimension = match kraus.first() {
+            Some(k) => k.size(),
+            None => {
+                return Err(QuantumError::OperationError {
+                    message: "A channel needs at least one Kraus operator".to_string(),
+                })
+            }
+        };
+
+        if dimension < 2 || !dimension.is_power_of_two() || kraus.iter().any(|k| k.size() != dimension) {
+            return Err(QuantumError::OperationError {
+                message: "Kraus operators must share a qubit dimension 2^k".to_string(),
+            });
+        }
+
+        let completeness = kraus.iter()
+            .fold(ComplexMatrix::zeros(dimension), |sum, k| &sum + &(&k.dagger() * k));
+
+        if completeness.max_difference(&ComplexMatrix::identity(dimension)) > COMPLETENESS_TOLERANCE {
+            return Err(QuantumError::OperationError {
+                message: "Kraus operators do not satisfy Σ K†K = I".to_string(),
+            });
+        }
+
+        Ok(Self {
+            num_qubits: dimension.trailing_zeros() as usize,
+            kraus,
+        })
+    }
+
+    /// Amplitude damping: energy relaxation |1⟩ → |0⟩ with probability γ
+    pub fn amplitude_damping(gamma: f64) -> QuantumResult<Self> {
+        check_probability("Damping rate", gamma)?;
+
+        Self::from_kraus(vec![
+            matrix2([[1.0, 0.0], [0.0, (1.0 - gamma).sqrt()]]),
+            matrix2([[0.0, gamma.sqrt()], [0.0, 0.0]]),
+        ])
+    }
+
+    /// Phase damping: loss of phase information without energy loss
+    ///
+    /// Off-diagonal elements are scaled by √(1 - λ).
+    pub fn phase_damping(lambda: f64) -> QuantumResult<Self> {
+        check_probability("Damping rate", lambda)?;
+
+        Self::from_kraus(vec![
+            matrix2([[1.0, 0.0], [0.0, (1.0 - lambda).sqrt()]]),
+            matrix2([[0.0, 0.0], [0.0, lambda.sqrt()]]),
+        ])
+    }
+
+    /// Depolarizing: ρ → (1 - p)ρ + p·I/2
+    pub fn depolarizing(p: f64) -> QuantumResult<Self> {
+        check_probability("Depolarizing probability", p)?;
+
+        let pauli = (p / 4.0).sqrt();
+        Self::from_kraus(vec![
+            matrix2([[1.0, 0.0], [0.0, 1.0]]).scale(c((1.0 - 3.0 * p / 4.0).sqrt(), 0.0)),
+            matrix2([[0.0, 1.0], [1.0, 0.0]]).scale(c(pauli, 0.0)),
+            ComplexMatrix::from_fn(2, |i, j| match (i, j) {
+                (0, 1) => c(0.0, -pauli),
+                (1, 0) => c(0.0, pauli),
+                _ => c(0.0, 0.0),
+            }),
+            matrix2([[1.0, 0.0], [0.0, -1.0]]).scale(c(pauli, 0.0)),
+        ])
+    }
+
+    /// Bit flip: X applied with probability p
+    pub fn bit_flip(p: f64) -> QuantumResult<Self> {
+        check_probability("Flip probability", p)?;
+
+        Self::from_kraus(vec![
+            matrix2([[1.0, 0.0], [0.0, 1.0]]).scale(c((1.0 - p).sqrt(), 0.0)),
+            matrix2([[0.0, 1.0], [1.0, 0.0]]).scale(c(p.sqrt(), 0.0)),
+        ])
+    }
+
+    /// Phase flip: Z applied with probability p
+    pub fn phase_flip(p: f64) -> QuantumResult<Self> {
+        check_probability("Flip probability", p)?;
+
+        Self::from_kraus(vec![
+            matrix2([[1.0, 0.0], [0.0, 1.0]]).scale(c((1.0 - p).sqrt(), 0.0)),
+            matrix2([[1.0, 0.0], [0.0, -1.0]]).scale(c(p.sqrt(), 0.0)),
+        ])
+    }
+
+    /// Get the number of qubits the channel acts on
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Get the Kraus operators
+    pub fn kraus(&self) -> &[ComplexMatrix] {
+        &self.kraus
+    }
+
+    /// Sequential composition: apply `self`, then `next`
+    pub fn then(&self, next: &Channel) -> QuantumResult<Channel> {
+        if self.num_qubits != next.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Cannot compose {}-qubit and {}-qubit channels", self.num_qubits, next.num_qubits),
+            });
+        }
+
+        let kraus = next.kraus.iter()
+            .flat_map(|b| self.kraus.iter().map(move |a| b * a))
+            .collect();
+
+        Channel::from_kraus(kraus)
+    }
+
+    /// Apply the channel to a density matrix of a register, on the given target qubits
+    ///
+    /// Each Kraus operator acts on the 2^k×2^k blocks that share the
+    /// non-target bits of their row and column, so the cost is O(4^n·2^k)
+    /// rather than dense 2^n×2^n products.
+    pub fn apply(&self, rho: &ComplexMatrix, targets: &[usize], num_qubits: usize) -> QuantumResult<ComplexMatrix> {
+        if targets.len() != self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("{}-qubit channel applied to {} targets", self.num_qubits, targets.len()),
+            });
+        }
+
+        let dimension = u32::try_from(num_qubits).ok().and_then(|n| 1usize.checked_shl(n));
+        if dimension != Some(rho.size()) {
+            return Err(QuantumError::OperationError {
+                message: format!("Density matrix of size {} does not match a {}-qubit register", rho.size(), num_qubits),
+            });
+        }
+
+        for (i, &t) in targets.iter().enumerate() {
+            if t >= num_qubits || targets[..i].contains(&t) {
+                return Err(QuantumError::OperationError {
+                    message: format!("Invalid target qubit {} for {}-qubit register", t, num_qubits),
+                });
+            }
+        }
+
+        // Register offsets of each sub-index, with targets[0] as its least significant bit
+        let target_mask = targets.iter().fold(0usize, |m, t| m | (1 << t));
+        let offsets: Vec<usize> = (0..1usize << targets.len())
+            .map(|sub| targets.iter().enumerate().fold(0, |acc, (k, t)| acc | (((sub >> k) & 1) << t)))
+            .collect();
+        let bases: Vec<usize> = (0..rho.size()).filter(|i| i & target_mask == 0).collect();
+        let daggers: Vec<ComplexMatrix> = self.kraus.iter().map(ComplexMatrix::dagger).collect();
+
+        let mut result = ComplexMatrix::zeros(rho.size());
+        for &row in &bases {
+            for &column in &bases {
+                let block = ComplexMatrix::from_fn(offsets.len(), |a, b| rho.get(row | offsets[a], column | offsets[b]));
+                let mapped = self.kraus.iter()
+                    .zip(&daggers)
+                    .fold(ComplexMatrix::zeros(offsets.len()), |sum, (k, dagger)| &sum + &(&(k * &block) * dagger));
+
+                for (a, &ra) in offsets.iter().enumerate() {
+                    for (b, &cb) in offsets.iter().enumerate() {
+                        result.set(row | ra, column | cb, mapped.get(a, b));
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Build a real 2×2 matrix
+fn matrix2(entries: [[f64; 2]; 2]) -> ComplexMatrix {
+    ComplexMatrix::from_fn(2, |i, j| c(entries[i][j], 0.0))
+}
+
+/// Ensure a channel parameter is a probability
+fn check_probability(name: &str, p: f64) -> QuantumResult<()> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(QuantumError::OperationError {
+            message: format!("{} must be in [0, 1], got {}", name, p),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Unit tests for the channels module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::channels::Channel;
+    use crate::quantum_state::density::DensityMatrix;
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use num::complex::Complex64;
+
+    #[test]
+    fn test_standard_channels_are_trace_preserving() {
+        for channel in [
+            Channel::amplitude_damping(0.2).unwrap(),
+            Channel::phase_damping(0.4).unwrap(),
+            Channel::depolarizing(0.6).unwrap(),
+            Channel::bit_flip(0.1).unwrap(),
+            Channel::phase_flip(0.9).unwrap(),
+        ] {
+            assert_eq!(channel.num_qubits(), 1);
+
+            let mut rho = DensityMatrix::new(2).unwrap();
+            rho.apply_all(&[Gate::H(0), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+            rho.apply_channel(&channel, &[1]).unwrap();
+            assert!((rho.trace() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters_are_rejected() {
+        assert!(Channel::amplitude_damping(1.5).is_err());
+        assert!(Channel::depolarizing(-0.1).is_err());
+        assert!(Channel::from_kraus(Vec::new()).is_err());
+
+        // A single non-unitary operator is not trace preserving
+        let half = ComplexMatrix::identity(2).scale(Complex64::new(0.5, 0.0));
+        assert!(Channel::from_kraus(vec![half]).is_err());
+    }
+
+    #[test]
+    fn test_custom_two_qubit_kraus_set() {
+        let cnot = ComplexMatrix::from_gate(&Gate::Cnot { control: 0, target: 1 }, 2).unwrap();
+        let identity = ComplexMatrix::identity(4);
+        let p = Complex64::new(0.5f64.sqrt(), 0.0);
+
+        let channel = Channel::from_kraus(vec![identity.scale(p), cnot.scale(p)]).unwrap();
+        assert_eq!(channel.num_qubits(), 2);
+
+        // |+⟩|0⟩ becomes an equal mixture of |+0⟩ and the Bell state
+        let mut rho = DensityMatrix::new(3).unwrap();
+        rho.apply(&Gate::H(0)).unwrap();
+        rho.apply_channel(&channel, &[0, 2]).unwrap();
+        assert!((rho.probability(0b101) - 0.25).abs() < 1e-12);
+        assert!((rho.probability(0b001) - 0.25).abs() < 1e-12);
+        assert!(rho.apply_channel(&channel, &[0]).is_err());
+    }
+
+    #[test]
+    fn test_composition_matches_sequential_application() {
+        let first = Channel::amplitude_damping(0.3).unwrap();
+        let second = Channel::phase_flip(0.2).unwrap();
+        let composed = first.then(&second).unwrap();
+
+        let mut sequential = DensityMatrix::new(1).unwrap();
+        sequential.apply_all(&[Gate::H(0), Gate::T(0)]).unwrap();
+        let mut combined = sequential.clone();
+
+        sequential.apply_channel(&first, &[0]).unwrap();
+        sequential.apply_channel(&second, &[0]).unwrap();
+        combined.apply_channel(&composed, &[0]).unwrap();
+
+        assert!(sequential.matrix().max_difference(combined.matrix()) < 1e-12);
+        assert_eq!(composed.kraus().len(), 4);
+    }
+
+    #[test]
+    fn test_local_application_matches_dense_embedding() {
+        let cnot = ComplexMatrix::from_gate(&Gate::Cnot { control: 0, target: 1 }, 2).unwrap();
+        let p = Complex64::new(0.5f64.sqrt(), 0.0);
+        let two_qubit = Channel::from_kraus(vec![ComplexMatrix::identity(4).scale(p), cnot.scale(p)]).unwrap();
+
+        let mut rho = DensityMatrix::new(3).unwrap();
+        rho.apply_all(&[Gate::H(0), Gate::Ry(1, 0.7), Gate::Cnot { control: 0, target: 2 }, Gate::T(2)]).unwrap();
+        rho.apply_channel(&Channel::amplitude_damping(0.4).unwrap(), &[1]).unwrap();
+
+        for (channel, targets) in [(two_qubit, vec![2, 0]), (Channel::depolarizing(0.3).unwrap(), vec![1])] {
+            let local = channel.apply(rho.matrix(), &targets, 3).unwrap();
+            let dense = channel.kraus().iter().fold(ComplexMatrix::zeros(8), |sum, k| {
+                let full = ComplexMatrix::embed(k, &targets, 3).unwrap();
+                &sum + &(&(&full * rho.matrix()) * &full.dagger())
+            });
+            assert!(local.max_difference(&dense) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_apply_rejects_mismatched_registers() {
+        let channel = Channel::bit_flip(0.1).unwrap();
+        let rho = DensityMatrix::new(2).unwrap();
+        assert!(channel.apply(rho.matrix(), &[0], 3).is_err());
+        assert!(channel.apply(rho.matrix(), &[0], 1).is_err());
+        assert!(channel.apply(rho.matrix(), &[0], 64).is_err());
+        assert!(channel.apply(rho.matrix(), &[2], 2).is_err());
+        assert!(channel.apply(rho.matrix(), &[1], 2).is_ok());
+    }
+}
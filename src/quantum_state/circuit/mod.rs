@@ -0,0 +1,230 @@
+//! Quantum Circuits
+//!
+//! This module provides the crate's circuit representation: an ordered list of
+//! gates, measurements and barriers over a fixed quantum and classical register,
+//! which can be executed on the state-vector simulator.
+
+#[cfg(test)]
+mod tests;
+
+use rand::Rng;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::gates::Gate;
+use crate::quantum_state::state_vector::StateVector;
+
+/// Single step of a circuit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation {
+    /// Apply a gate
+    Gate(Gate),
+
+    /// Measure a qubit into a classical bit
+    Measure {
+        /// Measured qubit
+        qubit: usize,
+        /// Classical bit receiving the outcome
+        bit: usize,
+    },
+
+    /// Reset a qubit to |0⟩
+    Reset(usize),
+
+    /// Scheduling barrier (no effect on simulation)
+    Barrier,
+}
+
+/// Quantum circuit over a fixed number of qubits and classical bits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circuit {
+    /// Number of qubits
+    num_qubits: usize,
+
+    /// Number of classical bits
+    num_bits: usize,
+
+    /// Operations in execution order
+    operations: Vec<Operation>,
+}
+
+/// Result of executing a circuit
+#[derive(Debug, Clone)]
+pub struct Execution {
+    /// Final (possibly collapsed) state
+    pub state: StateVector,
+
+    /// Classical bit values after all measurements
+    pub bits: Vec<u8>,
+}
+
+impl Circuit {
+    /// Create an empty circuit without classical bits
+    pub fn new(num_qubits: usize) -> Self {
+        Self::with_bits(num_qubits, 0)
+    }
+
+    /// Create an empty circuit with a classical register
+    pub fn with_bits(num_qubits: usize, num_bits: usize) -> Self {
+        Self {
+            num_qubits,
+            num_bits,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Get the number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Get the number of classical bits
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Get the operations in execution order
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    /// Get the number of operations
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Check if the circuit has no operations
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Append an operation after validating its qubit and bit indices
+    pub fn push(&mut self, operation: Operation) -> QuantumResult<&mut Self> {
+        match operation {
+            Operation::Gate(gate) => {
+                let qubits = gate.qubits();
+                for (i, q) in qubits.iter().enumerate() {
+                    self.check_qubit(*q)?;
+                    if qubits[..i].contains(q) {
+                        return Err(QuantumError::OperationError {
+                            message: format!("Qubit {} used more than once in {}", q, gate.name()),
+                        });
+                    }
+                }
+            }
+            Operation::Measure { qubit, bit } => {
+                self.check_qubit(qubit)?;
+                if bit >= self.num_bits {
+                    return Err(QuantumError::OperationError {
+                        message: format!("Classical bit {} out of range for {}-bit register", bit, self.num_bits),
+                    });
+                }
+            }
+            Operation::Reset(qubit) => self.check_qubit(qubit)?,
+            Operation::Barrier => {}
+        }
+
+        self.operations.push(operation);
+        Ok(self)
+    }
+
+    /// Append a gate
+    pub fn gate(&mut self, gate: Gate) -> QuantumResult<&mut Self> {
+        self.push(Operation::Gate(gate))
+    }
+
+    /// Append a measurement of a qubit into a classical bit
+    pub fn measure(&mut self, qubit: usize, bit: usize) -> QuantumResult<&mut Self> {
+        self.push(Operation::Measure { qubit, bit })
+    }
+
+    /// Get the gates of the circuit, skipping non-gate operations
+    pub fn gates(&self) -> impl Iterator<Item = &Gate> {
+        self.operations.iter().filter_map(|op| match op {
+            Operation::Gate(gate) => Some(gate),
+            _ => None,
+        })
+    }
+
+    /// Apply only the unitary part of the circuit to a state
+    ///
+    /// Fails if the circuit contains measurements or resets.
+    pub fn apply_unitary(&self, state: &mut StateVector) -> QuantumResult<()> {
+        for operation in &self.operations {
+            match operation {
+                Operation::Gate(gate) => state.apply(gate)?,
+                Operation::Barrier => {}
+                _ => {
+                    return Err(QuantumError::OperationError {
+                        message: "Circuit contains non-unitary operations".to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Execute the circuit on |0…0⟩, drawing measurement outcomes from `rng`
+    pub fn run<R: Rng + ?Sized>(&self, rng: &mut R) -> QuantumResult<Execution> {
+        let state = StateVector::new(self.num_qubits)?;
+        self.run_on(state, rng)
+    }
+
+    /// Execute the circuit on a given initial state
+    pub fn run_on<R: Rng + ?Sized>(&self, mut state: StateVector, rng: &mut R) -> QuantumResult<Execution> {
+        if state.num_qubits() != self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Circuit has {} qubits but state has {}", self.num_qubits, state.num_qubits()),
+            });
+        }
+
+        let mut bits = vec![0u8; self.num_bits];
+
+        for operation in &self.operations {
+            match *operation {
+                Operation::Gate(ref gate) => state.apply(gate)?,
+                Operation::Measure { qubit, bit } => bits[bit] = state.measure(qubit, rng)?,
+                Operation::Reset(qubit) => {
+                    if state.measure(qubit, rng)? == 1 {
+                        state.apply(&Gate::X(qubit))?;
+                    }
+                }
+                Operation::Barrier => {}
+            }
+        }
+
+        Ok(Execution { state, bits })
+    }
+
+    /// Get the inverse circuit (gates reversed and inverted)
+    ///
+    /// Fails if the circuit contains measurements or resets.
+    pub fn inverse(&self) -> QuantumResult<Circuit> {
+        let mut inverse = Circuit::with_bits(self.num_qubits, self.num_bits);
+
+        for operation in self.operations.iter().rev() {
+            match operation {
+                Operation::Gate(gate) => inverse.operations.push(Operation::Gate(gate.inverse())),
+                Operation::Barrier => inverse.operations.push(Operation::Barrier),
+                _ => {
+                    return Err(QuantumError::OperationError {
+                        message: "Cannot invert a circuit with measurements or resets".to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(inverse)
+    }
+
+    /// Ensure a qubit index is in range
+    fn check_qubit(&self, qubit: usize) -> QuantumResult<()> {
+        if qubit >= self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Qubit {} out of range for {}-qubit circuit", qubit, self.num_qubits),
+            });
+        }
+
+        Ok(())
+    }
+}
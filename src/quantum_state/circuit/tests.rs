@@ -0,0 +1,76 @@
+//! Unit tests for the circuit module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::circuit::{Circuit, Operation};
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::state_vector::StateVector;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn bell_circuit() -> Circuit {
+        let mut circuit = Circuit::with_bits(2, 2);
+        circuit.gate(Gate::H(0)).unwrap()
+            .gate(Gate::Cnot { control: 0, target: 1 }).unwrap()
+            .measure(0, 0).unwrap()
+            .measure(1, 1).unwrap();
+        circuit
+    }
+
+    #[test]
+    fn test_bell_measurements_are_correlated() {
+        let circuit = bell_circuit();
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut ones = 0;
+
+        for _ in 0..200 {
+            let execution = circuit.run(&mut rng).unwrap();
+            assert_eq!(execution.bits[0], execution.bits[1]);
+            ones += execution.bits[0] as usize;
+        }
+
+        assert!(ones > 60 && ones < 140);
+    }
+
+    #[test]
+    fn test_push_validates_indices() {
+        let mut circuit = Circuit::with_bits(2, 1);
+        assert!(circuit.gate(Gate::X(2)).is_err());
+        assert!(circuit.gate(Gate::Cnot { control: 1, target: 1 }).is_err());
+        assert!(circuit.measure(0, 1).is_err());
+        assert!(circuit.push(Operation::Reset(5)).is_err());
+        assert!(circuit.is_empty());
+    }
+
+    #[test]
+    fn test_inverse_circuit_restores_state() {
+        let mut circuit = Circuit::new(3);
+        circuit.gate(Gate::H(0)).unwrap()
+            .gate(Gate::T(1)).unwrap()
+            .gate(Gate::Rx(2, 0.4)).unwrap()
+            .gate(Gate::Toffoli { controls: [0, 1], target: 2 }).unwrap()
+            .gate(Gate::S(0)).unwrap();
+
+        let mut state = StateVector::new(3).unwrap();
+        circuit.apply_unitary(&mut state).unwrap();
+        circuit.inverse().unwrap().apply_unitary(&mut state).unwrap();
+
+        assert!((state.probability(0) - 1.0).abs() < 1e-12);
+        assert!(bell_circuit().inverse().is_err());
+        assert_eq!(circuit.gates().count(), 5);
+    }
+
+    #[test]
+    fn test_reset_returns_qubit_to_zero() {
+        let mut circuit = Circuit::with_bits(1, 1);
+        circuit.gate(Gate::H(0)).unwrap()
+            .push(Operation::Reset(0)).unwrap()
+            .measure(0, 0).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            assert_eq!(circuit.run(&mut rng).unwrap().bits[0], 0);
+        }
+    }
+}
@@ -0,0 +1,484 @@
+//! Density Matrices
+//!
+//! This module provides mixed-state simulation: density matrices built from
+//! pure states or ensembles, gate and channel evolution, partial trace, and
+//! the standard coherence measures (l1-norm, relative entropy of coherence,
+//! purity) that can seed a coherence field with a physically defined value.
+
+#[cfg(test)]
+mod tests;
+
+use num::complex::Complex64;
+use rand::Rng;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::coherence::Field as CoherenceField;
+use crate::quantum_state::channels::Channel;
+use crate::quantum_state::gates::{c, Gate, Matrix2};
+use crate::quantum_state::matrix::ComplexMatrix;
+use crate::quantum_state::state_vector::{StateVector, MAX_QUBITS};
+
+/// Tolerance for trace and Hermiticity checks
+const STATE_TOLERANCE: f64 = 1e-9;
+
+/// Largest register stored as a dense density matrix
+pub const MAX_DENSITY_QUBITS: usize = MAX_QUBITS / 2;
+
+/// Coherence measures in the computational basis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoherenceMeasure {
+    /// l1-norm of coherence: Σ_{i≠j} |ρ_ij|
+    L1Norm,
+
+    /// Relative entropy of coherence: S(Δ(ρ)) - S(ρ), in bits
+    RelativeEntropy,
+
+    /// Purity Tr(ρ²)
+    Purity,
+}
+
+/// Mixed state of an n-qubit register
+///
+/// Rows and columns are indexed by basis state with qubit 0 as the least significant bit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DensityMatrix {
+    /// Number of qubits
+    num_qubits: usize,
+
+    /// Density operator of dimension 2^num_qubits
+    matrix: ComplexMatrix,
+}
+
+impl DensityMatrix {
+    /// Create the register in |0…0⟩⟨0…0|
+    pub fn new(num_qubits: usize) -> QuantumResult<Self> {
+        Self::from_state_vector(&StateVector::new(num_qubits)?)
+    }
+
+    /// Create the pure state |ψ⟩⟨ψ|
+    pub fn from_state_vector(state: &StateVector) -> QuantumResult<Self> {
+        check_size(state.num_qubits())?;
+        let amplitudes = state.amplitudes();
+
+        Ok(Self {
+            num_qubits: state.num_qubits(),
+            matrix: ComplexMatrix::from_fn(amplitudes.len(), |i, j| amplitudes[i] * amplitudes[j].conj()),
+        })
+    }
+
+    /// Create the maximally mixed state I/2^n
+    pub fn maximally_mixed(num_qubits: usize) -> QuantumResult<Self> {
+        check_size(num_qubits)?;
+        let dimension = 1usize << num_qubits;
+
+        Ok(Self {
+            num_qubits,
+            matrix: ComplexMatrix::identity(dimension).scale(c(1.0 / dimension as f64, 0.0)),
+        })
+    }
+
+    /// Create a statistical mixture Σ p_k |ψ_k⟩⟨ψ_k|
+    pub fn from_ensemble(ensemble: &[(f64, StateVector)]) -> QuantumResult<Self> {
+        let num_qubits = match ensemble.first() {
+            Some((_, state)) => state.num_qubits(),
+            None => {
+                return Err(QuantumError::OperationError {
+                    message: "An ensemble needs at least one state".to_string(),
+                })
+            }
+        };
+
+        if ensemble.iter().any(|(p, s)| *p < 0.0 || s.num_qubits() != num_qubits) {
+            return Err(QuantumError::OperationError {
+                message: "Ensemble weights must be non-negative over equal-sized registers".to_string(),
+            });
+        }
+
+        let mut matrix = ComplexMatrix::zeros(1 << num_qubits);
+        for (p, state) in ensemble {
+            matrix = &matrix + &Self::from_state_vector(state)?.matrix.scale(c(*p, 0.0));
+        }
+
+        Self::from_matrix(matrix)
+    }
+
+    /// Create a density matrix from an explicit operator
+    ///
+    /// The operator must be Hermitian, unit trace and positive semidefinite.
+    pub fn from_matrix(matrix: ComplexMatrix) -> QuantumResult<Self> {
+        let dimension = matrix.size();
+        if dimension < 2 || !dimension.is_power_of_two() {
+            return Err(QuantumError::OperationError {
+                message: format!("Density matrix dimension {} is not 2^n", dimension),
+            });
+        }
+
+        let num_qubits = dimension.trailing_zeros() as usize;
+        check_size(num_qubits)?;
+
+        if !matrix.is_hermitian(STATE_TOLERANCE) || (matrix.trace().re - 1.0).abs() > STATE_TOLERANCE {
+            return Err(QuantumError::OperationError {
+                message: "Density matrix must be Hermitian with unit trace".to_string(),
+            });
+        }
+
+        let (values, _) = matrix.eigh()?;
+        if values[0] < -STATE_TOLERANCE {
+            return Err(QuantumError::OperationError {
+                message: format!("Density matrix has negative eigenvalue {}", values[0]),
+            });
+        }
+
+        Ok(Self { num_qubits, matrix })
+    }
+
+    /// Get the number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Get the Hilbert space dimension
+    pub fn dimension(&self) -> usize {
+        self.matrix.size()
+    }
+
+    /// Get the density operator
+    pub fn matrix(&self) -> &ComplexMatrix {
+        &self.matrix
+    }
+
+    /// Replace the density operator without validation
+    pub(crate) fn set_matrix(&mut self, matrix: ComplexMatrix) {
+        self.matrix = matrix;
+    }
+
+    /// Get a matrix element ρ_ij
+    pub fn element(&self, row: usize, column: usize) -> Complex64 {
+        self.matrix.get(row, column)
+    }
+
+    /// Probability of measuring a basis state
+    pub fn probability(&self, index: usize) -> f64 {
+        self.matrix.get(index, index).re
+    }
+
+    /// Probabilities of all basis states
+    pub fn probabilities(&self) -> Vec<f64> {
+        (0..self.dimension()).map(|i| self.probability(i)).collect()
+    }
+
+    /// Trace (1.0 for a valid state)
+    pub fn trace(&self) -> f64 {
+        self.matrix.trace().re
+    }
+
+    /// Eigenvalues of the density operator in ascending order
+    pub fn eigenvalues(&self) -> QuantumResult<Vec<f64>> {
+        Ok(self.matrix.eigh()?.0)
+    }
+
+    /// Check whether the state is pure within a tolerance
+    pub fn is_pure(&self, tolerance: f64) -> bool {
+        (self.purity() - 1.0).abs() <= tolerance
+    }
+
+    /// Apply a gate: ρ → UρU†
+    ///
+    /// Gates act locally on the affected index pairs, as in the state-vector
+    /// simulator, so each gate costs O(4^n) rather than a dense 2^n×2^n product.
+    pub fn apply(&mut self, gate: &Gate) -> QuantumResult<()> {
+        let qubits = gate.qubits();
+        for (i, &q) in qubits.iter().enumerate() {
+            self.check_qubit(q)?;
+            if qubits[..i].contains(&q) {
+                return Err(QuantumError::OperationError {
+                    message: format!("Qubit {} used more than once in one operation", q),
+                });
+            }
+        }
+
+        match (gate.matrix(), *gate) {
+            (Some(matrix), _) => {
+                let (controls, target) = qubits.split_at(qubits.len() - 1);
+                self.apply_controlled(controls, target[0], &matrix);
+            }
+            (None, Gate::Swap(a, b)) => {
+                let (ma, mb) = (1usize << a, 1usize << b);
+                let swapped = |i: usize| if (i & ma != 0) != (i & mb != 0) { i ^ ma ^ mb } else { i };
+                let matrix = &self.matrix;
+                self.matrix = ComplexMatrix::from_fn(self.dimension(), |i, j| matrix.get(swapped(i), swapped(j)));
+            }
+            (None, gate) => {
+                return Err(QuantumError::OperationError {
+                    message: format!("Gate {} has no simulation rule", gate.name()),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply a sequence of gates in order
+    pub fn apply_all(&mut self, gates: &[Gate]) -> QuantumResult<()> {
+        for gate in gates {
+            self.apply(gate)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a channel to the given target qubits
+    pub fn apply_channel(&mut self, channel: &Channel, targets: &[usize]) -> QuantumResult<()> {
+        self.matrix = channel.apply(&self.matrix, targets, self.num_qubits)?;
+        Ok(())
+    }
+
+    /// Apply a single-qubit channel independently to every qubit
+    pub fn apply_channel_to_all(&mut self, channel: &Channel) -> QuantumResult<()> {
+        for qubit in 0..self.num_qubits {
+            self.apply_channel(channel, &[qubit])?;
+        }
+        Ok(())
+    }
+
+    /// Probability of measuring a qubit as |1⟩
+    pub fn probability_one(&self, qubit: usize) -> QuantumResult<f64> {
+        self.check_qubit(qubit)?;
+
+        Ok((0..self.dimension())
+            .filter(|i| i & (1 << qubit) != 0)
+            .map(|i| self.probability(i))
+            .sum())
+    }
+
+    /// Measure a qubit in the computational basis, collapsing the state
+    pub fn measure<R: Rng + ?Sized>(&mut self, qubit: usize, rng: &mut R) -> QuantumResult<u8> {
+        let p1 = self.probability_one(qubit)?;
+        let outcome = if rng.gen::<f64>() < p1 { 1 } else { 0 };
+        let p = if outcome == 1 { p1 } else { 1.0 - p1 };
+        let mask = 1usize << qubit;
+
+        // ρ → PρP / p with P projecting onto the outcome
+        let keep = |i: usize| ((i & mask != 0) as u8) == outcome;
+        self.matrix = ComplexMatrix::from_fn(self.dimension(), |i, j| {
+            if keep(i) && keep(j) {
+                self.matrix.get(i, j) / p
+            } else {
+                c(0.0, 0.0)
+            }
+        });
+
+        Ok(outcome)
+    }
+
+    /// Partial transpose over the given qubits
+    ///
+    /// The result is Hermitian with unit trace but need not be positive, so
+    /// it is returned as a plain matrix; negative eigenvalues witness
+    /// entanglement across the cut.
+    pub fn partial_transpose(&self, qubits: &[usize]) -> QuantumResult<ComplexMatrix> {
+        let mut mask = 0usize;
+        for &q in qubits {
+            self.check_qubit(q)?;
+            mask |= 1 << q;
+        }
+
+        // Swap the row and column bits of the transposed qubits
+        Ok(ComplexMatrix::from_fn(self.dimension(), |i, j| {
+            let row = (i & !mask) | (j & mask);
+            let column = (j & !mask) | (i & mask);
+            self.matrix.get(row, column)
+        }))
+    }
+
+    /// Reduced state of the `keep` qubits, tracing out all others
+    ///
+    /// `keep[0]` becomes qubit 0 of the reduced register.
+    pub fn partial_trace(&self, keep: &[usize]) -> QuantumResult<DensityMatrix> {
+        if keep.is_empty() {
+            return Err(QuantumError::OperationError {
+                message: "Partial trace must keep at least one qubit".to_string(),
+            });
+        }
+
+        for (i, &q) in keep.iter().enumerate() {
+            if q >= self.num_qubits || keep[..i].contains(&q) {
+                return Err(QuantumError::OperationError {
+                    message: format!("Invalid qubit {} for {}-qubit partial trace", q, self.num_qubits),
+                });
+            }
+        }
+
+        let traced: Vec<usize> = (0..self.num_qubits).filter(|q| !keep.contains(q)).collect();
+        let compose = |kept: usize, env: usize| {
+            let mut index = 0usize;
+            for (k, q) in keep.iter().enumerate() {
+                index |= ((kept >> k) & 1) << q;
+            }
+            for (k, q) in traced.iter().enumerate() {
+                index |= ((env >> k) & 1) << q;
+            }
+            index
+        };
+
+        let matrix = ComplexMatrix::from_fn(1 << keep.len(), |i, j| {
+            (0..1usize << traced.len())
+                .map(|e| self.matrix.get(compose(i, e), compose(j, e)))
+                .sum()
+        });
+
+        Ok(Self { num_qubits: keep.len(), matrix })
+    }
+
+    /// Trace out the given qubits, keeping the rest in ascending order
+    pub fn trace_out(&self, qubits: &[usize]) -> QuantumResult<DensityMatrix> {
+        let keep: Vec<usize> = (0..self.num_qubits).filter(|q| !qubits.contains(q)).collect();
+        self.partial_trace(&keep)
+    }
+
+    /// Expectation value Tr(ρO) of a Hermitian observable
+    pub fn expectation(&self, observable: &ComplexMatrix) -> QuantumResult<f64> {
+        if observable.size() != self.dimension() {
+            return Err(QuantumError::OperationError {
+                message: format!("Observable of size {} does not match dimension {}", observable.size(), self.dimension()),
+            });
+        }
+
+        Ok((&self.matrix * observable).trace().re)
+    }
+
+    /// Fidelity with a pure state: ⟨ψ|ρ|ψ⟩
+    pub fn fidelity_with_state(&self, state: &StateVector) -> QuantumResult<f64> {
+        if state.num_qubits() != self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: "Fidelity requires registers of equal size".to_string(),
+            });
+        }
+
+        let amplitudes = state.amplitudes();
+        let projected = self.matrix.apply(amplitudes);
+        let value: Complex64 = amplitudes.iter().zip(&projected).map(|(a, b)| a.conj() * b).sum();
+        Ok(value.re)
+    }
+
+    /// Purity Tr(ρ²), from 1/2^n (maximally mixed) to 1 (pure)
+    pub fn purity(&self) -> f64 {
+        // Tr(ρ²) = Σ |ρ_ij|² for Hermitian ρ
+        self.matrix.data().iter().map(|v| v.norm_sqr()).sum()
+    }
+
+    /// Von Neumann entropy S(ρ) = -Tr(ρ log₂ ρ), in bits
+    pub fn von_neumann_entropy(&self) -> QuantumResult<f64> {
+        Ok(shannon_entropy(&self.eigenvalues()?))
+    }
+
+    /// l1-norm of coherence: Σ_{i≠j} |ρ_ij|
+    pub fn l1_coherence(&self) -> f64 {
+        let n = self.dimension();
+        let mut total = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    total += self.matrix.get(i, j).norm();
+                }
+            }
+        }
+        total
+    }
+
+    /// Relative entropy of coherence: S(Δ(ρ)) - S(ρ), in bits
+    ///
+    /// Δ(ρ) is the fully dephased state keeping only the diagonal.
+    pub fn relative_entropy_of_coherence(&self) -> QuantumResult<f64> {
+        let dephased = shannon_entropy(&self.probabilities());
+        Ok((dephased - self.von_neumann_entropy()?).max(0.0))
+    }
+
+    /// Value of a coherence measure
+    pub fn coherence(&self, measure: CoherenceMeasure) -> QuantumResult<f64> {
+        match measure {
+            CoherenceMeasure::L1Norm => Ok(self.l1_coherence()),
+            CoherenceMeasure::RelativeEntropy => self.relative_entropy_of_coherence(),
+            CoherenceMeasure::Purity => Ok(self.purity()),
+        }
+    }
+
+    /// Coherence measure rescaled to the range 0.0 - 1.0
+    ///
+    /// l1 is divided by 2^n - 1, relative entropy by n bits, and purity is
+    /// mapped linearly from [1/2^n, 1].
+    pub fn normalized_coherence(&self, measure: CoherenceMeasure) -> QuantumResult<f64> {
+        let d = self.dimension() as f64;
+        let value = self.coherence(measure)?;
+
+        let normalized = match measure {
+            CoherenceMeasure::L1Norm => value / (d - 1.0),
+            CoherenceMeasure::RelativeEntropy => value / self.num_qubits as f64,
+            CoherenceMeasure::Purity => (d * value - 1.0) / (d - 1.0),
+        };
+
+        Ok(normalized.clamp(0.0, 1.0))
+    }
+
+    /// Create a coherence field whose coherence is the given normalized measure
+    pub fn coherence_field(&self, measure: CoherenceMeasure) -> QuantumResult<CoherenceField> {
+        Ok(CoherenceField::with_coherence(self.normalized_coherence(measure)?))
+    }
+
+    /// Apply a 2×2 matrix to a target qubit, conditioned on all controls being |1⟩
+    ///
+    /// Mixes row pairs (ρ → Uρ), then column pairs with conjugated entries (ρ → ρU†).
+    fn apply_controlled(&mut self, controls: &[usize], target: usize, m: &Matrix2) {
+        let control_mask = controls.iter().fold(0usize, |mask, q| mask | (1 << q));
+        let target_mask = 1usize << target;
+        let dimension = self.dimension();
+        let pairs: Vec<usize> = (0..dimension)
+            .filter(|i| i & target_mask == 0 && i & control_mask == control_mask)
+            .collect();
+
+        for &i in &pairs {
+            let k = i | target_mask;
+            for j in 0..dimension {
+                let (a0, a1) = (self.matrix.get(i, j), self.matrix.get(k, j));
+                self.matrix.set(i, j, m[0][0] * a0 + m[0][1] * a1);
+                self.matrix.set(k, j, m[1][0] * a0 + m[1][1] * a1);
+            }
+        }
+
+        for &j in &pairs {
+            let k = j | target_mask;
+            for i in 0..dimension {
+                let (b0, b1) = (self.matrix.get(i, j), self.matrix.get(i, k));
+                self.matrix.set(i, j, b0 * m[0][0].conj() + b1 * m[0][1].conj());
+                self.matrix.set(i, k, b0 * m[1][0].conj() + b1 * m[1][1].conj());
+            }
+        }
+    }
+
+    /// Ensure a qubit index is inside the register
+    fn check_qubit(&self, qubit: usize) -> QuantumResult<()> {
+        if qubit >= self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Qubit {} out of range for {}-qubit density matrix", qubit, self.num_qubits),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Shannon entropy in bits of a probability distribution
+pub(crate) fn shannon_entropy(probabilities: &[f64]) -> f64 {
+    probabilities.iter()
+        .filter(|p| **p > 1e-15)
+        .map(|p| -p * p.log2())
+        .sum()
+}
+
+/// Ensure a register fits in a dense density matrix
+fn check_size(num_qubits: usize) -> QuantumResult<()> {
+    if num_qubits == 0 || num_qubits > MAX_DENSITY_QUBITS {
+        return Err(QuantumError::OperationError {
+            message: format!("Density matrices support 1 to {} qubits, got {}", MAX_DENSITY_QUBITS, num_qubits),
+        });
+    }
+    Ok(())
+}
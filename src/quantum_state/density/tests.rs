@@ -0,0 +1,223 @@
+//! Unit tests for the density module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::channels::Channel;
+    use crate::quantum_state::density::{CoherenceMeasure, DensityMatrix};
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use crate::quantum_state::state_vector::StateVector;
+    use num::complex::Complex64;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn bell() -> DensityMatrix {
+        let mut rho = DensityMatrix::new(2).unwrap();
+        rho.apply_all(&[Gate::H(0), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+        rho
+    }
+
+    fn plus() -> DensityMatrix {
+        let mut rho = DensityMatrix::new(1).unwrap();
+        rho.apply(&Gate::H(0)).unwrap();
+        rho
+    }
+
+    #[test]
+    fn test_pure_state_matches_state_vector() {
+        let mut state = StateVector::new(2).unwrap();
+        state.apply_all(&[Gate::H(0), Gate::T(0), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+
+        let rho = DensityMatrix::from_state_vector(&state).unwrap();
+        assert!((rho.trace() - 1.0).abs() < 1e-12);
+        assert!(rho.is_pure(1e-12));
+        assert!((rho.fidelity_with_state(&state).unwrap() - 1.0).abs() < 1e-12);
+        assert_eq!(rho.probabilities().len(), 4);
+        assert!((rho.probability(3) - state.probability(3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_matrix_validation() {
+        let c = |re| Complex64::new(re, 0.0);
+        let valid = ComplexMatrix::diagonal(&[c(0.25), c(0.75)]);
+        assert!(DensityMatrix::from_matrix(valid).is_ok());
+
+        let negative = ComplexMatrix::diagonal(&[c(-0.5), c(1.5)]);
+        assert!(DensityMatrix::from_matrix(negative).is_err());
+
+        let unnormalized = ComplexMatrix::diagonal(&[c(0.5), c(0.75)]);
+        assert!(DensityMatrix::from_matrix(unnormalized).is_err());
+
+        assert!(DensityMatrix::from_matrix(ComplexMatrix::identity(3).scale(c(1.0 / 3.0))).is_err());
+    }
+
+    #[test]
+    fn test_partial_trace_of_bell_state_is_maximally_mixed() {
+        let reduced = bell().partial_trace(&[0]).unwrap();
+        assert!(reduced.matrix().max_difference(DensityMatrix::maximally_mixed(1).unwrap().matrix()) < 1e-12);
+        assert!((reduced.purity() - 0.5).abs() < 1e-12);
+        assert!((reduced.von_neumann_entropy().unwrap() - 1.0).abs() < 1e-9);
+        assert!(bell().partial_trace(&[2]).is_err());
+    }
+
+    #[test]
+    fn test_partial_trace_of_product_state() {
+        // |+⟩ on qubit 0, |1⟩ on qubit 1, |0⟩ on qubit 2
+        let mut rho = DensityMatrix::new(3).unwrap();
+        rho.apply_all(&[Gate::H(0), Gate::X(1)]).unwrap();
+
+        let q0 = rho.partial_trace(&[0]).unwrap();
+        assert!(q0.matrix().max_difference(plus().matrix()) < 1e-12);
+
+        let q1 = rho.trace_out(&[0, 2]).unwrap();
+        assert!((q1.probability(1) - 1.0).abs() < 1e-12);
+
+        // Reordered reduction puts qubit 1 in the least significant position
+        let swapped = rho.partial_trace(&[1, 0]).unwrap();
+        assert!((swapped.probability(0b01) - 0.5).abs() < 1e-12);
+        assert!((swapped.probability(0b11) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_amplitude_damping_relaxes_to_ground() {
+        let mut rho = DensityMatrix::new(1).unwrap();
+        rho.apply(&Gate::X(0)).unwrap();
+
+        rho.apply_channel(&Channel::amplitude_damping(0.3).unwrap(), &[0]).unwrap();
+        assert!((rho.probability(1) - 0.7).abs() < 1e-12);
+
+        rho.apply_channel(&Channel::amplitude_damping(1.0).unwrap(), &[0]).unwrap();
+        assert!((rho.probability(0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_phase_damping_preserves_populations() {
+        let mut rho = plus();
+        rho.apply_channel(&Channel::phase_damping(0.36).unwrap(), &[0]).unwrap();
+
+        assert!((rho.probability(0) - 0.5).abs() < 1e-12);
+        assert!((rho.element(0, 1).re - 0.5 * 0.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_depolarizing_mixes_state() {
+        let mut rho = plus();
+        rho.apply_channel(&Channel::depolarizing(1.0).unwrap(), &[0]).unwrap();
+        assert!(rho.matrix().max_difference(DensityMatrix::maximally_mixed(1).unwrap().matrix()) < 1e-12);
+
+        let mut partial = plus();
+        partial.apply_channel(&Channel::depolarizing(0.5).unwrap(), &[0]).unwrap();
+        assert!((partial.element(0, 1).re - 0.25).abs() < 1e-12);
+        assert!((partial.trace() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_coherence_measures() {
+        let rho = plus();
+        assert!((rho.l1_coherence() - 1.0).abs() < 1e-12);
+        assert!((rho.relative_entropy_of_coherence().unwrap() - 1.0).abs() < 1e-9);
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+
+        let mixed = DensityMatrix::maximally_mixed(2).unwrap();
+        assert_eq!(mixed.l1_coherence(), 0.0);
+        assert!(mixed.relative_entropy_of_coherence().unwrap().abs() < 1e-9);
+        assert!((mixed.purity() - 0.25).abs() < 1e-12);
+        assert!(mixed.normalized_coherence(CoherenceMeasure::Purity).unwrap().abs() < 1e-12);
+
+        // Bell state: l1 = 1 of a possible 3, one bit of relative-entropy coherence of 2
+        let bell = bell();
+        assert!((bell.normalized_coherence(CoherenceMeasure::L1Norm).unwrap() - 1.0 / 3.0).abs() < 1e-12);
+        assert!((bell.normalized_coherence(CoherenceMeasure::RelativeEntropy).unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_coherence_agrees_with_state_vector() {
+        let mut state = StateVector::new(2).unwrap();
+        state.apply_all(&[Gate::Ry(0, 0.7), Gate::Rx(1, 1.3), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+        let rho = DensityMatrix::from_state_vector(&state).unwrap();
+
+        assert!((rho.l1_coherence() - state.l1_coherence()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_coherence_field_bridge() {
+        let mut rho = plus();
+        let field = rho.coherence_field(CoherenceMeasure::L1Norm).unwrap();
+        assert!((field.coherence() - 1.0).abs() < 1e-12);
+
+        // Dephasing lowers the field's coherence
+        rho.apply_channel(&Channel::phase_damping(0.75).unwrap(), &[0]).unwrap();
+        let dephased = rho.coherence_field(CoherenceMeasure::L1Norm).unwrap();
+        assert!((dephased.coherence() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ensemble_construction() {
+        let zero = StateVector::new(1).unwrap();
+        let one = StateVector::basis(1, 1).unwrap();
+
+        let rho = DensityMatrix::from_ensemble(&[(0.5, zero.clone()), (0.5, one)]).unwrap();
+        assert!((rho.purity() - 0.5).abs() < 1e-12);
+        assert!(DensityMatrix::from_ensemble(&[(0.5, zero)]).is_err());
+    }
+
+    #[test]
+    fn test_measurement_collapses_partner() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..10 {
+            let mut rho = bell();
+            let outcome = rho.measure(0, &mut rng).unwrap();
+
+            // The Bell partner always agrees
+            assert!((rho.probability_one(1).unwrap() - outcome as f64).abs() < 1e-12);
+            assert!((rho.trace() - 1.0).abs() < 1e-12);
+            assert!(rho.is_pure(1e-12));
+        }
+        assert!(bell().measure(2, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_partial_transpose_detects_entanglement() {
+        let transposed = bell().partial_transpose(&[0]).unwrap();
+        let (eigenvalues, _) = transposed.eigh().unwrap();
+        assert!((eigenvalues[0] + 0.5).abs() < 1e-12);
+        assert!((transposed.trace().re - 1.0).abs() < 1e-12);
+
+        // Product states stay positive, and transposing both halves is the full transpose
+        let mut product = DensityMatrix::new(2).unwrap();
+        product.apply_all(&[Gate::H(0), Gate::Ry(1, 0.4)]).unwrap();
+        assert!(product.partial_transpose(&[1]).unwrap().eigh().unwrap().0[0] > -1e-12);
+        let full = product.partial_transpose(&[0, 1]).unwrap();
+        let transpose = ComplexMatrix::from_fn(4, |i, j| product.element(j, i));
+        assert!(full.max_difference(&transpose) < 1e-15);
+    }
+
+    #[test]
+    fn test_local_gates_match_dense_unitaries() {
+        let mut rho = DensityMatrix::new(3).unwrap();
+        rho.apply_all(&[Gate::H(0), Gate::Ry(1, 0.7), Gate::Cnot { control: 0, target: 2 }]).unwrap();
+        rho.apply_channel(&Channel::amplitude_damping(0.3).unwrap(), &[2]).unwrap();
+        rho.apply_channel(&Channel::depolarizing(0.2).unwrap(), &[1]).unwrap();
+
+        let gates = [
+            Gate::Y(2),
+            Gate::Rx(1, 1.3),
+            Gate::T(0),
+            Gate::CPhase { control: 2, target: 0, angle: 0.4 },
+            Gate::Cz { control: 1, target: 2 },
+            Gate::Swap(0, 2),
+            Gate::Toffoli { controls: [2, 0], target: 1 },
+        ];
+        for gate in &gates {
+            let unitary = ComplexMatrix::from_gate(gate, 3).unwrap();
+            let expected = &(&unitary * rho.matrix()) * &unitary.dagger();
+
+            rho.apply(gate).unwrap();
+            assert!(rho.matrix().max_difference(&expected) < 1e-12, "{:?}", gate);
+        }
+
+        assert!(rho.apply(&Gate::Cnot { control: 1, target: 1 }).is_err());
+        assert!(rho.apply(&Gate::H(3)).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+//! Quantum Gates
+//!
+//! This module provides the standard gate library for the state-vector
+//! simulator: Pauli, Hadamard, phase, rotation and controlled gates, plus
+//! arbitrary single-qubit unitaries.
+
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_4};
+
+use num::complex::Complex64;
+
+/// 2×2 complex matrix in row-major order
+pub type Matrix2 = [[Complex64; 2]; 2];
+
+/// Build a complex number from real and imaginary parts
+pub(crate) fn c(re: f64, im: f64) -> Complex64 {
+    Complex64::new(re, im)
+}
+
+/// Multiply two 2×2 matrices
+pub fn matmul2(a: &Matrix2, b: &Matrix2) -> Matrix2 {
+    let mut result = [[c(0.0, 0.0); 2]; 2];
+
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = a[i][0] * b[0][j] + a[i][1] * b[1][j];
+        }
+    }
+
+    result
+}
+
+/// Conjugate transpose of a 2×2 matrix
+pub fn dagger2(m: &Matrix2) -> Matrix2 {
+    [
+        [m[0][0].conj(), m[1][0].conj()],
+        [m[0][1].conj(), m[1][1].conj()],
+    ]
+}
+
+/// Check whether a 2×2 matrix is unitary within a tolerance
+pub fn is_unitary2(m: &Matrix2, tolerance: f64) -> bool {
+    let product = matmul2(&dagger2(m), m);
+
+    (0..2).all(|i| (0..2).all(|j| {
+        let expected = if i == j { 1.0 } else { 0.0 };
+        (product[i][j] - c(expected, 0.0)).norm() <= tolerance
+    }))
+}
+
+/// Quantum gates acting on qubits identified by index
+///
+/// Qubit 0 is the least significant bit of a basis-state index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gate {
+    /// Hadamard gate
+    H(usize),
+
+    /// Pauli-X (NOT) gate
+    X(usize),
+
+    /// Pauli-Y gate
+    Y(usize),
+
+    /// Pauli-Z gate
+    Z(usize),
+
+    /// Phase gate (√Z)
+    S(usize),
+
+    /// Inverse phase gate (S†)
+    Sdg(usize),
+
+    /// π/8 gate (√S)
+    T(usize),
+
+    /// Inverse π/8 gate (T†)
+    Tdg(usize),
+
+    /// Rotation about the X axis by an angle (radians)
+    Rx(usize, f64),
+
+    /// Rotation about the Y axis by an angle (radians)
+    Ry(usize, f64),
+
+    /// Rotation about the Z axis by an angle (radians)
+    Rz(usize, f64),
+
+    /// Phase shift diag(1, e^{iθ})
+    Phase(usize, f64),
+
+    /// Arbitrary single-qubit unitary
+    Unitary(usize, Matrix2),
+
+    /// Controlled-NOT gate
+    Cnot {
+        /// Control qubit
+        control: usize,
+        /// Target qubit
+        target: usize,
+    },
+
+    /// Controlled-Z gate
+    Cz {
+        /// Control qubit
+        control: usize,
+        /// Target qubit
+        target: usize,
+    },
+
+    /// Controlled phase shift
+    CPhase {
+        /// Control qubit
+        control: usize,
+        /// Target qubit
+        target: usize,
+        /// Phase angle (radians)
+        angle: f64,
+    },
+
+    /// SWAP gate
+    Swap(usize, usize),
+
+    /// Toffoli (controlled-controlled-NOT) gate
+    Toffoli {
+        /// Control qubits
+        controls: [usize; 2],
+        /// Target qubit
+        target: usize,
+    },
+}
+
+impl Gate {
+    /// Get the lowercase gate name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gate::H(_) => "h",
+            Gate::X(_) => "x",
+            Gate::Y(_) => "y",
+            Gate::Z(_) => "z",
+            Gate::S(_) => "s",
+            Gate::Sdg(_) => "sdg",
+            Gate::T(_) => "t",
+            Gate::Tdg(_) => "tdg",
+            Gate::Rx(..) => "rx",
+            Gate::Ry(..) => "ry",
+            Gate::Rz(..) => "rz",
+            Gate::Phase(..) => "p",
+            Gate::Unitary(..) => "u",
+            Gate::Cnot { .. } => "cx",
+            Gate::Cz { .. } => "cz",
+            Gate::CPhase { .. } => "cp",
+            Gate::Swap(..) => "swap",
+            Gate::Toffoli { .. } => "ccx",
+        }
+    }
+
+    /// Get the qubits the gate acts on, controls first
+    pub fn qubits(&self) -> Vec<usize> {
+        match *self {
+            Gate::H(q) | Gate::X(q) | Gate::Y(q) | Gate::Z(q)
+            | Gate::S(q) | Gate::Sdg(q) | Gate::T(q) | Gate::Tdg(q)
+            | Gate::Rx(q, _) | Gate::Ry(q, _) | Gate::Rz(q, _)
+            | Gate::Phase(q, _) | Gate::Unitary(q, _) => vec![q],
+            Gate::Cnot { control, target }
+            | Gate::Cz { control, target }
+            | Gate::CPhase { control, target, .. } => vec![control, target],
+            Gate::Swap(a, b) => vec![a, b],
+            Gate::Toffoli { controls, target } => vec![controls[0], controls[1], target],
+        }
+    }
+
+    /// Get the 2×2 matrix of a single-qubit gate, or of the target action of a controlled gate
+    pub fn matrix(&self) -> Option<Matrix2> {
+        let zero = c(0.0, 0.0);
+        let one = c(1.0, 0.0);
+
+        let m = match *self {
+            Gate::H(_) => {
+                let h = c(FRAC_1_SQRT_2, 0.0);
+                [[h, h], [h, -h]]
+            }
+            Gate::X(_) | Gate::Cnot { .. } | Gate::Toffoli { .. } => [[zero, one], [one, zero]],
+            Gate::Y(_) => [[zero, c(0.0, -1.0)], [c(0.0, 1.0), zero]],
+            Gate::Z(_) | Gate::Cz { .. } => [[one, zero], [zero, -one]],
+            Gate::S(_) => [[one, zero], [zero, c(0.0, 1.0)]],
+            Gate::Sdg(_) => [[one, zero], [zero, c(0.0, -1.0)]],
+            Gate::T(_) => [[one, zero], [zero, Complex64::from_polar(1.0, FRAC_PI_4)]],
+            Gate::Tdg(_) => [[one, zero], [zero, Complex64::from_polar(1.0, -FRAC_PI_4)]],
+            Gate::Rx(_, theta) => {
+                let (s, co) = (theta / 2.0).sin_cos();
+                [[c(co, 0.0), c(0.0, -s)], [c(0.0, -s), c(co, 0.0)]]
+            }
+            Gate::Ry(_, theta) => {
+                let (s, co) = (theta / 2.0).sin_cos();
+                [[c(co, 0.0), c(-s, 0.0)], [c(s, 0.0), c(co, 0.0)]]
+            }
+            Gate::Rz(_, theta) => [
+                [Complex64::from_polar(1.0, -theta / 2.0), zero],
+                [zero, Complex64::from_polar(1.0, theta / 2.0)],
+            ],
+            Gate::Phase(_, theta) | Gate::CPhase { angle: theta, .. } => {
+                [[one, zero], [zero, Complex64::from_polar(1.0, theta)]]
+            }
+            Gate::Unitary(_, m) => m,
+            Gate::Swap(..) => return None,
+        };
+
+        Some(m)
+    }
+
+    /// Get the inverse gate
+    pub fn inverse(&self) -> Gate {
+        match *self {
+            Gate::S(q) => Gate::Sdg(q),
+            Gate::Sdg(q) => Gate::S(q),
+            Gate::T(q) => Gate::Tdg(q),
+            Gate::Tdg(q) => Gate::T(q),
+            Gate::Rx(q, theta) => Gate::Rx(q, -theta),
+            Gate::Ry(q, theta) => Gate::Ry(q, -theta),
+            Gate::Rz(q, theta) => Gate::Rz(q, -theta),
+            Gate::Phase(q, theta) => Gate::Phase(q, -theta),
+            Gate::Unitary(q, m) => Gate::Unitary(q, dagger2(&m)),
+            Gate::CPhase { control, target, angle } => Gate::CPhase { control, target, angle: -angle },
+            // Remaining gates are self-inverse
+            gate => gate,
+        }
+    }
+}
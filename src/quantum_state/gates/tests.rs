@@ -0,0 +1,58 @@
+//! Unit tests for the gates module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::gates::{Gate, matmul2, dagger2, is_unitary2};
+
+    const ALL_SINGLE: [Gate; 12] = [
+        Gate::H(0), Gate::X(0), Gate::Y(0), Gate::Z(0),
+        Gate::S(0), Gate::Sdg(0), Gate::T(0), Gate::Tdg(0),
+        Gate::Rx(0, 0.7), Gate::Ry(0, -1.3), Gate::Rz(0, 2.1), Gate::Phase(0, 0.4),
+    ];
+
+    #[test]
+    fn test_single_qubit_gates_are_unitary() {
+        for gate in ALL_SINGLE {
+            assert!(is_unitary2(&gate.matrix().unwrap(), 1e-12), "{:?}", gate);
+        }
+    }
+
+    #[test]
+    fn test_inverse_undoes_gate() {
+        for gate in ALL_SINGLE {
+            let product = matmul2(&gate.inverse().matrix().unwrap(), &gate.matrix().unwrap());
+            assert!((product[0][0].re - 1.0).abs() < 1e-12);
+            assert!(product[0][1].norm() < 1e-12);
+            assert!((product[1][1].re - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gate_relations() {
+        // S² = Z and T² = S
+        let s = Gate::S(0).matrix().unwrap();
+        let z = Gate::Z(0).matrix().unwrap();
+        let t = Gate::T(0).matrix().unwrap();
+        assert!((matmul2(&s, &s)[1][1] - z[1][1]).norm() < 1e-12);
+        assert!((matmul2(&t, &t)[1][1] - s[1][1]).norm() < 1e-12);
+
+        // H X H = Z
+        let h = Gate::H(0).matrix().unwrap();
+        let x = Gate::X(0).matrix().unwrap();
+        let hxh = matmul2(&matmul2(&h, &x), &h);
+        assert!((hxh[0][0] - z[0][0]).norm() < 1e-12);
+        assert!((hxh[1][1] - z[1][1]).norm() < 1e-12);
+
+        assert_eq!(dagger2(&dagger2(&t)), t);
+    }
+
+    #[test]
+    fn test_gate_qubits_and_names() {
+        let toffoli = Gate::Toffoli { controls: [0, 1], target: 2 };
+        assert_eq!(toffoli.qubits(), vec![0, 1, 2]);
+        assert_eq!(toffoli.name(), "ccx");
+        assert_eq!(Gate::Cnot { control: 3, target: 1 }.qubits(), vec![3, 1]);
+        assert!(Gate::Swap(0, 1).matrix().is_none());
+    }
+}
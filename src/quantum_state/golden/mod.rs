@@ -0,0 +1,99 @@
+//! Golden-Angle Gates
+//!
+//! This module builds gates from the phi constants: single-qubit rotations
+//! by the golden angle 2π·λ about each Pauli axis, and entangling gates
+//! whose interaction strength is set by λ. Because λ is irrational, repeated
+//! golden rotations never return to their start and fill the circle evenly.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{GOLDEN_ANGLE, LAMBDA};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::gates::Gate;
+use crate::quantum_state::state_vector::Pauli;
+
+/// Rotation by `turns` golden angles about a Pauli axis
+///
+/// Negative turns rotate in the opposite sense.
+pub fn golden_rotation(axis: Pauli, qubit: usize, turns: i32) -> QuantumResult<Gate> {
+    let angle = GOLDEN_ANGLE * turns as f64;
+
+    match axis {
+        Pauli::X => Ok(Gate::Rx(qubit, angle)),
+        Pauli::Y => Ok(Gate::Ry(qubit, angle)),
+        Pauli::Z => Ok(Gate::Rz(qubit, angle)),
+        Pauli::I => Err(QuantumError::OperationError {
+            message: "Golden rotations need an X, Y or Z axis".to_string(),
+        }),
+    }
+}
+
+/// Golden-angle rotation about X
+pub fn golden_rx(qubit: usize) -> Gate {
+    Gate::Rx(qubit, GOLDEN_ANGLE)
+}
+
+/// Golden-angle rotation about Y
+pub fn golden_ry(qubit: usize) -> Gate {
+    Gate::Ry(qubit, GOLDEN_ANGLE)
+}
+
+/// Golden-angle rotation about Z
+pub fn golden_rz(qubit: usize) -> Gate {
+    Gate::Rz(qubit, GOLDEN_ANGLE)
+}
+
+/// Golden-angle phase shift diag(1, e^{i·2πλ})
+pub fn golden_phase(qubit: usize) -> Gate {
+    Gate::Phase(qubit, GOLDEN_ANGLE)
+}
+
+/// Controlled golden-angle phase shift
+pub fn golden_cphase(control: usize, target: usize) -> Gate {
+    Gate::CPhase { control, target, angle: GOLDEN_ANGLE }
+}
+
+/// Ising interaction exp(-i·θ/2·P⊗P) for P = X, Y or Z, as a gate sequence
+pub fn ising(axis: Pauli, a: usize, b: usize, theta: f64) -> QuantumResult<Vec<Gate>> {
+    // Rotate each qubit so the chosen axis becomes Z, apply ZZ, then rotate back
+    let (into, out): (Vec<Gate>, Vec<Gate>) = match axis {
+        Pauli::Z => (Vec::new(), Vec::new()),
+        Pauli::X => (vec![Gate::H(a), Gate::H(b)], vec![Gate::H(a), Gate::H(b)]),
+        Pauli::Y => (
+            vec![Gate::Rx(a, std::f64::consts::FRAC_PI_2), Gate::Rx(b, std::f64::consts::FRAC_PI_2)],
+            vec![Gate::Rx(a, -std::f64::consts::FRAC_PI_2), Gate::Rx(b, -std::f64::consts::FRAC_PI_2)],
+        ),
+        Pauli::I => {
+            return Err(QuantumError::OperationError {
+                message: "Ising interactions need an X, Y or Z axis".to_string(),
+            })
+        }
+    };
+
+    let mut gates = into;
+    gates.extend([
+        Gate::Cnot { control: a, target: b },
+        Gate::Rz(b, theta),
+        Gate::Cnot { control: a, target: b },
+    ]);
+    gates.extend(out);
+    Ok(gates)
+}
+
+/// Ising interaction at the golden angle
+pub fn golden_ising(axis: Pauli, a: usize, b: usize) -> QuantumResult<Vec<Gate>> {
+    ising(axis, a, b, GOLDEN_ANGLE)
+}
+
+/// Phi-weighted entangler: golden XX, YY and ZZ interactions in sequence
+///
+/// Each interaction angle is scaled by a further power of λ, so the three
+/// couplings stand in golden ratio to one another.
+pub fn phi_entangler(a: usize, b: usize) -> QuantumResult<Vec<Gate>> {
+    let mut gates = Vec::new();
+    for (k, axis) in [Pauli::X, Pauli::Y, Pauli::Z].into_iter().enumerate() {
+        gates.extend(ising(axis, a, b, GOLDEN_ANGLE * LAMBDA.powi(k as i32))?);
+    }
+    Ok(gates)
+}
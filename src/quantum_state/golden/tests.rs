@@ -0,0 +1,91 @@
+//! Unit tests for the golden module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::{GOLDEN_ANGLE, LAMBDA};
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::golden::{
+        golden_cphase, golden_ising, golden_rotation, golden_rz, ising, phi_entangler,
+    };
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use crate::quantum_state::state_vector::{Pauli, StateVector};
+    use num::complex::Complex64;
+    use std::f64::consts::TAU;
+
+    #[test]
+    fn test_golden_angle_value() {
+        assert!((GOLDEN_ANGLE - TAU * LAMBDA).abs() < 1e-15);
+        // Equivalent to the classical golden angle 2π/φ² in the opposite sense
+        assert!((TAU - GOLDEN_ANGLE - TAU * LAMBDA * LAMBDA).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_golden_rotations_compose() {
+        let mut stepwise = StateVector::new(1).unwrap();
+        stepwise.apply(&Gate::H(0)).unwrap();
+        let mut direct = stepwise.clone();
+
+        for _ in 0..3 {
+            stepwise.apply(&golden_rz(0)).unwrap();
+        }
+        direct.apply(&golden_rotation(Pauli::Z, 0, 3).unwrap()).unwrap();
+
+        assert!((stepwise.fidelity(&direct).unwrap() - 1.0).abs() < 1e-12);
+        assert!(golden_rotation(Pauli::I, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_golden_phases_never_repeat() {
+        // Successive golden phases stay distinct modulo 2π
+        let phases: Vec<f64> = (1..50).map(|k| (k as f64 * GOLDEN_ANGLE).rem_euclid(TAU)).collect();
+        for (i, a) in phases.iter().enumerate() {
+            for b in &phases[i + 1..] {
+                assert!((a - b).abs() > 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ising_matches_exponential() {
+        let theta: f64 = 0.9;
+        for (axis, pauli) in [
+            (Pauli::X, Gate::X(0)),
+            (Pauli::Y, Gate::Y(0)),
+            (Pauli::Z, Gate::Z(0)),
+        ] {
+            let p = ComplexMatrix::from_matrix2(&pauli.matrix().unwrap());
+            let pp = p.kron(&p);
+
+            // exp(-iθ/2 PP) = cos(θ/2) I - i sin(θ/2) PP since (PP)² = I
+            let expected = &ComplexMatrix::identity(4).scale(Complex64::new((theta / 2.0).cos(), 0.0))
+                + &pp.scale(Complex64::new(0.0, -(theta / 2.0).sin()));
+
+            let mut actual = ComplexMatrix::identity(4);
+            for gate in ising(axis, 0, 1, theta).unwrap() {
+                actual = &ComplexMatrix::from_gate(&gate, 2).unwrap() * &actual;
+            }
+
+            // Equal up to a global phase
+            let overlap = (&expected.dagger() * &actual).trace();
+            assert!((overlap.norm() - 4.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_golden_entanglers_entangle() {
+        let mut state = StateVector::new(2).unwrap();
+        state.apply_all(&[Gate::H(0), Gate::H(1)]).unwrap();
+        state.apply_all(&golden_ising(Pauli::Z, 0, 1).unwrap()).unwrap();
+
+        // ⟨X⊗I⟩ drops from 1 to cos(2πλ) while ⟨XX⟩, which commutes with ZZ, stays 1
+        assert!((state.expectation(&[(0, Pauli::X)]).unwrap() - GOLDEN_ANGLE.cos()).abs() < 1e-12);
+        assert!((state.expectation(&[(0, Pauli::X), (1, Pauli::X)]).unwrap() - 1.0).abs() < 1e-12);
+
+        let mut other = StateVector::new(2).unwrap();
+        other.apply_all(&[Gate::H(0), Gate::X(1), golden_cphase(0, 1)]).unwrap();
+        assert!((other.expectation(&[(0, Pauli::X)]).unwrap() - GOLDEN_ANGLE.cos()).abs() < 1e-12);
+
+        assert_eq!(phi_entangler(0, 1).unwrap().len(), 3 + 7 + 7);
+    }
+}
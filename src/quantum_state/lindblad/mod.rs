@@ -0,0 +1,344 @@
+//! Lindblad Master Equation
+//!
+//! This module evolves open quantum systems under the Lindblad equation
+//!
+//! dρ/dt = -i[H, ρ] + Σ_k γ_k (L_k ρ L_k† - ½{L_k† L_k, ρ})
+//!
+//! with fixed-step RK4 or adaptive step-doubling RK4. Every consciousness
+//! state maps to its own Hamiltonian and dissipator set, so switching state
+//! through a `StateManager` changes the dynamics, and each step's coherence
+//! is recorded into the manager's coherence field history.
+
+#[cfg(test)]
+mod tests;
+
+use crate::constants::{ConsciousnessState, Frequency, LAMBDA};
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::consciousness::StateManager;
+use crate::quantum_state::density::{CoherenceMeasure, DensityMatrix, MAX_DENSITY_QUBITS};
+use crate::quantum_state::gates::c;
+use crate::quantum_state::matrix::ComplexMatrix;
+
+/// Base dephasing rate of the default dynamics (Observe state)
+const BASE_DEPHASING: f64 = 0.1;
+
+/// Smallest step the adaptive integrator will take
+const MIN_ADAPTIVE_STEP: f64 = 1e-12;
+
+/// Integration method
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    /// Classic fourth-order Runge-Kutta with a fixed step
+    Rk4 {
+        /// Time step
+        dt: f64,
+    },
+
+    /// RK4 with step doubling and local error control
+    Adaptive {
+        /// Maximum local error per step (max-norm of the matrix difference)
+        tolerance: f64,
+
+        /// Initial time step
+        initial_dt: f64,
+    },
+}
+
+/// Hamiltonian and dissipators generating one Lindblad evolution
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dynamics {
+    /// System Hamiltonian
+    hamiltonian: ComplexMatrix,
+
+    /// Jump operators with their rates
+    dissipators: Vec<(f64, ComplexMatrix)>,
+}
+
+impl Dynamics {
+    /// Create closed-system dynamics from a Hermitian Hamiltonian
+    pub fn new(hamiltonian: ComplexMatrix) -> QuantumResult<Self> {
+        if !hamiltonian.is_hermitian(1e-12) {
+            return Err(QuantumError::OperationError {
+                message: "Hamiltonian must be Hermitian".to_string(),
+            });
+        }
+
+        Ok(Self {
+            hamiltonian,
+            dissipators: Vec::new(),
+        })
+    }
+
+    /// Add a jump operator with a non-negative rate
+    pub fn with_dissipator(mut self, rate: f64, operator: ComplexMatrix) -> QuantumResult<Self> {
+        if rate < 0.0 || operator.size() != self.hamiltonian.size() {
+            return Err(QuantumError::OperationError {
+                message: format!("Invalid dissipator: rate {} with size {}", rate, operator.size()),
+            });
+        }
+
+        self.dissipators.push((rate, operator));
+        Ok(self)
+    }
+
+    /// Default dynamics for a consciousness state on an n-qubit register
+    ///
+    /// Each qubit precesses at the state's frequency relative to Unity
+    /// (432 Hz) under a λ-weighted transverse drive. Dephasing falls by a
+    /// factor λ per dimension above Physical, with energy relaxation at λ
+    /// times the dephasing rate.
+    pub fn for_state(state: ConsciousnessState, num_qubits: usize) -> QuantumResult<Self> {
+        let omega = state.frequency().value() / Frequency::Unity.value();
+        let dephasing = BASE_DEPHASING * LAMBDA.powi(state.dimension().value() as i32 - 3);
+        let relaxation = dephasing * LAMBDA;
+
+        let z = ComplexMatrix::diagonal(&[c(1.0, 0.0), c(-1.0, 0.0)]);
+        let x = ComplexMatrix::from_fn(2, |i, j| if i != j { c(1.0, 0.0) } else { c(0.0, 0.0) });
+        let lowering = ComplexMatrix::from_fn(2, |i, j| if (i, j) == (0, 1) { c(1.0, 0.0) } else { c(0.0, 0.0) });
+
+        let dimension = 1usize << num_qubits;
+        let mut hamiltonian = ComplexMatrix::zeros(dimension);
+        for q in 0..num_qubits {
+            let precession = ComplexMatrix::embed(&z, &[q], num_qubits)?.scale(c(omega / 2.0, 0.0));
+            let drive = ComplexMatrix::embed(&x, &[q], num_qubits)?.scale(c(LAMBDA / 2.0, 0.0));
+            hamiltonian = &(&hamiltonian + &precession) + &drive;
+        }
+
+        let mut dynamics = Self::new(hamiltonian)?;
+        for q in 0..num_qubits {
+            // L = Z/√2 damps off-diagonal elements at the dephasing rate
+            let half_z = ComplexMatrix::embed(&z, &[q], num_qubits)?.scale(c(std::f64::consts::FRAC_1_SQRT_2, 0.0));
+            dynamics = dynamics
+                .with_dissipator(dephasing, half_z)?
+                .with_dissipator(relaxation, ComplexMatrix::embed(&lowering, &[q], num_qubits)?)?;
+        }
+
+        Ok(dynamics)
+    }
+
+    /// Get the Hamiltonian
+    pub fn hamiltonian(&self) -> &ComplexMatrix {
+        &self.hamiltonian
+    }
+
+    /// Get the jump operators with their rates
+    pub fn dissipators(&self) -> &[(f64, ComplexMatrix)] {
+        &self.dissipators
+    }
+
+    /// Right-hand side of the Lindblad equation for a density operator
+    pub fn derivative(&self, rho: &ComplexMatrix) -> ComplexMatrix {
+        let mut result = self.hamiltonian.commutator(rho).scale(c(0.0, -1.0));
+
+        for (rate, l) in &self.dissipators {
+            let l_dagger = l.dagger();
+            let jump = &(l * rho) * &l_dagger;
+            let decay = (&l_dagger * l).anticommutator(rho).scale(c(0.5, 0.0));
+            result = &result + &(&jump - &decay).scale(c(*rate, 0.0));
+        }
+
+        result
+    }
+
+    /// One classic RK4 step
+    fn rk4_step(&self, rho: &ComplexMatrix, dt: f64) -> ComplexMatrix {
+        let half = c(dt / 2.0, 0.0);
+        let k1 = self.derivative(rho);
+        let k2 = self.derivative(&(rho + &k1.scale(half)));
+        let k3 = self.derivative(&(rho + &k2.scale(half)));
+        let k4 = self.derivative(&(rho + &k3.scale(c(dt, 0.0))));
+
+        let sum = &(&k1 + &k2.scale(c(2.0, 0.0))) + &(&k3.scale(c(2.0, 0.0)) + &k4);
+        rho + &sum.scale(c(dt / 6.0, 0.0))
+    }
+}
+
+/// Lindblad integrator with one set of dynamics per consciousness state
+#[derive(Debug, Clone)]
+pub struct LindbladSolver {
+    /// Number of qubits
+    num_qubits: usize,
+
+    /// Dynamics for each state, indexed in `ConsciousnessState::ALL` order
+    dynamics: Vec<Dynamics>,
+
+    /// Measure recorded into the coherence field history
+    measure: CoherenceMeasure,
+}
+
+impl LindbladSolver {
+    /// Create a solver with the default dynamics for every state
+    pub fn new(num_qubits: usize) -> QuantumResult<Self> {
+        if num_qubits == 0 || num_qubits > MAX_DENSITY_QUBITS {
+            return Err(QuantumError::OperationError {
+                message: format!("Lindblad solver supports 1 to {} qubits, got {}", MAX_DENSITY_QUBITS, num_qubits),
+            });
+        }
+
+        let dynamics = ConsciousnessState::ALL.iter()
+            .map(|state| Dynamics::for_state(*state, num_qubits))
+            .collect::<QuantumResult<Vec<_>>>()?;
+
+        Ok(Self {
+            num_qubits,
+            dynamics,
+            measure: CoherenceMeasure::L1Norm,
+        })
+    }
+
+    /// Get the number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Get the dynamics for a state
+    pub fn dynamics(&self, state: ConsciousnessState) -> &Dynamics {
+        &self.dynamics[state_index(state)]
+    }
+
+    /// Configure the dynamics for a state
+    pub fn set_dynamics(&mut self, state: ConsciousnessState, dynamics: Dynamics) -> QuantumResult<()> {
+        if dynamics.hamiltonian.size() != 1 << self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Dynamics of size {} do not fit a {}-qubit register", dynamics.hamiltonian.size(), self.num_qubits),
+            });
+        }
+
+        self.dynamics[state_index(state)] = dynamics;
+        Ok(())
+    }
+
+    /// Get the coherence measure recorded during evolution
+    pub fn measure(&self) -> CoherenceMeasure {
+        self.measure
+    }
+
+    /// Set the coherence measure recorded during evolution
+    pub fn set_measure(&mut self, measure: CoherenceMeasure) {
+        self.measure = measure;
+    }
+
+    /// Evolve a density matrix under one state's dynamics
+    ///
+    /// Returns the time points reached, including the final time.
+    pub fn evolve_in_state(&self, state: ConsciousnessState, rho: &mut DensityMatrix, duration: f64, method: Method) -> QuantumResult<Vec<f64>> {
+        let mut times = Vec::new();
+        self.integrate(state, rho, duration, method, |time, _| {
+            times.push(time);
+            Ok(())
+        })?;
+        Ok(times)
+    }
+
+    /// Evolve a density matrix under the manager's current state
+    ///
+    /// After every accepted step the chosen coherence measure is written into
+    /// the manager's coherence field, so the field history holds the most
+    /// recent steps. Returns the full trajectory as (time, coherence) pairs.
+    pub fn evolve(&self, manager: &mut StateManager, rho: &mut DensityMatrix, duration: f64, method: Method) -> QuantumResult<Vec<(f64, f64)>> {
+        let measure = self.measure;
+        let mut trajectory = Vec::new();
+
+        self.integrate(manager.current_state(), rho, duration, method, |time, state| {
+            let coherence = state.normalized_coherence(measure)?;
+            manager.coherence_field_mut().set_coherence(coherence);
+            trajectory.push((time, coherence));
+            Ok(())
+        })?;
+
+        Ok(trajectory)
+    }
+
+    /// Integrate, calling `on_step` with the time and state after each accepted step
+    fn integrate(
+        &self,
+        state: ConsciousnessState,
+        rho: &mut DensityMatrix,
+        duration: f64,
+        method: Method,
+        mut on_step: impl FnMut(f64, &DensityMatrix) -> QuantumResult<()>,
+    ) -> QuantumResult<()> {
+        if rho.num_qubits() != self.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Solver for {} qubits given a {}-qubit state", self.num_qubits, rho.num_qubits()),
+            });
+        }
+
+        if !(duration >= 0.0 && duration.is_finite()) {
+            return Err(QuantumError::OperationError {
+                message: format!("Evolution duration must be non-negative, got {}", duration),
+            });
+        }
+
+        let dynamics = self.dynamics(state);
+        let mut time = 0.0;
+
+        match method {
+            Method::Rk4 { dt } => {
+                check_positive(dt)?;
+                let steps = (duration / dt).ceil() as usize;
+
+                // Spread what is left over the remaining steps, so rounding in
+                // `time` can neither overshoot nor leave a sliver of a step
+                for k in 0..steps {
+                    let h = (duration - time) / (steps - k) as f64;
+                    rho.set_matrix(hermitize(&dynamics.rk4_step(rho.matrix(), h)));
+                    time += h;
+                    on_step(time, rho)?;
+                }
+            }
+            Method::Adaptive { tolerance, initial_dt } => {
+                check_positive(tolerance)?;
+                check_positive(initial_dt)?;
+                let mut h = initial_dt;
+
+                while time < duration {
+                    h = h.min(duration - time);
+
+                    let full = dynamics.rk4_step(rho.matrix(), h);
+                    let halves = dynamics.rk4_step(&dynamics.rk4_step(rho.matrix(), h / 2.0), h / 2.0);
+                    let error = halves.max_difference(&full) / 15.0;
+                    let finite = halves.data().iter().all(|v| v.re.is_finite() && v.im.is_finite());
+                    if !finite || !error.is_finite() {
+                        return Err(QuantumError::OperationError {
+                            message: format!("Adaptive step of {} at time {} produced a non-finite error estimate", h, time),
+                        });
+                    }
+
+                    if error <= tolerance || h <= MIN_ADAPTIVE_STEP {
+                        // Accept the Richardson-corrected step
+                        let correction = (&halves - &full).scale(c(1.0 / 15.0, 0.0));
+                        rho.set_matrix(hermitize(&(&halves + &correction)));
+                        time += h;
+                        on_step(time, rho)?;
+                    }
+
+                    let factor = if error > 0.0 { 0.9 * (tolerance / error).powf(0.2) } else { 2.0 };
+                    h = (h * factor.clamp(0.2, 2.0)).max(MIN_ADAPTIVE_STEP);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Position of a state in `ConsciousnessState::ALL`
+fn state_index(state: ConsciousnessState) -> usize {
+    ConsciousnessState::ALL.iter().position(|s| *s == state).unwrap_or(0)
+}
+
+/// Remove the anti-Hermitian part accumulated by round-off
+fn hermitize(matrix: &ComplexMatrix) -> ComplexMatrix {
+    (matrix + &matrix.dagger()).scale(c(0.5, 0.0))
+}
+
+/// Ensure a step size or tolerance is positive and finite
+fn check_positive(value: f64) -> QuantumResult<()> {
+    if !(value > 0.0 && value.is_finite()) {
+        return Err(QuantumError::OperationError {
+            message: format!("Step size and tolerance must be positive, got {}", value),
+        });
+    }
+    Ok(())
+}
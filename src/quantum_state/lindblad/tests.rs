@@ -0,0 +1,166 @@
+//! Unit tests for the lindblad module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::constants::ConsciousnessState;
+    use crate::quantum_field::consciousness::StateManager;
+    use crate::quantum_state::density::{CoherenceMeasure, DensityMatrix};
+    use crate::quantum_state::gates::Gate;
+    use crate::quantum_state::lindblad::{Dynamics, LindbladSolver, Method};
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use num::complex::Complex64;
+
+    fn c(re: f64) -> Complex64 {
+        Complex64::new(re, 0.0)
+    }
+
+    fn plus() -> DensityMatrix {
+        let mut rho = DensityMatrix::new(1).unwrap();
+        rho.apply(&Gate::H(0)).unwrap();
+        rho
+    }
+
+    fn solver_with(dynamics: Dynamics) -> LindbladSolver {
+        let mut solver = LindbladSolver::new(1).unwrap();
+        solver.set_dynamics(ConsciousnessState::Observe, dynamics).unwrap();
+        solver
+    }
+
+    #[test]
+    fn test_pure_dephasing_matches_analytic_decay() {
+        let z = ComplexMatrix::diagonal(&[c(1.0), c(-1.0)]).scale(c(std::f64::consts::FRAC_1_SQRT_2));
+        let dynamics = Dynamics::new(ComplexMatrix::zeros(2)).unwrap().with_dissipator(0.5, z).unwrap();
+        let solver = solver_with(dynamics);
+
+        let mut rho = plus();
+        let times = solver.evolve_in_state(ConsciousnessState::Observe, &mut rho, 2.0, Method::Rk4 { dt: 0.01 }).unwrap();
+
+        assert_eq!(times.len(), 200);
+        assert!((times[199] - 2.0).abs() < 1e-12);
+        assert!((rho.element(0, 1).re - 0.5 * (-1.0f64).exp()).abs() < 1e-9);
+        assert!((rho.probability(0) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_amplitude_damping_matches_analytic_decay() {
+        let lowering = ComplexMatrix::from_rows(&[vec![c(0.0), c(1.0)], vec![c(0.0), c(0.0)]]).unwrap();
+        let dynamics = Dynamics::new(ComplexMatrix::zeros(2)).unwrap().with_dissipator(0.8, lowering).unwrap();
+        let solver = solver_with(dynamics);
+
+        let mut rho = DensityMatrix::new(1).unwrap();
+        rho.apply(&Gate::X(0)).unwrap();
+        solver.evolve_in_state(ConsciousnessState::Observe, &mut rho, 1.5, Method::Rk4 { dt: 0.005 }).unwrap();
+
+        assert!((rho.probability(1) - (-1.2f64).exp()).abs() < 1e-9);
+        assert!((rho.trace() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rabi_oscillation_is_unitary() {
+        let x = ComplexMatrix::from_rows(&[vec![c(0.0), c(1.0)], vec![c(1.0), c(0.0)]]).unwrap();
+        let solver = solver_with(Dynamics::new(x.scale(c(0.5))).unwrap());
+
+        // P(1) = sin²(t/2)
+        let mut rho = DensityMatrix::new(1).unwrap();
+        solver.evolve_in_state(ConsciousnessState::Observe, &mut rho, 1.0, Method::Rk4 { dt: 0.01 }).unwrap();
+        assert!((rho.probability(1) - 0.5f64.sin().powi(2)).abs() < 1e-9);
+        assert!(rho.is_pure(1e-9));
+    }
+
+    #[test]
+    fn test_adaptive_matches_fixed_step() {
+        let solver = LindbladSolver::new(2).unwrap();
+        let mut start = DensityMatrix::new(2).unwrap();
+        start.apply_all(&[Gate::H(0), Gate::Cnot { control: 0, target: 1 }]).unwrap();
+
+        let mut fixed = start.clone();
+        solver.evolve_in_state(ConsciousnessState::Create, &mut fixed, 3.0, Method::Rk4 { dt: 0.001 }).unwrap();
+
+        let mut adaptive = start;
+        let times = solver.evolve_in_state(
+            ConsciousnessState::Create,
+            &mut adaptive,
+            3.0,
+            Method::Adaptive { tolerance: 1e-10, initial_dt: 0.1 },
+        ).unwrap();
+
+        assert!(times.len() < 3000);
+        assert!((times[times.len() - 1] - 3.0).abs() < 1e-12);
+        assert!(adaptive.matrix().max_difference(fixed.matrix()) < 1e-7);
+        assert!((adaptive.trace() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_state_switch_changes_dynamics_and_records_history() {
+        let solver = LindbladSolver::new(1).unwrap();
+        let mut manager = StateManager::new();
+        let method = Method::Rk4 { dt: 0.05 };
+
+        let mut observed = plus();
+        let trajectory = solver.evolve(&mut manager, &mut observed, 5.0, method).unwrap();
+        assert_eq!(trajectory.len(), 100);
+        assert_eq!(manager.coherence_field().coherence(), trajectory[99].1);
+
+        // The field history holds the most recent steps of the trajectory
+        let recent: Vec<f64> = trajectory[90..].iter().map(|(_, c)| *c).collect();
+        assert_eq!(manager.coherence_field().history(), recent.as_slice());
+
+        manager.set_state(ConsciousnessState::Amplify).unwrap();
+        let mut amplified = plus();
+        let trajectory = solver.evolve(&mut manager, &mut amplified, 5.0, method).unwrap();
+        assert_eq!(*manager.coherence_field().history().last().unwrap(), trajectory[99].1);
+
+        // Higher-dimensional states decohere more slowly
+        assert!(amplified.l1_coherence() > observed.l1_coherence());
+        assert!(amplified.purity() > observed.purity());
+    }
+
+    #[test]
+    fn test_recorded_measure_is_configurable() {
+        let mut solver = LindbladSolver::new(1).unwrap();
+        solver.set_measure(CoherenceMeasure::Purity);
+        assert_eq!(solver.measure(), CoherenceMeasure::Purity);
+
+        let mut manager = StateManager::new();
+        let mut rho = plus();
+        let trajectory = solver.evolve(&mut manager, &mut rho, 1.0, Method::Adaptive { tolerance: 1e-8, initial_dt: 0.1 }).unwrap();
+
+        assert!(trajectory.windows(2).all(|w| w[1].1 <= w[0].1 + 1e-12));
+        assert!((trajectory[trajectory.len() - 1].0 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_configuration_is_rejected() {
+        let mut solver = LindbladSolver::new(1).unwrap();
+        assert!(LindbladSolver::new(0).is_err());
+        assert!(solver.set_dynamics(ConsciousnessState::Create, Dynamics::new(ComplexMatrix::zeros(4)).unwrap()).is_err());
+
+        let not_hermitian = ComplexMatrix::from_rows(&[vec![c(0.0), c(1.0)], vec![c(0.0), c(0.0)]]).unwrap();
+        assert!(Dynamics::new(not_hermitian).is_err());
+
+        let mut rho = DensityMatrix::new(2).unwrap();
+        assert!(solver.evolve_in_state(ConsciousnessState::Observe, &mut rho, 1.0, Method::Rk4 { dt: 0.1 }).is_err());
+
+        let mut single = plus();
+        assert!(solver.evolve_in_state(ConsciousnessState::Observe, &mut single, 1.0, Method::Rk4 { dt: 0.0 }).is_err());
+
+        // Dynamics that overflow fail instead of creeping on at the minimum step
+        let lowering = ComplexMatrix::from_rows(&[vec![c(0.0), c(1.0)], vec![c(0.0), c(0.0)]]).unwrap();
+        let runaway = solver_with(Dynamics::new(ComplexMatrix::zeros(2)).unwrap().with_dissipator(1e300, lowering).unwrap());
+        let adaptive = Method::Adaptive { tolerance: 1e-8, initial_dt: 0.1 };
+        let message = runaway.evolve_in_state(ConsciousnessState::Observe, &mut plus(), 1.0, adaptive).unwrap_err().to_string();
+        assert!(message.contains("non-finite error estimate"));
+    }
+
+    #[test]
+    fn test_fixed_steps_end_exactly_at_duration() {
+        let solver = LindbladSolver::new(1).unwrap();
+        for (duration, dt, steps) in [(1.0, 0.3, 4), (0.3, 0.1, 3), (2.0, 0.7, 3)] {
+            let times = solver.evolve_in_state(ConsciousnessState::Observe, &mut plus(), duration, Method::Rk4 { dt }).unwrap();
+            assert_eq!(times.len(), steps);
+            assert!((times[times.len() - 1] - duration).abs() < 1e-15);
+            assert!(times.windows(2).all(|w| w[1] - w[0] <= dt + 1e-15));
+        }
+    }
+}
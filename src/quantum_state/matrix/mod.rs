@@ -0,0 +1,385 @@
+//! Dense Complex Matrices
+//!
+//! This module provides the square complex matrices used for density
+//! matrices, channels and Hamiltonians, including Kronecker products,
+//! embedding of few-qubit operators into a register, and a Jacobi
+//! eigensolver for Hermitian matrices.
+
+#[cfg(test)]
+mod tests;
+
+use std::ops::{Add, Mul, Sub};
+
+use num::complex::Complex64;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::gates::{c, Gate, Matrix2};
+use crate::quantum_state::state_vector::StateVector;
+
+/// Maximum Jacobi sweeps for Hermitian eigendecomposition
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Square complex matrix in row-major order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexMatrix {
+    /// Number of rows (and columns)
+    size: usize,
+
+    /// Entries in row-major order
+    data: Vec<Complex64>,
+}
+
+impl ComplexMatrix {
+    /// Create a zero matrix
+    pub fn zeros(size: usize) -> Self {
+        Self {
+            size,
+            data: vec![c(0.0, 0.0); size * size],
+        }
+    }
+
+    /// Create an identity matrix
+    pub fn identity(size: usize) -> Self {
+        Self::from_fn(size, |i, j| if i == j { c(1.0, 0.0) } else { c(0.0, 0.0) })
+    }
+
+    /// Create a matrix from a function of (row, column)
+    pub fn from_fn(size: usize, f: impl Fn(usize, usize) -> Complex64) -> Self {
+        let data = (0..size * size).map(|k| f(k / size, k % size)).collect();
+        Self { size, data }
+    }
+
+    /// Create a matrix from rows of entries
+    pub fn from_rows(rows: &[Vec<Complex64>]) -> QuantumResult<Self> {
+        let size = rows.len();
+
+        if rows.iter().any(|row| row.len() != size) {
+            return Err(QuantumError::OperationError {
+                message: "Matrix rows must form a square".to_string(),
+            });
+        }
+
+        Ok(Self {
+            size,
+            data: rows.iter().flatten().cloned().collect(),
+        })
+    }
+
+    /// Create a 2×2 matrix
+    pub fn from_matrix2(m: &Matrix2) -> Self {
+        Self::from_fn(2, |i, j| m[i][j])
+    }
+
+    /// Create a diagonal matrix
+    pub fn diagonal(entries: &[Complex64]) -> Self {
+        Self::from_fn(entries.len(), |i, j| if i == j { entries[i] } else { c(0.0, 0.0) })
+    }
+
+    /// Create the outer product |a⟩⟨b|
+    pub fn outer(a: &[Complex64], b: &[Complex64]) -> QuantumResult<Self> {
+        if a.len() != b.len() {
+            return Err(QuantumError::OperationError {
+                message: format!("Cannot form outer product of lengths {} and {}", a.len(), b.len()),
+            });
+        }
+
+        Ok(Self::from_fn(a.len(), |i, j| a[i] * b[j].conj()))
+    }
+
+    /// Build the full register unitary of a gate acting on `num_qubits` qubits
+    pub fn from_gate(gate: &Gate, num_qubits: usize) -> QuantumResult<Self> {
+        let dimension = 1usize << num_qubits;
+        let mut matrix = Self::zeros(dimension);
+
+        for column in 0..dimension {
+            let mut state = StateVector::basis(num_qubits, column)?;
+            state.apply(gate)?;
+
+            for (row, amplitude) in state.amplitudes().iter().enumerate() {
+                matrix.data[row * dimension + column] = *amplitude;
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Embed an operator on a few qubits into an `num_qubits` register
+    ///
+    /// `targets[0]` corresponds to the least significant bit of the operator's index.
+    pub fn embed(operator: &ComplexMatrix, targets: &[usize], num_qubits: usize) -> QuantumResult<Self> {
+        if operator.size != 1usize << targets.len() {
+            return Err(QuantumError::OperationError {
+                message: format!("Operator of size {} does not act on {} qubits", operator.size, targets.len()),
+            });
+        }
+
+        for (i, &t) in targets.iter().enumerate() {
+            if t >= num_qubits || targets[..i].contains(&t) {
+                return Err(QuantumError::OperationError {
+                    message: format!("Invalid target qubit {} for {}-qubit register", t, num_qubits),
+                });
+            }
+        }
+
+        let target_mask = targets.iter().fold(0usize, |m, t| m | (1 << t));
+        let sub_index = |i: usize| {
+            targets.iter()
+                .enumerate()
+                .fold(0usize, |acc, (k, t)| acc | (((i >> t) & 1) << k))
+        };
+
+        Ok(Self::from_fn(1 << num_qubits, |i, j| {
+            if i & !target_mask != j & !target_mask {
+                c(0.0, 0.0)
+            } else {
+                operator.get(sub_index(i), sub_index(j))
+            }
+        }))
+    }
+
+    /// Get the number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get an entry
+    pub fn get(&self, row: usize, column: usize) -> Complex64 {
+        self.data[row * self.size + column]
+    }
+
+    /// Set an entry
+    pub fn set(&mut self, row: usize, column: usize, value: Complex64) {
+        self.data[row * self.size + column] = value;
+    }
+
+    /// Get the entries in row-major order
+    pub fn data(&self) -> &[Complex64] {
+        &self.data
+    }
+
+    /// Conjugate transpose
+    pub fn dagger(&self) -> Self {
+        Self::from_fn(self.size, |i, j| self.get(j, i).conj())
+    }
+
+    /// Trace
+    pub fn trace(&self) -> Complex64 {
+        (0..self.size).map(|i| self.get(i, i)).sum()
+    }
+
+    /// Multiply every entry by a scalar
+    pub fn scale(&self, factor: Complex64) -> Self {
+        Self {
+            size: self.size,
+            data: self.data.iter().map(|v| v * factor).collect(),
+        }
+    }
+
+    /// Kronecker (tensor) product `self ⊗ other`
+    ///
+    /// `other` occupies the least significant bits of the combined index.
+    pub fn kron(&self, other: &ComplexMatrix) -> Self {
+        let n = other.size;
+        Self::from_fn(self.size * n, |i, j| self.get(i / n, j / n) * other.get(i % n, j % n))
+    }
+
+    /// Commutator `[self, other] = self·other - other·self`
+    pub fn commutator(&self, other: &ComplexMatrix) -> Self {
+        &(self * other) - &(other * self)
+    }
+
+    /// Anticommutator `{self, other} = self·other + other·self`
+    pub fn anticommutator(&self, other: &ComplexMatrix) -> Self {
+        &(self * other) + &(other * self)
+    }
+
+    /// Largest absolute difference between entries of two matrices
+    pub fn max_difference(&self, other: &ComplexMatrix) -> f64 {
+        self.data.iter()
+            .zip(&other.data)
+            .map(|(a, b)| (a - b).norm())
+            .fold(0.0, f64::max)
+    }
+
+    /// Check whether the matrix is Hermitian within a tolerance
+    pub fn is_hermitian(&self, tolerance: f64) -> bool {
+        self.max_difference(&self.dagger()) <= tolerance
+    }
+
+    /// Check whether the matrix is unitary within a tolerance
+    pub fn is_unitary(&self, tolerance: f64) -> bool {
+        (&self.dagger() * self).max_difference(&Self::identity(self.size)) <= tolerance
+    }
+
+    /// Multiply the matrix by a vector
+    pub fn apply(&self, vector: &[Complex64]) -> Vec<Complex64> {
+        (0..self.size)
+            .map(|i| (0..self.size).map(|j| self.get(i, j) * vector[j]).sum())
+            .collect()
+    }
+
+    /// Eigendecomposition of a Hermitian matrix by complex Jacobi rotations
+    ///
+    /// Returns eigenvalues in ascending order and the matrix whose columns are
+    /// the corresponding orthonormal eigenvectors. Fails if the rotations
+    /// have not diagonalized the matrix within the sweep limit.
+    pub fn eigh(&self) -> QuantumResult<(Vec<f64>, ComplexMatrix)> {
+        self.jacobi_eigh(MAX_JACOBI_SWEEPS)
+    }
+
+    /// Jacobi eigendecomposition with at most `max_sweeps` sweeps
+    fn jacobi_eigh(&self, max_sweeps: usize) -> QuantumResult<(Vec<f64>, ComplexMatrix)> {
+        let finite = self.data.iter().all(|v| v.re.is_finite() && v.im.is_finite());
+        if !finite || !self.is_hermitian(1e-9 * (1.0 + self.frobenius_norm())) {
+            return Err(QuantumError::OperationError {
+                message: "Eigendecomposition requires a finite Hermitian matrix".to_string(),
+            });
+        }
+
+        let n = self.size;
+        let mut a = self.clone();
+        let mut v = Self::identity(n);
+        let tolerance = 1e-14 * self.frobenius_norm().max(f64::MIN_POSITIVE);
+        let mut converged = a.off_diagonal_norm() <= tolerance;
+
+        for _ in 0..max_sweeps {
+            if converged {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let apq = a.get(p, q);
+                    let magnitude = apq.norm();
+                    if magnitude <= 1e-300 {
+                        continue;
+                    }
+
+                    // Phase-rotate to a real off-diagonal entry, then apply a real Jacobi rotation
+                    let phase = apq / magnitude;
+                    let tau = (a.get(q, q).re - a.get(p, p).re) / (2.0 * magnitude);
+                    let t = if tau >= 0.0 {
+                        1.0 / (tau + (1.0 + tau * tau).sqrt())
+                    } else {
+                        -1.0 / (-tau + (1.0 + tau * tau).sqrt())
+                    };
+                    let cos = 1.0 / (1.0 + t * t).sqrt();
+                    let sin = t * cos;
+
+                    let u_pp = c(cos, 0.0);
+                    let u_pq = c(sin, 0.0);
+                    let u_qp = -phase.conj() * sin;
+                    let u_qq = phase.conj() * cos;
+
+                    // A ← A·U
+                    for k in 0..n {
+                        let (akp, akq) = (a.get(k, p), a.get(k, q));
+                        a.set(k, p, akp * u_pp + akq * u_qp);
+                        a.set(k, q, akp * u_pq + akq * u_qq);
+
+                        let (vkp, vkq) = (v.get(k, p), v.get(k, q));
+                        v.set(k, p, vkp * u_pp + vkq * u_qp);
+                        v.set(k, q, vkp * u_pq + vkq * u_qq);
+                    }
+
+                    // A ← U†·A
+                    for k in 0..n {
+                        let (apk, aqk) = (a.get(p, k), a.get(q, k));
+                        a.set(p, k, u_pp.conj() * apk + u_qp.conj() * aqk);
+                        a.set(q, k, u_pq.conj() * apk + u_qq.conj() * aqk);
+                    }
+                }
+            }
+
+            converged = a.off_diagonal_norm() <= tolerance;
+        }
+
+        if !converged {
+            return Err(QuantumError::OperationError {
+                message: format!("Jacobi eigendecomposition did not converge in {} sweeps", max_sweeps),
+            });
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a.get(i, i).re.total_cmp(&a.get(j, j).re));
+
+        let values = order.iter().map(|&i| a.get(i, i).re).collect();
+        let vectors = Self::from_fn(n, |row, col| v.get(row, order[col]));
+
+        Ok((values, vectors))
+    }
+
+    /// Apply a real function to the eigenvalues of a Hermitian matrix
+    pub fn map_hermitian(&self, f: impl Fn(f64) -> f64) -> QuantumResult<ComplexMatrix> {
+        let (values, vectors) = self.eigh()?;
+        let mapped: Vec<Complex64> = values.iter().map(|v| c(f(*v), 0.0)).collect();
+
+        Ok(&(&vectors * &Self::diagonal(&mapped)) * &vectors.dagger())
+    }
+
+    /// Frobenius norm
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().map(|v| v.norm_sqr()).sum::<f64>().sqrt()
+    }
+
+    /// Frobenius norm of the off-diagonal part
+    fn off_diagonal_norm(&self) -> f64 {
+        let mut total = 0.0;
+        for i in 0..self.size {
+            for j in 0..self.size {
+                if i != j {
+                    total += self.get(i, j).norm_sqr();
+                }
+            }
+        }
+        total.sqrt()
+    }
+}
+
+impl Mul for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn mul(self, other: &ComplexMatrix) -> ComplexMatrix {
+        assert_eq!(self.size, other.size, "matrix sizes must match");
+        let n = self.size;
+        let mut result = ComplexMatrix::zeros(n);
+
+        for i in 0..n {
+            for k in 0..n {
+                let a = self.data[i * n + k];
+                if a == c(0.0, 0.0) {
+                    continue;
+                }
+                for j in 0..n {
+                    result.data[i * n + j] += a * other.data[k * n + j];
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Add for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn add(self, other: &ComplexMatrix) -> ComplexMatrix {
+        assert_eq!(self.size, other.size, "matrix sizes must match");
+        ComplexMatrix {
+            size: self.size,
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect(),
+        }
+    }
+}
+
+impl Sub for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn sub(self, other: &ComplexMatrix) -> ComplexMatrix {
+        assert_eq!(self.size, other.size, "matrix sizes must match");
+        ComplexMatrix {
+            size: self.size,
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a - b).collect(),
+        }
+    }
+}
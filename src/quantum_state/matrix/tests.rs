@@ -0,0 +1,105 @@
+//! Unit tests for the matrix module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use crate::quantum_state::gates::Gate;
+    use num::complex::Complex64;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    fn hermitian() -> ComplexMatrix {
+        ComplexMatrix::from_rows(&[
+            vec![c(2.0, 0.0), c(1.0, -1.0), c(0.0, 0.5)],
+            vec![c(1.0, 1.0), c(3.0, 0.0), c(-0.5, 0.0)],
+            vec![c(0.0, -0.5), c(-0.5, 0.0), c(1.0, 0.0)],
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_eigh_reconstructs_matrix() {
+        let m = hermitian();
+        let (values, vectors) = m.eigh().unwrap();
+
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        assert!(vectors.is_unitary(1e-10));
+
+        let diagonal: Vec<Complex64> = values.iter().map(|v| c(*v, 0.0)).collect();
+        let rebuilt = &(&vectors * &ComplexMatrix::diagonal(&diagonal)) * &vectors.dagger();
+        assert!(rebuilt.max_difference(&m) < 1e-10);
+
+        // Eigenvalues sum to the trace
+        assert!((values.iter().sum::<f64>() - 6.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_eigh_rejects_non_hermitian() {
+        let m = ComplexMatrix::from_rows(&[
+            vec![c(0.0, 0.0), c(1.0, 0.0)],
+            vec![c(0.0, 0.0), c(0.0, 0.0)],
+        ]).unwrap();
+        assert!(m.eigh().is_err());
+
+        let infinite = ComplexMatrix::diagonal(&[c(f64::INFINITY, 0.0), c(0.0, 0.0)]);
+        assert!(infinite.eigh().is_err());
+    }
+
+    #[test]
+    fn test_eigh_reports_non_convergence() {
+        let m = ComplexMatrix::from_fn(6, |i, j| c(1.0 / (1.0 + i as f64 + j as f64), (i as f64 - j as f64) * 0.1));
+        assert!(m.jacobi_eigh(1).unwrap_err().to_string().contains("did not converge"));
+        assert!(m.jacobi_eigh(0).is_err());
+        assert!(m.eigh().is_ok());
+        assert!(ComplexMatrix::identity(4).jacobi_eigh(0).is_ok());
+    }
+
+    #[test]
+    fn test_map_hermitian_square_root() {
+        let m = hermitian();
+        let shifted = &m + &ComplexMatrix::identity(3).scale(c(2.0, 0.0));
+        let root = shifted.map_hermitian(f64::sqrt).unwrap();
+        assert!((&root * &root).max_difference(&shifted) < 1e-10);
+    }
+
+    #[test]
+    fn test_kron_and_embed_agree() {
+        let x = ComplexMatrix::from_matrix2(&Gate::X(0).matrix().unwrap());
+        let z = ComplexMatrix::from_matrix2(&Gate::Z(0).matrix().unwrap());
+
+        // Z on qubit 1, X on qubit 0
+        let kron = z.kron(&x);
+        let embedded = &ComplexMatrix::embed(&x, &[0], 2).unwrap() * &ComplexMatrix::embed(&z, &[1], 2).unwrap();
+        assert!(kron.max_difference(&embedded) < 1e-15);
+
+        let two_qubit = ComplexMatrix::embed(&kron, &[1, 0], 2).unwrap();
+        assert!(two_qubit.max_difference(&x.kron(&z)) < 1e-15);
+
+        assert!(ComplexMatrix::embed(&x, &[2], 2).is_err());
+    }
+
+    #[test]
+    fn test_from_gate_matches_embedding() {
+        let cnot = ComplexMatrix::from_gate(&Gate::Cnot { control: 0, target: 1 }, 2).unwrap();
+        assert!(cnot.is_unitary(1e-12));
+        assert_eq!(cnot.get(3, 1), c(1.0, 0.0));
+        assert_eq!(cnot.get(1, 3), c(1.0, 0.0));
+
+        let h = ComplexMatrix::from_gate(&Gate::H(1), 2).unwrap();
+        let embedded = ComplexMatrix::embed(&ComplexMatrix::from_matrix2(&Gate::H(0).matrix().unwrap()), &[1], 2).unwrap();
+        assert!(h.max_difference(&embedded) < 1e-15);
+    }
+
+    #[test]
+    fn test_commutators() {
+        let x = ComplexMatrix::from_matrix2(&Gate::X(0).matrix().unwrap());
+        let y = ComplexMatrix::from_matrix2(&Gate::Y(0).matrix().unwrap());
+        let z = ComplexMatrix::from_matrix2(&Gate::Z(0).matrix().unwrap());
+
+        // [X, Y] = 2iZ, {X, Y} = 0
+        assert!(x.commutator(&y).max_difference(&z.scale(c(0.0, 2.0))) < 1e-15);
+        assert!(x.anticommutator(&y).frobenius_norm() < 1e-15);
+    }
+}
@@ -0,0 +1,43 @@
+//! Quantum State Simulation
+//!
+//! The quantum state module provides numerical simulation of qubit registers
+//! built on `num::Complex`, and bridges the simulated states back to quantum
+//! field coherence.
+//!
+//! ## Core Components
+//!
+//! - **Gates**: Standard single- and multi-qubit gate library
+//! - **State Vector**: Pure-state simulation with measurement and expectation values
+//! - **Circuit**: Ordered gate and measurement sequences
+//! - **QASM**: OpenQASM 2.0/3 import and export for circuits
+//! - **Golden**: Golden-angle rotations and phi-weighted entangling gates
+//! - **Anyon**: Fibonacci-anyon braids and a Solovay-Kitaev braid compiler
+//! - **Bloch**: Bloch vectors, geodesic interpolation and SVG sphere rendering
+//! - **Density**: Mixed states, partial trace and coherence measures
+//! - **Channels**: Noise channels in Kraus form
+//! - **Lindblad**: Open-system evolution with state-specific dynamics
+//! - **Matrix**: Dense complex matrices and Hermitian eigendecomposition
+
+pub mod anyon;
+pub mod bloch;
+pub mod channels;
+pub mod circuit;
+pub mod density;
+pub mod gates;
+pub mod golden;
+pub mod lindblad;
+pub mod matrix;
+pub mod qasm;
+pub mod state_vector;
+
+/// Re-export key components
+pub use self::anyon::{Braid, SolovayKitaev};
+pub use self::bloch::{BlochSphereSvg, BlochVector};
+pub use self::channels::Channel;
+pub use self::circuit::Circuit;
+pub use self::density::DensityMatrix;
+pub use self::gates::Gate;
+pub use self::lindblad::LindbladSolver;
+pub use self::matrix::ComplexMatrix;
+pub use self::qasm::QasmVersion;
+pub use self::state_vector::StateVector;
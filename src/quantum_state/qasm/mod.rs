@@ -0,0 +1,852 @@
+//! OpenQASM Import and Export
+//!
+//! This module reads and writes circuits in OpenQASM 2.0 and 3 so they can
+//! move between this crate and external toolchains. The parser covers
+//! register declarations, the standard gate library, measurement, reset and
+//! barriers, with constant parameter expressions. Library gates with no
+//! counterpart in [`Gate`], such as `ch`, `cu3` or `rxx`, are expanded into
+//! exact decompositions. Custom `gate` definitions, classical declarations,
+//! control flow and other OpenQASM 3 features are rejected with the line and
+//! column of the offending token.
+
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::{E, PI, TAU};
+use std::fmt::Write;
+
+use num::complex::Complex64;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_state::circuit::{Circuit, Operation};
+use crate::quantum_state::gates::{c, Gate, Matrix2};
+use crate::quantum_state::state_vector::MAX_QUBITS;
+
+/// Magnitude below which a matrix element is treated as zero in decompositions
+const DECOMPOSITION_EPSILON: f64 = 1e-12;
+
+/// OpenQASM language version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QasmVersion {
+    /// OpenQASM 2.0 with `qelib1.inc`
+    V2,
+
+    /// OpenQASM 3 with `stdgates.inc`
+    V3,
+}
+
+impl Circuit {
+    /// Parse an OpenQASM 2.0 or 3 program
+    ///
+    /// Registers are laid out in declaration order, so the first qubit of
+    /// the first quantum register becomes qubit 0.
+    pub fn from_qasm(source: &str) -> QuantumResult<Circuit> {
+        Parser::new(tokenize(source)?).parse()
+    }
+
+    /// Emit the circuit as an OpenQASM program
+    ///
+    /// Arbitrary single-qubit unitaries are written as `u3`/`U` gates, which
+    /// preserve them up to a global phase.
+    pub fn to_qasm(&self, version: QasmVersion) -> String {
+        let mut out = String::new();
+
+        match version {
+            QasmVersion::V2 => {
+                out.push_str("OPENQASM 2.0;\ninclude \"qelib1.inc\";\n");
+                let _ = writeln!(out, "qreg q[{}];", self.num_qubits());
+                if self.num_bits() > 0 {
+                    let _ = writeln!(out, "creg c[{}];", self.num_bits());
+                }
+            }
+            QasmVersion::V3 => {
+                out.push_str("OPENQASM 3.0;\ninclude \"stdgates.inc\";\n");
+                let _ = writeln!(out, "qubit[{}] q;", self.num_qubits());
+                if self.num_bits() > 0 {
+                    let _ = writeln!(out, "bit[{}] c;", self.num_bits());
+                }
+            }
+        }
+
+        for operation in self.operations() {
+            match *operation {
+                Operation::Gate(gate) => out.push_str(&emit_gate(&gate, version)),
+                Operation::Measure { qubit, bit } => {
+                    let _ = match version {
+                        QasmVersion::V2 => writeln!(out, "measure q[{}] -> c[{}];", qubit, bit),
+                        QasmVersion::V3 => writeln!(out, "c[{}] = measure q[{}];", bit, qubit),
+                    };
+                }
+                Operation::Reset(qubit) => {
+                    let _ = writeln!(out, "reset q[{}];", qubit);
+                }
+                Operation::Barrier => out.push_str("barrier q;\n"),
+            }
+        }
+
+        out
+    }
+}
+
+/// Write one gate statement
+fn emit_gate(gate: &Gate, version: QasmVersion) -> String {
+    let v2 = version == QasmVersion::V2;
+
+    let (name, params): (&str, Vec<f64>) = match *gate {
+        Gate::Rx(_, theta) | Gate::Ry(_, theta) | Gate::Rz(_, theta) => (gate.name(), vec![theta]),
+        Gate::Phase(_, theta) => (if v2 { "u1" } else { "p" }, vec![theta]),
+        Gate::CPhase { angle, .. } => (if v2 { "cu1" } else { "cp" }, vec![angle]),
+        Gate::Unitary(_, m) => {
+            let (theta, phi, lambda) = u3_angles(&m);
+            (if v2 { "u3" } else { "U" }, vec![theta, phi, lambda])
+        }
+        _ => (gate.name(), Vec::new()),
+    };
+
+    let mut statement = name.to_string();
+    if !params.is_empty() {
+        let list: Vec<String> = params.iter().map(|p| format!("{:?}", p)).collect();
+        statement.push_str(&format!("({})", list.join(", ")));
+    }
+
+    let qubits: Vec<String> = gate.qubits().iter().map(|q| format!("q[{}]", q)).collect();
+    format!("{} {};\n", statement, qubits.join(", "))
+}
+
+/// Angles (θ, φ, λ) with U = e^{iγ}·u3(θ, φ, λ) for some global phase γ
+fn u3_angles(m: &Matrix2) -> (f64, f64, f64) {
+    let theta = 2.0 * m[1][0].norm().atan2(m[0][0].norm());
+
+    if m[1][0].norm() <= DECOMPOSITION_EPSILON {
+        // Diagonal: only φ + λ is defined
+        (theta, 0.0, m[1][1].arg() - m[0][0].arg())
+    } else if m[0][0].norm() <= DECOMPOSITION_EPSILON {
+        // Anti-diagonal: only φ - λ is defined
+        let global = m[1][0].arg();
+        (theta, 0.0, (-m[0][1]).arg() - global)
+    } else {
+        let global = m[0][0].arg();
+        (theta, m[1][0].arg() - global, (-m[0][1]).arg() - global)
+    }
+}
+
+/// Matrix of the OpenQASM `u3(θ, φ, λ)` gate
+fn u3_matrix(theta: f64, phi: f64, lambda: f64) -> Matrix2 {
+    let (s, co) = (theta / 2.0).sin_cos();
+    [
+        [c(co, 0.0), -Complex64::from_polar(s, lambda)],
+        [Complex64::from_polar(s, phi), Complex64::from_polar(co, phi + lambda)],
+    ]
+}
+
+/// Lexical token
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Identifier or keyword
+    Ident(String),
+    /// Integer literal (digits only)
+    Integer(u64),
+    /// Other numeric literal
+    Number(f64),
+    /// String literal
+    Str(String),
+    /// `->`
+    Arrow,
+    /// Single-character punctuation or operator
+    Symbol(char),
+    /// End of input
+    End,
+}
+
+impl Token {
+    /// Value of an integer or other numeric literal
+    fn numeric(&self) -> Option<f64> {
+        match *self {
+            Token::Integer(value) => Some(value as f64),
+            Token::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Token with its source position (1-based)
+#[derive(Debug, Clone)]
+struct Spanned {
+    /// Token
+    token: Token,
+    /// Line number
+    line: usize,
+    /// Column number
+    column: usize,
+}
+
+/// Error at a source position
+fn error_at(line: usize, column: usize, message: impl std::fmt::Display) -> QuantumError {
+    QuantumError::OperationError {
+        message: format!("QASM line {}, column {}: {}", line, column, message),
+    }
+}
+
+/// Split source text into tokens, skipping whitespace and comments
+fn tokenize(source: &str) -> QuantumResult<Vec<Spanned>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line, mut column) = (0, 1, 1);
+
+    while i < chars.len() {
+        let ch = chars[i];
+        let (start_line, start_column) = (line, column);
+
+        // Advance over `n` characters, tracking line and column
+        let mut advance = |count: usize, i: &mut usize| {
+            for _ in 0..count {
+                if chars[*i] == '\n' {
+                    line += 1;
+                    column = 1;
+                } else {
+                    column += 1;
+                }
+                *i += 1;
+            }
+        };
+
+        if ch.is_whitespace() {
+            advance(1, &mut i);
+        } else if ch == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                advance(1, &mut i);
+            }
+        } else if ch == '/' && chars.get(i + 1) == Some(&'*') {
+            advance(2, &mut i);
+            loop {
+                if i + 1 >= chars.len() {
+                    return Err(error_at(start_line, start_column, "unterminated block comment"));
+                }
+                if chars[i] == '*' && chars[i + 1] == '/' {
+                    advance(2, &mut i);
+                    break;
+                }
+                advance(1, &mut i);
+            }
+        } else if ch.is_alphabetic() || ch == '_' {
+            let mut end = i;
+            while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                end += 1;
+            }
+            let ident: String = chars[i..end].iter().collect();
+            advance(end - i, &mut i);
+            tokens.push(Spanned { token: Token::Ident(ident), line: start_line, column: start_column });
+        } else if ch.is_ascii_digit() || (ch == '.' && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit())) {
+            let mut end = i;
+            while end < chars.len() && (chars[end].is_ascii_digit() || chars[end] == '.') {
+                end += 1;
+            }
+            if end < chars.len() && (chars[end] == 'e' || chars[end] == 'E') {
+                let mut exponent = end + 1;
+                if exponent < chars.len() && (chars[exponent] == '+' || chars[exponent] == '-') {
+                    exponent += 1;
+                }
+                if exponent < chars.len() && chars[exponent].is_ascii_digit() {
+                    end = exponent;
+                    while end < chars.len() && chars[end].is_ascii_digit() {
+                        end += 1;
+                    }
+                }
+            }
+
+            let text: String = chars[i..end].iter().collect();
+            let token = match text.parse::<u64>() {
+                Ok(value) => Token::Integer(value),
+                Err(_) => Token::Number(text.parse::<f64>()
+                    .map_err(|_| error_at(start_line, start_column, format!("invalid number '{}'", text)))?),
+            };
+            advance(end - i, &mut i);
+            tokens.push(Spanned { token, line: start_line, column: start_column });
+        } else if ch == '"' {
+            let mut end = i + 1;
+            while end < chars.len() && chars[end] != '"' && chars[end] != '\n' {
+                end += 1;
+            }
+            if end >= chars.len() || chars[end] != '"' {
+                return Err(error_at(start_line, start_column, "unterminated string"));
+            }
+            let text: String = chars[i + 1..end].iter().collect();
+            advance(end + 1 - i, &mut i);
+            tokens.push(Spanned { token: Token::Str(text), line: start_line, column: start_column });
+        } else if ch == '-' && chars.get(i + 1) == Some(&'>') {
+            advance(2, &mut i);
+            tokens.push(Spanned { token: Token::Arrow, line: start_line, column: start_column });
+        } else if "[](){};,=+-*/^".contains(ch) {
+            advance(1, &mut i);
+            tokens.push(Spanned { token: Token::Symbol(ch), line: start_line, column: start_column });
+        } else {
+            return Err(error_at(start_line, start_column, format!("unexpected character '{}'", ch)));
+        }
+    }
+
+    tokens.push(Spanned { token: Token::End, line, column });
+    Ok(tokens)
+}
+
+/// Declared register occupying a contiguous range of flat indices
+#[derive(Debug, Clone)]
+struct Register {
+    /// Register name
+    name: String,
+    /// First flat index
+    offset: usize,
+    /// Number of elements
+    size: usize,
+}
+
+/// Operand referring to a whole register or one element
+#[derive(Debug, Clone)]
+struct Operand {
+    /// Flat indices selected by the operand
+    indices: Vec<usize>,
+    /// Whether a whole register was named
+    whole: bool,
+}
+
+/// Recursive-descent parser over a token stream
+struct Parser {
+    /// Tokens ending with `Token::End`
+    tokens: Vec<Spanned>,
+    /// Current token index
+    position: usize,
+    /// Quantum registers
+    qregs: Vec<Register>,
+    /// Classical registers
+    cregs: Vec<Register>,
+    /// Parsed operations with their source positions
+    operations: Vec<(Operation, usize, usize)>,
+}
+
+impl Parser {
+    /// Create a parser over tokens
+    fn new(tokens: Vec<Spanned>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            qregs: Vec::new(),
+            cregs: Vec::new(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Parse the whole program into a circuit
+    fn parse(mut self) -> QuantumResult<Circuit> {
+        if self.peek_ident() == Some("OPENQASM") {
+            self.parse_version()?;
+        }
+
+        while self.peek().token != Token::End {
+            self.parse_statement()?;
+        }
+
+        let num_qubits = self.qregs.iter().map(|r| r.size).sum();
+        let num_bits = self.cregs.iter().map(|r| r.size).sum();
+        let mut circuit = Circuit::with_bits(num_qubits, num_bits);
+
+        for (operation, line, column) in self.operations {
+            circuit.push(operation).map_err(|e| match e {
+                QuantumError::OperationError { message } => error_at(line, column, message),
+                other => other,
+            })?;
+        }
+
+        Ok(circuit)
+    }
+
+    /// Current token
+    fn peek(&self) -> &Spanned {
+        &self.tokens[self.position]
+    }
+
+    /// Current token if it is an identifier
+    fn peek_ident(&self) -> Option<&str> {
+        match &self.peek().token {
+            Token::Ident(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Consume and return the current token
+    fn next(&mut self) -> Spanned {
+        let token = self.tokens[self.position].clone();
+        if token.token != Token::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    /// Error at the current token
+    fn error(&self, message: impl std::fmt::Display) -> QuantumError {
+        let token = self.peek();
+        error_at(token.line, token.column, message)
+    }
+
+    /// Consume an expected symbol
+    fn expect_symbol(&mut self, symbol: char) -> QuantumResult<()> {
+        if self.peek().token == Token::Symbol(symbol) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", symbol)))
+        }
+    }
+
+    /// Consume a symbol if present
+    fn accept_symbol(&mut self, symbol: char) -> bool {
+        if self.peek().token == Token::Symbol(symbol) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume an identifier
+    fn expect_ident(&mut self) -> QuantumResult<String> {
+        match self.peek().token.clone() {
+            Token::Ident(name) => {
+                self.next();
+                Ok(name)
+            }
+            _ => Err(self.error("expected identifier")),
+        }
+    }
+
+    /// Consume a non-negative integer literal written without a fraction or exponent
+    fn expect_integer(&mut self) -> QuantumResult<usize> {
+        match self.peek().token {
+            Token::Integer(value) => {
+                let value = usize::try_from(value).map_err(|_| self.error("integer out of range"))?;
+                self.next();
+                Ok(value)
+            }
+            _ => Err(self.error("expected non-negative integer")),
+        }
+    }
+
+    /// Parse `OPENQASM <version>;`
+    fn parse_version(&mut self) -> QuantumResult<()> {
+        self.next();
+        match self.peek().token.numeric() {
+            Some(version) if (2.0..4.0).contains(&version) => {
+                self.next();
+            }
+            _ => return Err(self.error("unsupported OpenQASM version (expected 2.0 or 3)")),
+        }
+        self.expect_symbol(';')
+    }
+
+    /// Parse one statement
+    fn parse_statement(&mut self) -> QuantumResult<()> {
+        let start = self.peek().clone();
+        let keyword = match &start.token {
+            Token::Ident(name) => name.clone(),
+            _ => return Err(self.error("expected statement")),
+        };
+
+        match keyword.as_str() {
+            "OPENQASM" => Err(self.error("version declaration must come first")),
+            "include" => {
+                self.next();
+                match self.next().token {
+                    Token::Str(_) => self.expect_symbol(';'),
+                    _ => Err(error_at(start.line, start.column, "expected file name after include")),
+                }
+            }
+            "qreg" | "creg" => {
+                self.next();
+                let name = self.expect_ident()?;
+                self.expect_symbol('[')?;
+                let size = self.expect_integer()?;
+                self.expect_symbol(']')?;
+                self.expect_symbol(';')?;
+                self.declare(keyword == "qreg", name, size, &start)
+            }
+            "qubit" | "bit" => {
+                self.next();
+                let size = if self.accept_symbol('[') {
+                    let size = self.expect_integer()?;
+                    self.expect_symbol(']')?;
+                    size
+                } else {
+                    1
+                };
+                let name = self.expect_ident()?;
+                self.expect_symbol(';')?;
+                self.declare(keyword == "qubit", name, size, &start)
+            }
+            "measure" => {
+                self.next();
+                let qubits = self.parse_operand(true)?;
+                if self.next().token != Token::Arrow {
+                    return Err(error_at(start.line, start.column, "expected '->' in measurement"));
+                }
+                let bits = self.parse_operand(false)?;
+                self.expect_symbol(';')?;
+                self.push_measurements(qubits, bits, &start)
+            }
+            "reset" => {
+                self.next();
+                let qubits = self.parse_operand(true)?;
+                self.expect_symbol(';')?;
+                for q in qubits.indices {
+                    self.operations.push((Operation::Reset(q), start.line, start.column));
+                }
+                Ok(())
+            }
+            "barrier" => {
+                self.next();
+                self.parse_operand(true)?;
+                while self.accept_symbol(',') {
+                    self.parse_operand(true)?;
+                }
+                self.expect_symbol(';')?;
+                self.operations.push((Operation::Barrier, start.line, start.column));
+                Ok(())
+            }
+            "const" | "input" | "output" | "let" | "int" | "uint" | "float" | "angle" | "bool"
+            | "complex" | "duration" | "stretch" | "array" | "if" | "else" | "for" | "while"
+            | "switch" | "box" | "delay" | "extern" | "return" | "break" | "continue"
+            | "cal" | "defcal" | "defcalgrammar" => {
+                Err(error_at(start.line, start.column, format!("'{}' statements are not supported", keyword)))
+            }
+            _ if self.cregs.iter().any(|r| r.name == keyword) => {
+                // OpenQASM 3 assignment: bits = measure qubits;
+                let bits = self.parse_operand(false)?;
+                self.expect_symbol('=')?;
+                if self.peek_ident() != Some("measure") {
+                    return Err(self.error("only measurement results can be assigned"));
+                }
+                self.next();
+                let qubits = self.parse_operand(true)?;
+                self.expect_symbol(';')?;
+                self.push_measurements(qubits, bits, &start)
+            }
+            _ => self.parse_gate(),
+        }
+    }
+
+    /// Declare a register
+    ///
+    /// Quantum and classical registers each hold at most [`MAX_QUBITS`]
+    /// elements in total, the largest register the simulator can run.
+    fn declare(&mut self, quantum: bool, name: String, size: usize, start: &Spanned) -> QuantumResult<()> {
+        if self.qregs.iter().chain(&self.cregs).any(|r| r.name == name) {
+            return Err(error_at(start.line, start.column, format!("register '{}' already declared", name)));
+        }
+
+        let registers = if quantum { &mut self.qregs } else { &mut self.cregs };
+        let offset = registers.last().map_or(0, |r| r.offset + r.size);
+        if offset.checked_add(size).is_none_or(|total| total > MAX_QUBITS) {
+            let kind = if quantum { "qubits" } else { "bits" };
+            return Err(error_at(
+                start.line,
+                start.column,
+                format!("register '{}' exceeds the limit of {} {}", name, MAX_QUBITS, kind),
+            ));
+        }
+
+        registers.push(Register { name, offset, size });
+        Ok(())
+    }
+
+    /// Parse `name` or `name[index]` of a quantum or classical register
+    fn parse_operand(&mut self, quantum: bool) -> QuantumResult<Operand> {
+        let token = self.peek().clone();
+        let name = self.expect_ident()?;
+
+        let registers = if quantum { &self.qregs } else { &self.cregs };
+        let register = registers.iter()
+            .find(|r| r.name == name)
+            .cloned()
+            .ok_or_else(|| {
+                let kind = if quantum { "quantum" } else { "classical" };
+                error_at(token.line, token.column, format!("unknown {} register '{}'", kind, name))
+            })?;
+
+        if self.accept_symbol('[') {
+            let index_token = self.peek().clone();
+            let index = self.expect_integer()?;
+            self.expect_symbol(']')?;
+
+            if index >= register.size {
+                return Err(error_at(
+                    index_token.line,
+                    index_token.column,
+                    format!("index {} out of range for register '{}' of size {}", index, name, register.size),
+                ));
+            }
+
+            let flat = register.offset.checked_add(index).ok_or_else(|| self.error("register index overflows"))?;
+            Ok(Operand { indices: vec![flat], whole: false })
+        } else {
+            let end = register.offset.checked_add(register.size).ok_or_else(|| self.error("register size overflows"))?;
+            Ok(Operand { indices: (register.offset..end).collect(), whole: true })
+        }
+    }
+
+    /// Record measurements, pairing whole registers element by element
+    fn push_measurements(&mut self, qubits: Operand, bits: Operand, start: &Spanned) -> QuantumResult<()> {
+        if qubits.indices.len() != bits.indices.len() {
+            return Err(error_at(start.line, start.column, "measurement operands have different sizes"));
+        }
+
+        for (qubit, bit) in qubits.indices.into_iter().zip(bits.indices) {
+            self.operations.push((Operation::Measure { qubit, bit }, start.line, start.column));
+        }
+        Ok(())
+    }
+
+    /// Parse a gate application with optional parameters and register broadcasting
+    fn parse_gate(&mut self) -> QuantumResult<()> {
+        let start = self.peek().clone();
+        let name = self.expect_ident()?;
+
+        if name == "gate" || name == "opaque" || name == "def" {
+            return Err(error_at(start.line, start.column, format!("'{}' definitions are not supported", name)));
+        }
+
+        let mut params = Vec::new();
+        if self.accept_symbol('(') && !self.accept_symbol(')') {
+            params.push(self.parse_expression()?);
+            while self.accept_symbol(',') {
+                params.push(self.parse_expression()?);
+            }
+            self.expect_symbol(')')?;
+        }
+
+        let mut operands = vec![self.parse_operand(true)?];
+        while self.accept_symbol(',') {
+            operands.push(self.parse_operand(true)?);
+        }
+        self.expect_symbol(';')?;
+
+        // Whole-register operands broadcast over equal-sized registers
+        let width = operands.iter().filter(|o| o.whole).map(|o| o.indices.len()).max().unwrap_or(1);
+        if operands.iter().any(|o| o.whole && o.indices.len() != width) {
+            return Err(error_at(start.line, start.column, "broadcast registers have different sizes"));
+        }
+
+        for k in 0..width {
+            let qubits: Vec<usize> = operands.iter()
+                .map(|o| if o.whole { o.indices[k] } else { o.indices[0] })
+                .collect();
+
+            let gates = build_gate(&name, &params, &qubits).map_err(|m| error_at(start.line, start.column, m))?;
+            for gate in gates {
+                self.operations.push((Operation::Gate(gate), start.line, start.column));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// expression := term (('+' | '-') term)*
+    fn parse_expression(&mut self) -> QuantumResult<f64> {
+        let mut value = self.parse_term()?;
+        loop {
+            if self.accept_symbol('+') {
+                value += self.parse_term()?;
+            } else if self.accept_symbol('-') {
+                value -= self.parse_term()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// term := unary (('*' | '/') unary)*
+    fn parse_term(&mut self) -> QuantumResult<f64> {
+        let mut value = self.parse_unary()?;
+        loop {
+            if self.accept_symbol('*') {
+                value *= self.parse_unary()?;
+            } else if self.accept_symbol('/') {
+                value /= self.parse_unary()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// unary := ('-' | '+') unary | primary ('^' unary)?
+    fn parse_unary(&mut self) -> QuantumResult<f64> {
+        if self.accept_symbol('-') {
+            return Ok(-self.parse_unary()?);
+        }
+        if self.accept_symbol('+') {
+            return self.parse_unary();
+        }
+
+        let base = self.parse_primary()?;
+        if self.accept_symbol('^') {
+            Ok(base.powf(self.parse_unary()?))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// primary := number | constant | function '(' expression ')' | '(' expression ')'
+    fn parse_primary(&mut self) -> QuantumResult<f64> {
+        let token = self.peek().clone();
+
+        if let Some(value) = token.token.numeric() {
+            self.next();
+            return Ok(value);
+        }
+
+        match token.token {
+            Token::Symbol('(') => {
+                self.next();
+                let value = self.parse_expression()?;
+                self.expect_symbol(')')?;
+                Ok(value)
+            }
+            Token::Ident(name) => {
+                self.next();
+                let constant = match name.as_str() {
+                    "pi" | "π" => Some(PI),
+                    "tau" | "τ" => Some(TAU),
+                    "euler" | "ℇ" => Some(E),
+                    _ => None,
+                };
+                if let Some(value) = constant {
+                    return Ok(value);
+                }
+
+                let function: fn(f64) -> f64 = match name.as_str() {
+                    "sin" => f64::sin,
+                    "cos" => f64::cos,
+                    "tan" => f64::tan,
+                    "asin" | "arcsin" => f64::asin,
+                    "acos" | "arccos" => f64::acos,
+                    "atan" | "arctan" => f64::atan,
+                    "exp" => f64::exp,
+                    "ln" | "log" => f64::ln,
+                    "sqrt" => f64::sqrt,
+                    _ => return Err(error_at(token.line, token.column, format!("unknown identifier '{}' in expression", name))),
+                };
+
+                self.expect_symbol('(')?;
+                let argument = self.parse_expression()?;
+                self.expect_symbol(')')?;
+                Ok(function(argument))
+            }
+            _ => Err(self.error("expected expression")),
+        }
+    }
+}
+
+/// Map a standard-library gate name onto the crate's gate set
+///
+/// Gates without a native counterpart are expanded by [`decompose`], and
+/// identity gates are dropped.
+fn build_gate(name: &str, params: &[f64], qubits: &[usize]) -> Result<Vec<Gate>, String> {
+    let (expected_params, expected_qubits) = match name {
+        "id" | "h" | "x" | "y" | "z" | "s" | "sdg" | "t" | "tdg" | "sx" | "sxdg" => (0, 1),
+        "rx" | "ry" | "rz" | "p" | "u1" | "phase" => (1, 1),
+        "u2" => (2, 1),
+        "u3" | "u" | "U" => (3, 1),
+        "cx" | "CX" | "cnot" | "cy" | "cz" | "ch" | "swap" => (0, 2),
+        "cp" | "cu1" | "cphase" | "crx" | "cry" | "crz" | "rxx" | "rzz" => (1, 2),
+        "cu3" => (3, 2),
+        "cu" => (4, 2),
+        "ccx" | "toffoli" | "cswap" => (0, 3),
+        _ => return Err(format!("unsupported gate '{}'", name)),
+    };
+
+    if params.len() != expected_params {
+        return Err(format!("gate '{}' takes {} parameter(s), got {}", name, expected_params, params.len()));
+    }
+    if qubits.len() != expected_qubits {
+        return Err(format!("gate '{}' acts on {} qubit(s), got {}", name, expected_qubits, qubits.len()));
+    }
+
+    let q = qubits[0];
+    let gate = match name {
+        "id" => return Ok(Vec::new()),
+        "h" => Gate::H(q),
+        "x" => Gate::X(q),
+        "y" => Gate::Y(q),
+        "z" => Gate::Z(q),
+        "s" => Gate::S(q),
+        "sdg" => Gate::Sdg(q),
+        "t" => Gate::T(q),
+        "tdg" => Gate::Tdg(q),
+        "sx" => Gate::Unitary(q, sx_matrix(false)),
+        "sxdg" => Gate::Unitary(q, sx_matrix(true)),
+        "rx" => Gate::Rx(q, params[0]),
+        "ry" => Gate::Ry(q, params[0]),
+        "rz" => Gate::Rz(q, params[0]),
+        "p" | "u1" | "phase" => Gate::Phase(q, params[0]),
+        "u2" => Gate::Unitary(q, u3_matrix(PI / 2.0, params[0], params[1])),
+        "u3" | "u" | "U" => Gate::Unitary(q, u3_matrix(params[0], params[1], params[2])),
+        "cx" | "CX" | "cnot" => Gate::Cnot { control: q, target: qubits[1] },
+        "cz" => Gate::Cz { control: q, target: qubits[1] },
+        "cp" | "cu1" | "cphase" => Gate::CPhase { control: q, target: qubits[1], angle: params[0] },
+        "swap" => Gate::Swap(q, qubits[1]),
+        "ccx" | "toffoli" => Gate::Toffoli { controls: [q, qubits[1]], target: qubits[2] },
+        _ => return Ok(decompose(name, params, qubits)),
+    };
+
+    Ok(vec![gate])
+}
+
+/// Expand a standard-library gate without a native counterpart
+///
+/// The expansions are exact, including the relative phase picked up by the
+/// control qubit, so emitting the circuit writes out the expanded sequence.
+fn decompose(name: &str, params: &[f64], qubits: &[usize]) -> Vec<Gate> {
+    let (a, b) = (qubits[0], qubits[1]);
+    let cnot = Gate::Cnot { control: a, target: b };
+    let cz = Gate::Cz { control: a, target: b };
+
+    match name {
+        // S·X·S† = Y
+        "cy" => vec![Gate::Sdg(b), cnot, Gate::S(b)],
+        // Ry(-π/4)·X·Ry(π/4) = H
+        "ch" => vec![Gate::Ry(b, PI / 4.0), cnot, Gate::Ry(b, -PI / 4.0)],
+        // Z·Rx(-θ/2)·Z = Rx(θ/2), and likewise for X with Ry and Rz
+        "crx" => vec![Gate::Rx(b, params[0] / 2.0), cz, Gate::Rx(b, -params[0] / 2.0), cz],
+        "cry" => vec![Gate::Ry(b, params[0] / 2.0), cnot, Gate::Ry(b, -params[0] / 2.0), cnot],
+        "crz" => vec![Gate::Rz(b, params[0] / 2.0), cnot, Gate::Rz(b, -params[0] / 2.0), cnot],
+        "cu3" | "cu" => {
+            let (theta, phi, lambda) = (params[0], params[1], params[2]);
+            // `cu` adds a phase γ on the control
+            let control_phase = (lambda + phi) / 2.0 + params.get(3).copied().unwrap_or(0.0);
+            vec![
+                Gate::Phase(a, control_phase),
+                Gate::Phase(b, (lambda - phi) / 2.0),
+                cnot,
+                Gate::Unitary(b, u3_matrix(-theta / 2.0, 0.0, -(phi + lambda) / 2.0)),
+                cnot,
+                Gate::Unitary(b, u3_matrix(theta / 2.0, phi, 0.0)),
+            ]
+        }
+        "cswap" => {
+            let c = qubits[2];
+            let cnot = Gate::Cnot { control: c, target: b };
+            vec![cnot, Gate::Toffoli { controls: [a, b], target: c }, cnot]
+        }
+        // exp(-iθ/2 X⊗X) is exp(-iθ/2 Z⊗Z) in the Hadamard basis
+        "rxx" => vec![
+            Gate::H(a), Gate::H(b),
+            cnot, Gate::Rz(b, params[0]), cnot,
+            Gate::H(a), Gate::H(b),
+        ],
+        _ => vec![cnot, Gate::Rz(b, params[0]), cnot],
+    }
+}
+
+/// Matrix of √X or its inverse
+fn sx_matrix(inverse: bool) -> Matrix2 {
+    let sign = if inverse { -1.0 } else { 1.0 };
+    let a = c(0.5, 0.5 * sign);
+    let b = c(0.5, -0.5 * sign);
+    [[a, b], [b, a]]
+}
@@ -0,0 +1,271 @@
+//! Unit tests for the qasm module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::circuit::{Circuit, Operation};
+    use crate::quantum_state::gates::{c, Gate, Matrix2};
+    use crate::quantum_state::matrix::ComplexMatrix;
+    use crate::quantum_state::qasm::{u3_matrix, QasmVersion};
+    use crate::quantum_state::state_vector::StateVector;
+    use num::complex::Complex64;
+    use std::f64::consts::PI;
+
+    fn standard_library() -> Circuit {
+        let u = {
+            let mut state = StateVector::new(1).unwrap();
+            state.apply_all(&[Gate::Ry(0, 0.3), Gate::Rz(0, 1.1), Gate::Phase(0, -0.4)]).unwrap();
+            let a = state.amplitudes();
+            // Columns |ψ⟩ and its orthogonal complement
+            [[a[0], -a[1].conj()], [a[1], a[0].conj()]]
+        };
+
+        let gates = [
+            Gate::H(0), Gate::X(1), Gate::Y(2), Gate::Z(0),
+            Gate::S(1), Gate::Sdg(2), Gate::T(0), Gate::Tdg(1),
+            Gate::Rx(2, 0.25), Gate::Ry(0, -1.5), Gate::Rz(1, PI / 3.0), Gate::Phase(2, 2.0),
+            Gate::Unitary(1, u),
+            Gate::Cnot { control: 0, target: 2 },
+            Gate::Cz { control: 2, target: 1 },
+            Gate::CPhase { control: 1, target: 0, angle: 0.7 },
+            Gate::Swap(0, 2),
+            Gate::Toffoli { controls: [2, 0], target: 1 },
+        ];
+
+        let mut circuit = Circuit::with_bits(3, 3);
+        for gate in gates {
+            circuit.gate(gate).unwrap();
+        }
+        circuit.push(Operation::Barrier).unwrap()
+            .push(Operation::Reset(1)).unwrap()
+            .measure(0, 2).unwrap()
+            .measure(2, 0).unwrap();
+        circuit
+    }
+
+    /// Largest entry difference of two unitaries after removing a global phase
+    fn phase_distance(a: &ComplexMatrix, b: &ComplexMatrix) -> f64 {
+        let overlap: Complex64 = a.data().iter().zip(b.data()).map(|(x, y)| x.conj() * y).sum();
+        let phase = overlap / overlap.norm();
+        a.scale(phase).max_difference(b)
+    }
+
+    fn assert_round_trip(version: QasmVersion) {
+        let original = standard_library();
+        let parsed = Circuit::from_qasm(&original.to_qasm(version)).unwrap();
+
+        assert_eq!(parsed.num_qubits(), 3);
+        assert_eq!(parsed.num_bits(), 3);
+        assert_eq!(parsed.len(), original.len());
+
+        for (a, b) in original.operations().iter().zip(parsed.operations()) {
+            match (a, b) {
+                (Operation::Gate(Gate::Unitary(qa, ma)), Operation::Gate(Gate::Unitary(qb, mb))) => {
+                    assert_eq!(qa, qb);
+                    let distance = phase_distance(&ComplexMatrix::from_matrix2(ma), &ComplexMatrix::from_matrix2(mb));
+                    assert!(distance < 1e-12);
+                }
+                _ => assert_eq!(a, b),
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_qasm2() {
+        assert_round_trip(QasmVersion::V2);
+    }
+
+    #[test]
+    fn test_round_trip_qasm3() {
+        assert_round_trip(QasmVersion::V3);
+    }
+
+    /// Full unitary of a gate-only circuit
+    fn circuit_unitary(circuit: &Circuit) -> ComplexMatrix {
+        let n = circuit.num_qubits();
+        circuit.gates().fold(ComplexMatrix::identity(1 << n), |u, gate| {
+            &ComplexMatrix::from_gate(gate, n).unwrap() * &u
+        })
+    }
+
+    /// Unitary applying `m` to qubit 1 when qubit 0 is set
+    fn controlled(m: Matrix2) -> ComplexMatrix {
+        ComplexMatrix::from_fn(4, |i, j| match (i & 1, j & 1) {
+            (0, 0) if i == j => c(1.0, 0.0),
+            (1, 1) => m[i >> 1][j >> 1],
+            _ => c(0.0, 0.0),
+        })
+    }
+
+    #[test]
+    fn test_decomposed_library_gates() {
+        let (theta, phi, lambda, gamma): (f64, f64, f64, f64) = (0.7, -1.3, 2.1, 0.4);
+        let (cos, sin) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+        let cu = {
+            let mut m = u3_matrix(theta, phi, lambda);
+            for row in m.iter_mut() {
+                for entry in row.iter_mut() {
+                    *entry *= Complex64::from_polar(1.0, gamma);
+                }
+            }
+            m
+        };
+
+        let cases = [
+            ("cy q[0], q[1];", controlled(Gate::Y(0).matrix().unwrap())),
+            ("ch q[0], q[1];", controlled(Gate::H(0).matrix().unwrap())),
+            ("crx(0.7) q[0], q[1];", controlled(Gate::Rx(0, theta).matrix().unwrap())),
+            ("cry(0.7) q[0], q[1];", controlled(Gate::Ry(0, theta).matrix().unwrap())),
+            ("crz(0.7) q[0], q[1];", controlled(Gate::Rz(0, theta).matrix().unwrap())),
+            ("cu3(0.7, -1.3, 2.1) q[0], q[1];", controlled(u3_matrix(theta, phi, lambda))),
+            ("cu(0.7, -1.3, 2.1, 0.4) q[0], q[1];", controlled(cu)),
+            ("rxx(0.7) q[0], q[1];", ComplexMatrix::from_fn(4, |i, j| match i ^ j {
+                0 => c(cos, 0.0),
+                3 => c(0.0, -sin),
+                _ => c(0.0, 0.0),
+            })),
+            ("rzz(0.7) q[0], q[1];", ComplexMatrix::diagonal(&[
+                Complex64::from_polar(1.0, -theta / 2.0),
+                Complex64::from_polar(1.0, theta / 2.0),
+                Complex64::from_polar(1.0, theta / 2.0),
+                Complex64::from_polar(1.0, -theta / 2.0),
+            ])),
+        ];
+
+        for (statement, expected) in cases {
+            let circuit = Circuit::from_qasm(&format!("qreg q[2];\n{}", statement)).unwrap();
+            assert!(circuit_unitary(&circuit).max_difference(&expected) < 1e-12, "{}", statement);
+
+            for version in [QasmVersion::V2, QasmVersion::V3] {
+                let parsed = Circuit::from_qasm(&circuit.to_qasm(version)).unwrap();
+                assert_eq!(parsed.len(), circuit.len());
+                assert!(phase_distance(&circuit_unitary(&parsed), &expected) < 1e-12, "{}", statement);
+            }
+        }
+
+        // cswap exchanges qubits 1 and 2 when qubit 0 is set
+        let circuit = Circuit::from_qasm("qreg q[3];\ncswap q[0], q[1], q[2];").unwrap();
+        let expected = ComplexMatrix::from_fn(8, |i, j| {
+            let swapped = if j & 1 == 1 { (j & 1) | ((j & 2) << 1) | ((j & 4) >> 1) } else { j };
+            if i == swapped { c(1.0, 0.0) } else { c(0.0, 0.0) }
+        });
+        assert!(circuit_unitary(&circuit).max_difference(&expected) < 1e-12);
+
+        for version in [QasmVersion::V2, QasmVersion::V3] {
+            let parsed = Circuit::from_qasm(&circuit.to_qasm(version)).unwrap();
+            assert_eq!(parsed.operations(), circuit.operations());
+        }
+    }
+
+    #[test]
+    fn test_emitted_headers_and_measurements() {
+        let mut circuit = Circuit::with_bits(2, 1);
+        circuit.gate(Gate::Phase(0, 0.5)).unwrap().measure(1, 0).unwrap();
+
+        let v2 = circuit.to_qasm(QasmVersion::V2);
+        assert!(v2.starts_with("OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[2];\ncreg c[1];\n"));
+        assert!(v2.contains("u1(0.5) q[0];"));
+        assert!(v2.contains("measure q[1] -> c[0];"));
+
+        let v3 = circuit.to_qasm(QasmVersion::V3);
+        assert!(v3.starts_with("OPENQASM 3.0;\ninclude \"stdgates.inc\";\nqubit[2] q;\nbit[1] c;\n"));
+        assert!(v3.contains("p(0.5) q[0];"));
+        assert!(v3.contains("c[0] = measure q[1];"));
+    }
+
+    #[test]
+    fn test_external_qasm2_program() {
+        let source = r#"
+            // Bell pair with extra registers
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            qreg a[1];
+            qreg b[2];
+            creg m[3];
+            h a[0];
+            cx a[0], b[1];
+            rz(-pi/4 + 2*0.125) b[0];
+            u2(0, pi) b[0];
+            id a[0];
+            barrier a, b;
+            /* whole-register measurement */
+            measure b -> m[1];
+        "#;
+        assert!(Circuit::from_qasm(source).is_err());
+
+        let source = source.replace("measure b -> m[1];", "measure a[0] -> m[0];\nmeasure b[1] -> m[2];");
+        let circuit = Circuit::from_qasm(&source).unwrap();
+        assert_eq!(circuit.num_qubits(), 3);
+        assert_eq!(circuit.num_bits(), 3);
+        assert_eq!(circuit.operations()[1], Operation::Gate(Gate::Cnot { control: 0, target: 2 }));
+        assert_eq!(circuit.operations()[2], Operation::Gate(Gate::Rz(1, -PI / 4.0 + 0.25)));
+        assert_eq!(circuit.operations()[5], Operation::Measure { qubit: 0, bit: 0 });
+    }
+
+    #[test]
+    fn test_qasm3_declarations_and_broadcasting() {
+        let source = "OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[3] q;\nqubit anc;\nbit[3] c;\nh q;\ncx q, q;\nc = measure q;\nreset anc;\n";
+        assert!(Circuit::from_qasm(source).is_err());
+
+        let source = source.replace("cx q, q;", "cp(π/2) q[0], anc;");
+        let circuit = Circuit::from_qasm(&source).unwrap();
+        assert_eq!(circuit.num_qubits(), 4);
+        assert_eq!(circuit.gates().filter(|g| matches!(g, Gate::H(_))).count(), 3);
+        assert_eq!(circuit.operations()[3], Operation::Gate(Gate::CPhase { control: 0, target: 3, angle: PI / 2.0 }));
+        assert_eq!(circuit.operations()[6], Operation::Measure { qubit: 2, bit: 2 });
+        assert_eq!(circuit.operations()[7], Operation::Reset(3));
+    }
+
+    #[test]
+    fn test_sx_and_u_gates_simulate_correctly() {
+        let source = "OPENQASM 3.0;\nqubit[1] q;\nsx q[0];\nsx q[0];\n";
+        let circuit = Circuit::from_qasm(source).unwrap();
+        let mut state = StateVector::new(1).unwrap();
+        circuit.apply_unitary(&mut state).unwrap();
+        assert!((state.probability(1) - 1.0).abs() < 1e-12);
+
+        // u3(π, 0, π) is X
+        let circuit = Circuit::from_qasm("qreg q[1];\nu3(pi, 0, pi) q[0];").unwrap();
+        let mut state = StateVector::new(1).unwrap();
+        circuit.apply_unitary(&mut state).unwrap();
+        assert!((state.probability(1) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors_report_line_and_column() {
+        let message = |source: &str| Circuit::from_qasm(source).unwrap_err().to_string();
+
+        assert!(message("qreg q[2];\nfoo q[0];").contains("line 2, column 1: unsupported gate 'foo'"));
+        assert!(message("qreg q[2];\nh q[5];").contains("line 2, column 5: index 5 out of range"));
+        assert!(message("qreg q[2];\n  cx q[0] q[1];").contains("line 2, column 11: expected ';'"));
+        assert!(message("qreg q[1];\nrx(1 +) q[0];").contains("line 2, column 7: expected expression"));
+        assert!(message("qreg q[2];\ncx q[1], q[1];").contains("line 2, column 1: Qubit 1 used more than once"));
+        assert!(message("OPENQASM 4.0;").contains("line 1, column 10: unsupported OpenQASM version"));
+        assert!(message("qreg q[1];\ngate foo a { h a; }").contains("line 2, column 1: 'gate' definitions are not supported"));
+        assert!(message("qreg q[1];\nh r[0];").contains("unknown quantum register 'r'"));
+        assert!(message("qubit[1] q;\nconst float t = 0.5;").contains("line 2, column 1: 'const' statements are not supported"));
+        assert!(message("qubit[1] q;\ninput angle t;").contains("line 2, column 1: 'input' statements are not supported"));
+        assert!(message("qreg q[2];\ncrx(0.5) q[0];").contains("gate 'crx' acts on 2 qubit(s), got 1"));
+        assert!(message("qreg q[1];\n/* open").contains("line 2, column 1: unterminated block comment"));
+        assert!(message("qreg q[1];\nh q[0]; @").contains("line 2, column 9: unexpected character '@'"));
+    }
+
+    #[test]
+    fn test_register_sizes_and_indices_are_integers() {
+        let message = |source: &str| Circuit::from_qasm(source).unwrap_err().to_string();
+
+        assert!(message("qreg q[3.0];").contains("line 1, column 8: expected non-negative integer"));
+        assert!(message("qreg q[2];\nh q[1e0];").contains("line 2, column 5: expected non-negative integer"));
+        assert!(message("qubit[2.] q;").contains("expected non-negative integer"));
+        assert!(message("qreg q[99999999999999999999];").contains("expected non-negative integer"));
+
+        // Declarations stop at the simulator's qubit limit, in total across registers
+        assert!(message("qreg q[18446744073709551615];\nh q;").contains("line 1, column 1: register 'q' exceeds the limit of 24 qubits"));
+        assert!(message("qreg a[20];\nqreg b[5];").contains("line 2, column 1: register 'b' exceeds the limit"));
+        assert!(message("creg c[25];").contains("exceeds the limit of 24 bits"));
+        assert_eq!(Circuit::from_qasm("qreg a[20];\nqreg b[4];\nh b;").unwrap().num_qubits(), 24);
+
+        // Integer literals still work as parameters and versions
+        assert!(Circuit::from_qasm("OPENQASM 3;\nqubit q;\nrx(2) q;").is_ok());
+    }
+}
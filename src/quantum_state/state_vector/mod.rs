@@ -0,0 +1,336 @@
+//! Qubit State-Vector Simulation
+//!
+//! This module provides a pure-state simulator for n qubits, with gate
+//! application, measurement driven by a caller-supplied (seedable) random
+//! number generator, Pauli expectation values, and a bridge that derives a
+//! coherence field from the l1-norm of coherence of the simulated state.
+
+#[cfg(test)]
+mod tests;
+
+use num::complex::Complex64;
+use rand::Rng;
+
+use crate::error::{QuantumError, QuantumResult};
+use crate::quantum_field::coherence::Field as CoherenceField;
+use crate::quantum_state::gates::{c, Gate, Matrix2};
+
+/// Largest register the simulator will allocate
+pub const MAX_QUBITS: usize = 24;
+
+/// Tolerance for normalization checks
+const NORM_TOLERANCE: f64 = 1e-9;
+
+/// Single-qubit Pauli operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pauli {
+    /// Identity
+    I,
+    /// Pauli-X
+    X,
+    /// Pauli-Y
+    Y,
+    /// Pauli-Z
+    Z,
+}
+
+/// Pure state of an n-qubit register
+///
+/// Amplitudes are indexed by basis state with qubit 0 as the least significant bit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateVector {
+    /// Number of qubits
+    num_qubits: usize,
+
+    /// Basis-state amplitudes
+    amplitudes: Vec<Complex64>,
+}
+
+impl StateVector {
+    /// Create a register initialized to |0…0⟩
+    pub fn new(num_qubits: usize) -> QuantumResult<Self> {
+        Self::basis(num_qubits, 0)
+    }
+
+    /// Create a register in a computational basis state
+    pub fn basis(num_qubits: usize, index: usize) -> QuantumResult<Self> {
+        if num_qubits == 0 || num_qubits > MAX_QUBITS {
+            return Err(QuantumError::OperationError {
+                message: format!("Register size must be between 1 and {} qubits, got {}", MAX_QUBITS, num_qubits),
+            });
+        }
+
+        let dimension = 1usize << num_qubits;
+        if index >= dimension {
+            return Err(QuantumError::OperationError {
+                message: format!("Basis state {} out of range for {} qubits", index, num_qubits),
+            });
+        }
+
+        let mut amplitudes = vec![c(0.0, 0.0); dimension];
+        amplitudes[index] = c(1.0, 0.0);
+
+        Ok(Self { num_qubits, amplitudes })
+    }
+
+    /// Create a register from normalized amplitudes
+    pub fn from_amplitudes(amplitudes: Vec<Complex64>) -> QuantumResult<Self> {
+        let len = amplitudes.len();
+        if len < 2 || !len.is_power_of_two() || len.trailing_zeros() as usize > MAX_QUBITS {
+            return Err(QuantumError::OperationError {
+                message: format!("Amplitude count must be a power of two ≥ 2, got {}", len),
+            });
+        }
+
+        let norm: f64 = amplitudes.iter().map(|a| a.norm_sqr()).sum();
+        if (norm - 1.0).abs() > NORM_TOLERANCE {
+            return Err(QuantumError::OperationError {
+                message: format!("State is not normalized (norm² = {:.6})", norm),
+            });
+        }
+
+        Ok(Self {
+            num_qubits: len.trailing_zeros() as usize,
+            amplitudes,
+        })
+    }
+
+    /// Get the number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Get the Hilbert-space dimension (2^n)
+    pub fn dimension(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// Get the basis-state amplitudes
+    pub fn amplitudes(&self) -> &[Complex64] {
+        &self.amplitudes
+    }
+
+    /// Get the probability of a basis state
+    pub fn probability(&self, index: usize) -> f64 {
+        self.amplitudes.get(index).map(|a| a.norm_sqr()).unwrap_or(0.0)
+    }
+
+    /// Get the probabilities of all basis states
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).collect()
+    }
+
+    /// Get the squared norm of the state (1.0 for a valid state)
+    pub fn norm_sqr(&self) -> f64 {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).sum()
+    }
+
+    /// Inner product ⟨self|other⟩
+    pub fn inner(&self, other: &StateVector) -> QuantumResult<Complex64> {
+        if self.num_qubits != other.num_qubits {
+            return Err(QuantumError::OperationError {
+                message: format!("Cannot compare {}-qubit and {}-qubit states", self.num_qubits, other.num_qubits),
+            });
+        }
+
+        Ok(self.amplitudes.iter()
+            .zip(&other.amplitudes)
+            .map(|(a, b)| a.conj() * b)
+            .sum())
+    }
+
+    /// Fidelity |⟨self|other⟩|² between two pure states
+    pub fn fidelity(&self, other: &StateVector) -> QuantumResult<f64> {
+        Ok(self.inner(other)?.norm_sqr())
+    }
+
+    /// Apply a gate to the register
+    pub fn apply(&mut self, gate: &Gate) -> QuantumResult<()> {
+        let qubits = gate.qubits();
+        self.check_qubits(&qubits)?;
+
+        match (gate.matrix(), *gate) {
+            (Some(matrix), _) => {
+                let (controls, target) = qubits.split_at(qubits.len() - 1);
+                self.apply_controlled(controls, target[0], &matrix);
+            }
+            (None, Gate::Swap(a, b)) => {
+                let (ma, mb) = (1usize << a, 1usize << b);
+                for i in 0..self.amplitudes.len() {
+                    // Visit each swapped pair once, from the |…1_a…0_b…⟩ side
+                    if i & ma != 0 && i & mb == 0 {
+                        self.amplitudes.swap(i, i ^ ma ^ mb);
+                    }
+                }
+            }
+            (None, gate) => {
+                return Err(QuantumError::OperationError {
+                    message: format!("Gate {} has no simulation rule", gate.name()),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply a sequence of gates
+    pub fn apply_all(&mut self, gates: &[Gate]) -> QuantumResult<()> {
+        for gate in gates {
+            self.apply(gate)?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply a 2×2 matrix to a target qubit, conditioned on all controls being |1⟩
+    pub(crate) fn apply_controlled(&mut self, controls: &[usize], target: usize, matrix: &Matrix2) {
+        let control_mask = controls.iter().fold(0usize, |mask, q| mask | (1 << q));
+        let target_mask = 1usize << target;
+
+        for i in 0..self.amplitudes.len() {
+            if i & target_mask != 0 || i & control_mask != control_mask {
+                continue;
+            }
+
+            let j = i | target_mask;
+            let (a0, a1) = (self.amplitudes[i], self.amplitudes[j]);
+            self.amplitudes[i] = matrix[0][0] * a0 + matrix[0][1] * a1;
+            self.amplitudes[j] = matrix[1][0] * a0 + matrix[1][1] * a1;
+        }
+    }
+
+    /// Probability of measuring a qubit as |1⟩
+    pub fn probability_one(&self, qubit: usize) -> QuantumResult<f64> {
+        self.check_qubits(&[qubit])?;
+
+        Ok(self.amplitudes.iter()
+            .enumerate()
+            .filter(|(i, _)| i & (1 << qubit) != 0)
+            .map(|(_, a)| a.norm_sqr())
+            .sum())
+    }
+
+    /// Measure a qubit in the computational basis, collapsing the state
+    pub fn measure<R: Rng + ?Sized>(&mut self, qubit: usize, rng: &mut R) -> QuantumResult<u8> {
+        let p1 = self.probability_one(qubit)?;
+        let outcome = if rng.gen::<f64>() < p1 { 1 } else { 0 };
+        let p = if outcome == 1 { p1 } else { 1.0 - p1 };
+        let scale = 1.0 / p.sqrt();
+        let mask = 1usize << qubit;
+
+        for (i, a) in self.amplitudes.iter_mut().enumerate() {
+            if ((i & mask != 0) as u8) == outcome {
+                *a *= scale;
+            } else {
+                *a = c(0.0, 0.0);
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Measure every qubit, collapsing to a basis state and returning its index
+    pub fn measure_all<R: Rng + ?Sized>(&mut self, rng: &mut R) -> usize {
+        let index = self.sample_index(rng);
+
+        for a in self.amplitudes.iter_mut() {
+            *a = c(0.0, 0.0);
+        }
+        self.amplitudes[index] = c(1.0, 0.0);
+
+        index
+    }
+
+    /// Sample measurement outcomes without collapsing the state
+    ///
+    /// Returns the count of each basis-state index over `shots` samples.
+    pub fn sample_counts<R: Rng + ?Sized>(&self, shots: usize, rng: &mut R) -> Vec<usize> {
+        let mut counts = vec![0; self.amplitudes.len()];
+        for _ in 0..shots {
+            counts[self.sample_index(rng)] += 1;
+        }
+        counts
+    }
+
+    /// Draw a basis-state index according to the Born rule
+    fn sample_index<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let r: f64 = rng.gen::<f64>() * self.norm_sqr();
+        let mut cumulative = 0.0;
+
+        for (i, a) in self.amplitudes.iter().enumerate() {
+            cumulative += a.norm_sqr();
+            if r < cumulative {
+                return i;
+            }
+        }
+
+        // Rounding can leave r just above the final cumulative sum
+        self.amplitudes.iter()
+            .rposition(|a| a.norm_sqr() > 0.0)
+            .unwrap_or(0)
+    }
+
+    /// Expectation value of a Pauli string, given as (qubit, operator) pairs
+    ///
+    /// Qubits not listed act as the identity.
+    pub fn expectation(&self, paulis: &[(usize, Pauli)]) -> QuantumResult<f64> {
+        let qubits: Vec<usize> = paulis.iter().map(|(q, _)| *q).collect();
+        self.check_qubits(&qubits)?;
+
+        let mut transformed = self.clone();
+        for &(qubit, pauli) in paulis {
+            let gate = match pauli {
+                Pauli::I => continue,
+                Pauli::X => Gate::X(qubit),
+                Pauli::Y => Gate::Y(qubit),
+                Pauli::Z => Gate::Z(qubit),
+            };
+            transformed.apply(&gate)?;
+        }
+
+        // Pauli strings are Hermitian, so ⟨ψ|P|ψ⟩ is real
+        Ok(self.inner(&transformed)?.re)
+    }
+
+    /// Expectation value of Z on a single qubit
+    pub fn expectation_z(&self, qubit: usize) -> QuantumResult<f64> {
+        self.expectation(&[(qubit, Pauli::Z)])
+    }
+
+    /// l1-norm of coherence in the computational basis: Σ_{i≠j} |ψ_i||ψ_j|
+    ///
+    /// Ranges from 0 for basis states to 2^n - 1 for uniform superpositions.
+    pub fn l1_coherence(&self) -> f64 {
+        let l1: f64 = self.amplitudes.iter().map(|a| a.norm()).sum();
+        (l1 * l1 - self.norm_sqr()).max(0.0)
+    }
+
+    /// l1-norm of coherence normalized to the range 0.0 - 1.0
+    pub fn normalized_l1_coherence(&self) -> f64 {
+        self.l1_coherence() / (self.dimension() - 1) as f64
+    }
+
+    /// Create a coherence field whose coherence is the normalized l1-norm of coherence
+    pub fn coherence_field(&self) -> CoherenceField {
+        CoherenceField::with_coherence(self.normalized_l1_coherence())
+    }
+
+    /// Ensure qubit indices are in range and distinct
+    fn check_qubits(&self, qubits: &[usize]) -> QuantumResult<()> {
+        for (i, &q) in qubits.iter().enumerate() {
+            if q >= self.num_qubits {
+                return Err(QuantumError::OperationError {
+                    message: format!("Qubit {} out of range for {}-qubit register", q, self.num_qubits),
+                });
+            }
+
+            if qubits[..i].contains(&q) {
+                return Err(QuantumError::OperationError {
+                    message: format!("Qubit {} used more than once in one operation", q),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
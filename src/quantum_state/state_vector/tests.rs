@@ -0,0 +1,156 @@
+//! Unit tests for the state-vector module
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use crate::quantum_state::state_vector::{Pauli, StateVector};
+    use crate::quantum_state::gates::Gate;
+    use num::complex::Complex64;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    fn bell() -> StateVector {
+        let mut state = StateVector::new(2).unwrap();
+        state.apply(&Gate::H(0)).unwrap();
+        state.apply(&Gate::Cnot { control: 0, target: 1 }).unwrap();
+        state
+    }
+
+    #[test]
+    fn test_new_register_is_all_zero() {
+        let state = StateVector::new(3).unwrap();
+        assert_eq!(state.dimension(), 8);
+        assert_eq!(state.probability(0), 1.0);
+        assert!(StateVector::new(0).is_err());
+        assert!(StateVector::basis(2, 4).is_err());
+    }
+
+    #[test]
+    fn test_from_amplitudes_requires_normalization() {
+        let h = Complex64::new(FRAC_1_SQRT_2, 0.0);
+        assert!(StateVector::from_amplitudes(vec![h, h]).is_ok());
+        assert!(StateVector::from_amplitudes(vec![h, h, h]).is_err());
+        assert!(StateVector::from_amplitudes(vec![Complex64::new(1.0, 0.0); 2]).is_err());
+    }
+
+    #[test]
+    fn test_bell_state() {
+        let state = bell();
+        assert!((state.probability(0b00) - 0.5).abs() < 1e-12);
+        assert!((state.probability(0b11) - 0.5).abs() < 1e-12);
+        assert!(state.probability(0b01) < 1e-12);
+
+        // ⟨ZZ⟩ = 1, ⟨XX⟩ = 1, ⟨Z⟩ = 0
+        assert!((state.expectation(&[(0, Pauli::Z), (1, Pauli::Z)]).unwrap() - 1.0).abs() < 1e-12);
+        assert!((state.expectation(&[(0, Pauli::X), (1, Pauli::X)]).unwrap() - 1.0).abs() < 1e-12);
+        assert!(state.expectation_z(0).unwrap().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_qubit_ordering_and_pauli_gates() {
+        let mut state = StateVector::new(3).unwrap();
+        state.apply(&Gate::X(1)).unwrap();
+        assert_eq!(state.probability(0b010), 1.0);
+
+        state.apply(&Gate::Swap(1, 2)).unwrap();
+        assert_eq!(state.probability(0b100), 1.0);
+
+        state.apply(&Gate::Y(2)).unwrap();
+        assert!((state.amplitudes()[0] - Complex64::new(0.0, -1.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_toffoli_truth_table() {
+        for input in 0..8usize {
+            let mut state = StateVector::basis(3, input).unwrap();
+            state.apply(&Gate::Toffoli { controls: [0, 1], target: 2 }).unwrap();
+
+            let expected = if input & 0b011 == 0b011 { input ^ 0b100 } else { input };
+            assert_eq!(state.probability(expected), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_controlled_z_and_phase() {
+        let mut state = StateVector::new(2).unwrap();
+        state.apply_all(&[Gate::H(0), Gate::H(1), Gate::Cz { control: 0, target: 1 }]).unwrap();
+        assert!((state.amplitudes()[3].re + 0.5).abs() < 1e-12);
+
+        let mut other = StateVector::new(2).unwrap();
+        other.apply_all(&[Gate::H(0), Gate::H(1), Gate::CPhase { control: 0, target: 1, angle: PI }]).unwrap();
+        assert!((state.fidelity(&other).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut state = StateVector::new(1).unwrap();
+        state.apply(&Gate::Ry(0, PI / 3.0)).unwrap();
+        assert!((state.probability_one(0).unwrap() - 0.25).abs() < 1e-12);
+        assert!((state.expectation(&[(0, Pauli::X)]).unwrap() - (PI / 3.0).sin()).abs() < 1e-12);
+
+        state.apply(&Gate::Rz(0, PI / 2.0)).unwrap();
+        assert!((state.expectation(&[(0, Pauli::Y)]).unwrap() - (PI / 3.0).sin()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_seeded_measurement_is_reproducible() {
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut state = StateVector::new(4).unwrap();
+            for q in 0..4 {
+                state.apply(&Gate::H(q)).unwrap();
+            }
+            state.measure_all(&mut rng)
+        };
+
+        assert_eq!(run(42), run(42));
+    }
+
+    #[test]
+    fn test_measurement_collapses_entangled_partner() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..10 {
+            let mut state = bell();
+            let outcome = state.measure(0, &mut rng).unwrap();
+            assert!((state.norm_sqr() - 1.0).abs() < 1e-12);
+            assert_eq!(state.probability_one(1).unwrap(), outcome as f64);
+        }
+    }
+
+    #[test]
+    fn test_sample_counts_follow_born_rule() {
+        let mut state = StateVector::new(1).unwrap();
+        state.apply(&Gate::Ry(0, 2.0 * (0.8f64).sqrt().asin())).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let counts = state.sample_counts(10_000, &mut rng);
+        let fraction = counts[1] as f64 / 10_000.0;
+        assert!((fraction - 0.8).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_l1_coherence_bridge() {
+        let basis = StateVector::new(2).unwrap();
+        assert_eq!(basis.l1_coherence(), 0.0);
+        assert_eq!(basis.coherence_field().coherence(), 0.0);
+
+        let mut uniform = StateVector::new(2).unwrap();
+        uniform.apply_all(&[Gate::H(0), Gate::H(1)]).unwrap();
+        assert!((uniform.l1_coherence() - 3.0).abs() < 1e-12);
+        assert!((uniform.coherence_field().coherence() - 1.0).abs() < 1e-12);
+
+        // Bell state has coherence only between |00⟩ and |11⟩
+        assert!((bell().l1_coherence() - 1.0).abs() < 1e-12);
+        assert!((bell().normalized_l1_coherence() - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_qubits_are_rejected() {
+        let mut state = StateVector::new(2).unwrap();
+        assert!(state.apply(&Gate::H(2)).is_err());
+        assert!(state.apply(&Gate::Cnot { control: 0, target: 0 }).is_err());
+        assert!(state.expectation(&[(3, Pauli::Z)]).is_err());
+    }
+}